@@ -1,5 +1,5 @@
 //! Example showing how to get the list of models per `AdapterKind`
-//! Note: Currently, only Ollama makes a dynamic query. Other adapters have a static list of models.
+//! Note: The models are listed from the provider models endpoint (needs the API key), with the adapter static list as a fallback.
 
 use genai::Client;
 use genai::adapter::AdapterKind;
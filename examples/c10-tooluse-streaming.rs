@@ -72,6 +72,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 			ChatStreamEvent::ReasoningChunk(chunk) => {
 				println!("\nReasoning: {}", chunk.content);
 			}
+			ChatStreamEvent::Resumed(resumed) => {
+				println!("\nStream resumed: {}", resumed.cause);
+			}
 			ChatStreamEvent::End(end) => {
 				println!("\nStream ended");
 
@@ -1,6 +1,8 @@
 use crate::adapter::adapters::together::TogetherAdapter;
 use crate::adapter::adapters::zai::ZaiAdapter;
 use crate::adapter::anthropic::AnthropicAdapter;
+use crate::adapter::azure_openai::AzureOpenAIAdapter;
+use crate::adapter::bedrock::BedrockAdapter;
 use crate::adapter::cohere::CohereAdapter;
 use crate::adapter::deepseek::{self, DeepSeekAdapter};
 use crate::adapter::fireworks::FireworksAdapter;
@@ -47,6 +49,10 @@ pub enum AdapterKind {
 	Cohere,
 	/// OpenAI shared behavior + some custom. (currently, localhost only, can be customize with ServerTargetResolver).
 	Ollama,
+	/// AWS Bedrock Converse API, with SigV4 signed requests (namespace only, e.g., `bedrock::amazon.nova-pro-v1:0`)
+	Bedrock,
+	/// Azure OpenAI deployments, OpenAI protocol with deployment URLs (namespace only, e.g., `azure::my-gpt-4o`)
+	AzureOpenAI,
 	#[cfg(feature = "vertex")]
 	/// Google Vertex AI adapter - separate from Gemini API due to different auth and endpoint structure
 	#[cfg(feature = "vertex")]
@@ -54,6 +60,9 @@ pub enum AdapterKind {
 	/// For native llama.cpp integration with local models
 	#[cfg(feature = "llamacpp")]
 	LlamaCpp,
+	/// Scripted responses for tests (namespace only, e.g., `mock::my-model`)
+	#[cfg(feature = "mock")]
+	Mock,
 }
 
 /// Serialization/Parse implementations
@@ -74,10 +83,14 @@ impl AdapterKind {
 			AdapterKind::Zai => "Zai",
 			AdapterKind::Cohere => "Cohere",
 			AdapterKind::Ollama => "Ollama",
+			AdapterKind::Bedrock => "Bedrock",
+			AdapterKind::AzureOpenAI => "AzureOpenAI",
 			#[cfg(feature = "vertex")]
 			AdapterKind::Vertex => "Vertex",
 			#[cfg(feature = "llamacpp")]
 			AdapterKind::LlamaCpp => "LlamaCpp",
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => "Mock",
 		}
 	}
 
@@ -97,10 +110,14 @@ impl AdapterKind {
 			AdapterKind::Zai => "zai",
 			AdapterKind::Cohere => "cohere",
 			AdapterKind::Ollama => "ollama",
+			AdapterKind::Bedrock => "bedrock",
+			AdapterKind::AzureOpenAI => "azure_openai",
 			#[cfg(feature = "vertex")]
 			AdapterKind::Vertex => "vertex",
 			#[cfg(feature = "llamacpp")]
 			AdapterKind::LlamaCpp => "llamacpp",
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => "mock",
 		}
 	}
 
@@ -119,10 +136,14 @@ impl AdapterKind {
 			"zai" => Some(AdapterKind::Zai),
 			"cohere" => Some(AdapterKind::Cohere),
 			"ollama" => Some(AdapterKind::Ollama),
+			"bedrock" => Some(AdapterKind::Bedrock),
+			"azure_openai" => Some(AdapterKind::AzureOpenAI),
 			#[cfg(feature = "vertex")]
 			"vertex" => Some(AdapterKind::Vertex),
 			#[cfg(feature = "llamacpp")]
 			"llamacpp" => Some(AdapterKind::LlamaCpp),
+			#[cfg(feature = "mock")]
+			"mock" => Some(AdapterKind::Mock),
 			_ => None,
 		}
 	}
@@ -146,10 +167,14 @@ impl AdapterKind {
 			AdapterKind::Zai => Some(ZaiAdapter::API_KEY_DEFAULT_ENV_NAME),
 			AdapterKind::Cohere => Some(CohereAdapter::API_KEY_DEFAULT_ENV_NAME),
 			AdapterKind::Ollama => None,
+			AdapterKind::Bedrock => Some(BedrockAdapter::API_KEY_DEFAULT_ENV_NAME),
+			AdapterKind::AzureOpenAI => Some(AzureOpenAIAdapter::API_KEY_DEFAULT_ENV_NAME),
 			#[cfg(feature = "vertex")]
 			AdapterKind::Vertex => Some(VertexAdapter::API_KEY_DEFAULT_ENV_NAME),
 			#[cfg(feature = "llamacpp")]
 			AdapterKind::LlamaCpp => None,
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => None,
 		}
 	}
 }
@@ -175,6 +200,9 @@ impl AdapterKind {
 	/// - e.g., for together.ai `together::meta-llama/Llama-3-8b-chat-hf`
 	/// - e.g., for nebius with `nebius::Qwen/Qwen3-235B-A22B`
 	/// - e.g., for ZAI coding plan with `coding::glm-4.6`
+	/// - e.g., for AWS Bedrock with `bedrock::anthropic.claude-3-5-haiku-20241022-v1:0`
+	/// - e.g., for an Azure OpenAI deployment with `azure::my-gpt-4o` (or `azure_openai::my-gpt-4o`)
+	/// - e.g., for the scripted mock adapter (feature `mock`) with `mock::my-model`
 	///
 	/// And all adapters can be force namspaced as well.
 	///
@@ -187,6 +215,10 @@ impl AdapterKind {
 			if ns == "zai" {
 				return Ok(AdapterKind::Zai);
 			}
+			// Short namespace for the Azure OpenAI deployments
+			if ns == "azure" {
+				return Ok(AdapterKind::AzureOpenAI);
+			}
 
 			if let Some(adapter) = Self::from_lower_str(ns) {
 				return Ok(adapter);
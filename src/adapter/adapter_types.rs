@@ -2,10 +2,9 @@ use crate::adapter::AdapterKind;
 use crate::chat::{ChatOptionsSet, ChatRequest, ChatResponse, ChatStreamResponse};
 use crate::embed::{EmbedOptionsSet, EmbedRequest, EmbedResponse};
 use crate::resolver::{AuthData, Endpoint};
-use crate::webc::WebResponse;
+use crate::webc::{WebResponse, WebStreamRequest};
 use crate::{Headers, ModelIden};
 use crate::{Result, ServiceTarget};
-use reqwest::RequestBuilder;
 use serde_json::Value;
 
 pub trait Adapter {
@@ -16,6 +15,8 @@ pub trait Adapter {
 
 	fn default_endpoint() -> Endpoint;
 
+	/// The static list of the common model names, used when the provider models cannot be listed
+	/// (see `AdapterDispatcher::list_models`).
 	// NOTE: Adapter is a crate trait, so it is acceptable to use async fn here.
 	async fn all_model_names(kind: AdapterKind) -> Result<Vec<String>>;
 
@@ -41,7 +42,7 @@ pub trait Adapter {
 	/// To be implemented by Adapters.
 	fn to_chat_stream(
 		model_iden: ModelIden,
-		reqwest_builder: RequestBuilder,
+		stream_request: WebStreamRequest,
 		options_set: ChatOptionsSet<'_, '_>,
 	) -> Result<ChatStreamResponse>;
 
@@ -76,11 +77,22 @@ pub enum ServiceType {
 // region:    --- WebRequestData
 
 // NOTE: This cannot really move to `webc` because it must be public with the adapter, and `webc` is private for now.
+/// The web request built by an adapter, before it is sent (see `Middleware::on_request`).
 #[derive(Debug, Clone)]
 pub struct WebRequestData {
+	/// The full URL of the request.
 	pub url: String,
+	/// The HTTP headers, including the auth headers.
 	pub headers: Headers,
+	/// The JSON payload (provider format).
 	pub payload: Value,
 }
 
+/// One request of a provider batch, as built by the adapter for a synchronous chat call.
+#[derive(Debug)]
+pub(crate) struct BatchItemData {
+	pub custom_id: String,
+	pub request: WebRequestData,
+}
+
 // endregion: --- WebRequestData
@@ -6,11 +6,9 @@ use crate::chat::{
 	ContentPart, MessageContent, PromptTokensDetails, ReasoningEffort, ToolCall, Usage,
 };
 use crate::resolver::{AuthData, Endpoint};
-use crate::webc::WebResponse;
+use crate::webc::{EventSource, WebResponse, WebStreamRequest};
 use crate::{Headers, ModelIden};
 use crate::{Result, ServiceTarget};
-use reqwest::RequestBuilder;
-use reqwest_eventsource::EventSource;
 use serde_json::{Value, json};
 use tracing::warn;
 use value_ext::JsonValueExt;
@@ -37,7 +35,7 @@ const MAX_TOKENS_32K: u32 = 32000; // claude-opus-4
 const MAX_TOKENS_8K: u32 = 8192; // claude-3-5-sonnet, claude-3-5-haiku
 const MAX_TOKENS_4K: u32 = 4096; // claude-3-opus, claude-3-haiku
 
-const ANTHROPIC_VERSION: &str = "2023-06-01";
+pub(super) const ANTHROPIC_VERSION: &str = "2023-06-01";
 const MODELS: &[&str] = &[
 	"claude-opus-4-1-20250805",
 	"claude-sonnet-4-5-20250929",
@@ -228,6 +226,9 @@ impl Adapter for AnthropicAdapter {
 		let usage = body.x_take::<Value>("usage");
 		let usage = usage.map(Self::into_usage).unwrap_or_default();
 
+		// -- Capture the finish reason
+		let finish_reason: Option<String> = body.x_get("stop_reason").ok();
+
 		// -- Capture the content
 		let mut content: MessageContent = MessageContent::default();
 
@@ -277,16 +278,21 @@ impl Adapter for AnthropicAdapter {
 			model_iden,
 			provider_model_iden,
 			usage,
+			finish_reason,
 			captured_raw_body,
+			fallback_attempts: Vec::new(),
+			cached: false,
+			timings: None,
+			content_filter_results: None,
 		})
 	}
 
 	fn to_chat_stream(
 		model_iden: ModelIden,
-		reqwest_builder: RequestBuilder,
+		stream_request: WebStreamRequest,
 		options_set: ChatOptionsSet<'_, '_>,
 	) -> Result<ChatStreamResponse> {
-		let event_source = EventSource::new(reqwest_builder)?;
+		let event_source = EventSource::new(stream_request)?;
 		let anthropic_stream = AnthropicStreamer::new(event_source, model_iden.clone(), options_set);
 		let chat_stream = ChatStream::from_inter_stream(anthropic_stream);
 		Ok(ChatStreamResponse {
@@ -0,0 +1,198 @@
+//! Anthropic Message Batches API: <https://docs.anthropic.com/en/api/creating-message-batches>
+//!
+//! The requests are sent inline as `{custom_id, params}`, and the results are served as JSONL at the batch `results_url`.
+
+use super::adapter_impl::ANTHROPIC_VERSION;
+use crate::adapter::adapters::support::{batch_web_error, get_api_key, json_count, parse_jsonl};
+use crate::adapter::anthropic::AnthropicAdapter;
+use crate::adapter::{Adapter, BatchItemData};
+use crate::chat::{BatchRequestCounts, BatchStatus, ChatBatch, ChatBatchResult, ChatOptionsSet};
+use crate::webc::{WebClient, WebResponse};
+use crate::{Error, Headers, ModelIden, Result, ServiceTarget};
+use serde_json::{Value, json};
+use value_ext::JsonValueExt;
+
+impl AnthropicAdapter {
+	pub(crate) async fn submit_chat_batch(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		items: Vec<BatchItemData>,
+	) -> Result<ChatBatch> {
+		let (base_url, headers) = batch_base_and_headers(&target)?;
+		let requests: Vec<Value> = items
+			.into_iter()
+			.map(|item| json!({"custom_id": item.custom_id, "params": item.request.payload}))
+			.collect();
+		let res = web_client
+			.do_post(
+				&format!("{base_url}messages/batches"),
+				&headers,
+				json!({"requests": requests}),
+			)
+			.await
+			.map_err(batch_web_error(&target.model))?;
+
+		to_chat_batch(target.model, res.body)
+	}
+
+	pub(crate) async fn poll_batch(web_client: &WebClient, target: ServiceTarget, batch_id: &str) -> Result<ChatBatch> {
+		let (base_url, headers) = batch_base_and_headers(&target)?;
+		let headers: Vec<(String, String)> = headers.into_iter().collect();
+		let res = web_client
+			.do_get(&format!("{base_url}messages/batches/{batch_id}"), &headers)
+			.await
+			.map_err(batch_web_error(&target.model))?;
+
+		to_chat_batch(target.model, res.body)
+	}
+
+	pub(crate) async fn fetch_batch_results(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		batch: &ChatBatch,
+		options_set: ChatOptionsSet<'_, '_>,
+	) -> Result<Vec<ChatBatchResult>> {
+		let (_, headers) = batch_base_and_headers(&target)?;
+		let Some(results_url) = batch.results_ref.as_deref() else {
+			return Ok(Vec::new());
+		};
+		let content = web_client
+			.do_get_text(results_url, &headers)
+			.await
+			.map_err(batch_web_error(&batch.model_iden))?;
+
+		let results = parse_jsonl(&batch.model_iden, &content)?
+			.into_iter()
+			.map(|line| to_chat_batch_result(&batch.model_iden, line, options_set.clone()))
+			.collect();
+
+		Ok(results)
+	}
+}
+
+// region:    --- Support
+
+fn batch_base_and_headers(target: &ServiceTarget) -> Result<(String, Headers)> {
+	let api_key = get_api_key(&target.auth, &target.model)?;
+	let headers = Headers::from(vec![
+		("x-api-key".to_string(), api_key),
+		("anthropic-version".to_string(), ANTHROPIC_VERSION.to_string()),
+	]);
+	Ok((target.endpoint.base_url().to_string(), headers))
+}
+
+fn to_chat_batch(model_iden: ModelIden, body: Value) -> Result<ChatBatch> {
+	let id: String = body.x_get("id").map_err(|err| Error::BatchResponse {
+		model_iden: model_iden.clone(),
+		cause: format!("Batch has no id: {err}"),
+	})?;
+
+	let status = match body.x_get_str("processing_status").unwrap_or_default() {
+		"ended" => BatchStatus::Completed,
+		"canceling" => BatchStatus::Cancelling,
+		_ => BatchStatus::InProgress,
+	};
+
+	// The batch ends with all its requests processed (succeeded, errored, canceled, or expired).
+	let request_counts = body.get("request_counts").map(|counts| {
+		let succeeded = json_count(counts.get("succeeded"));
+		let failed = ["errored", "canceled", "expired"]
+			.iter()
+			.map(|name| json_count(counts.get(*name)))
+			.sum();
+		BatchRequestCounts {
+			total: succeeded + failed + json_count(counts.get("processing")),
+			succeeded,
+			failed,
+		}
+	});
+
+	Ok(ChatBatch {
+		id,
+		model_iden,
+		status,
+		request_counts,
+		results_ref: body.x_get("results_url").ok(),
+		errors_ref: None,
+	})
+}
+
+/// Line format: `{"custom_id": "..", "result": {"type": "succeeded", "message": {..}}}`
+/// (or `"type": "errored"` with an `error`, `"canceled"`, `"expired"`)
+fn to_chat_batch_result(
+	model_iden: &ModelIden,
+	mut line: Value,
+	options_set: ChatOptionsSet<'_, '_>,
+) -> ChatBatchResult {
+	let custom_id: String = line.x_take("custom_id").unwrap_or_default();
+	let result_type: String = line.x_take("/result/type").unwrap_or_default();
+
+	let result = match (result_type.as_str(), line.x_take::<Value>("/result/message")) {
+		("succeeded", Ok(message)) => {
+			AnthropicAdapter::to_chat_response(model_iden.clone(), WebResponse::from_body(message), options_set)
+		}
+		(result_type, _) => {
+			let cause = match line.x_take::<Value>("/result/error") {
+				Ok(error) => error.to_string(),
+				Err(_) => format!("Request {result_type}"),
+			};
+			Err(Error::BatchRequestFailed {
+				model_iden: model_iden.clone(),
+				custom_id: custom_id.clone(),
+				cause,
+			})
+		}
+	};
+
+	ChatBatchResult { custom_id, result }
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::adapter::AdapterKind;
+
+	#[test]
+	fn test_anthropic_batch_parse_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let model_iden = ModelIden::new(AdapterKind::Anthropic, "claude-haiku-4-5");
+		let batch_body = json!({
+			"id": "msgbatch_abc",
+			"processing_status": "ended",
+			"request_counts": {"processing": 0, "succeeded": 1, "errored": 1, "canceled": 0, "expired": 0},
+			"results_url": "https://api.anthropic.com/v1/messages/batches/msgbatch_abc/results",
+		});
+		let content = r#"
+{"custom_id":"req-1","result":{"type":"succeeded","message":{"model":"claude-haiku-4-5","content":[{"type":"text","text":"Hello"}],"usage":{"input_tokens":3,"output_tokens":1}}}}
+{"custom_id":"req-2","result":{"type":"errored","error":{"type":"invalid_request_error"}}}
+"#;
+
+		// -- Exec
+		let batch = to_chat_batch(model_iden.clone(), batch_body)?;
+		let mut results: Vec<ChatBatchResult> = parse_jsonl(&model_iden, content)?
+			.into_iter()
+			.map(|line| to_chat_batch_result(&model_iden, line, ChatOptionsSet::default()))
+			.collect();
+
+		// -- Check
+		assert_eq!(batch.status, BatchStatus::Completed);
+		assert_eq!(batch.request_counts.map(|c| (c.total, c.failed)), Some((2, 1)));
+		assert!(batch.results_ref.is_some());
+		assert_eq!(results.len(), 2);
+		let failed = results.pop().ok_or("Should have a failed result")?;
+		assert!(matches!(failed.result, Err(Error::BatchRequestFailed { .. })));
+		let ok = results.pop().ok_or("Should have a result")?;
+		assert_eq!(ok.custom_id, "req-1");
+		assert_eq!(ok.result?.first_text(), Some("Hello"));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -6,6 +6,9 @@
 // region:    --- Modules
 
 mod adapter_impl;
+mod batch;
+mod models;
+mod provider_error;
 mod streamer;
 
 pub use adapter_impl::*;
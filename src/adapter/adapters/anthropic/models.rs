@@ -0,0 +1,110 @@
+//! Anthropic models endpoint: <https://docs.anthropic.com/en/api/models-list>
+//!
+//! The list is paginated (`has_more`, then `after_id` set to the `last_id`).
+
+use super::adapter_impl::ANTHROPIC_VERSION;
+use crate::adapter::AdapterKind;
+use crate::adapter::adapters::support::{get_api_key, model_list_web_error, rfc3339_to_unix_secs, take_model_list};
+use crate::adapter::anthropic::AnthropicAdapter;
+use crate::webc::WebClient;
+use crate::{ModelInfo, Result, ServiceTarget};
+use serde_json::Value;
+
+const PAGE_LIMIT: u32 = 1000;
+
+impl AnthropicAdapter {
+	pub(crate) async fn list_models(web_client: &WebClient, target: ServiceTarget) -> Result<Vec<ModelInfo>> {
+		let ServiceTarget { endpoint, auth, model } = target;
+		let adapter_kind = model.adapter_kind;
+		let api_key = get_api_key(&auth, &model)?;
+
+		let headers = [
+			("x-api-key".to_string(), api_key),
+			("anthropic-version".to_string(), ANTHROPIC_VERSION.to_string()),
+		];
+
+		let mut models = Vec::new();
+		let mut after_id: Option<String> = None;
+		loop {
+			let mut url = format!("{}models?limit={PAGE_LIMIT}", endpoint.base_url());
+			if let Some(after_id) = &after_id {
+				url.push_str(&format!("&after_id={after_id}"));
+			}
+			let mut res = web_client
+				.do_get(&url, &headers)
+				.await
+				.map_err(model_list_web_error(adapter_kind))?;
+
+			models.extend(to_model_infos(adapter_kind, &mut res.body)?);
+
+			after_id = match res.body.get("has_more").and_then(Value::as_bool) {
+				Some(true) => res.body.get("last_id").and_then(Value::as_str).map(String::from),
+				_ => None,
+			};
+			if after_id.is_none() {
+				break;
+			}
+		}
+
+		Ok(models)
+	}
+}
+
+// region:    --- Support
+
+/// `{"data": [{"id", "display_name", "created_at", "type"}], "has_more", "last_id"}`
+fn to_model_infos(adapter_kind: AdapterKind, body: &mut Value) -> Result<Vec<ModelInfo>> {
+	let models = take_model_list(adapter_kind, body, "/data")?
+		.into_iter()
+		.filter_map(|item| {
+			Some(ModelInfo {
+				id: item.get("id")?.as_str()?.to_string(),
+				display_name: item.get("display_name").and_then(Value::as_str).map(String::from),
+				created: item.get("created_at").and_then(Value::as_str).and_then(rfc3339_to_unix_secs),
+				owned_by: None,
+				context_window: None,
+			})
+		})
+		.collect();
+
+	Ok(models)
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_anthropic_to_model_infos_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let mut body = json!({
+			"data": [
+				{"type": "model", "id": "claude-3-7-sonnet-20250219", "display_name": "Claude Sonnet 3.7", "created_at": "2025-02-19T00:00:00Z"},
+				{"type": "model", "id": "claude-3-5-haiku-20241022", "display_name": "Claude Haiku 3.5", "created_at": "2024-10-22T09:30:15.123-02:00"}
+			],
+			"has_more": false,
+			"last_id": "claude-3-5-haiku-20241022"
+		});
+
+		// -- Exec
+		let models = to_model_infos(AdapterKind::Anthropic, &mut body)?;
+
+		// -- Check
+		assert_eq!(models.len(), 2);
+		assert_eq!(models[0].id, "claude-3-7-sonnet-20250219");
+		assert_eq!(models[0].display_name.as_deref(), Some("Claude Sonnet 3.7"));
+		assert_eq!(models[0].created, Some(1739923200));
+		assert_eq!(models[1].created, Some(1729596615));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,83 @@
+//! Anthropic errors: <https://docs.anthropic.com/en/api/errors>
+//!
+//! Body (also sent as an `error` stream event): `{"type": "error", "error": {"type", "message"}, "request_id"}`
+
+use crate::adapter::adapters::support::classify_provider_error;
+use crate::adapter::anthropic::AnthropicAdapter;
+use crate::{ErrorKind, ProviderError};
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+use serde_json::Value;
+
+impl AnthropicAdapter {
+	pub(crate) fn to_provider_error(
+		status: Option<StatusCode>,
+		headers: Option<&HeaderMap>,
+		body: &Value,
+	) -> ProviderError {
+		let mut provider_error = ProviderError::new(status, headers);
+
+		let error = body.get("error").unwrap_or(body);
+		provider_error.code = error.get("type").and_then(Value::as_str).map(String::from);
+		provider_error.message = error
+			.get("message")
+			.and_then(Value::as_str)
+			.or_else(|| error.as_str())
+			.map(String::from);
+		if let Some(request_id) = body.get("request_id").and_then(Value::as_str) {
+			provider_error.request_id = Some(request_id.to_string());
+		}
+
+		// `invalid_request_error` is left to the message (e.g., "prompt is too long", "credit balance is too low").
+		let code_kind = provider_error.code.as_deref().and_then(|code| match code {
+			"rate_limit_error" => Some(ErrorKind::RateLimited),
+			"billing_error" => Some(ErrorKind::QuotaExhausted),
+			"request_too_large" => Some(ErrorKind::ContextLengthExceeded),
+			"authentication_error" | "permission_error" => Some(ErrorKind::InvalidAuth),
+			"not_found_error" => Some(ErrorKind::ModelNotFound),
+			"overloaded_error" => Some(ErrorKind::Overloaded),
+			"api_error" => Some(ErrorKind::ServerError),
+			"timeout_error" => Some(ErrorKind::Timeout),
+			_ => None,
+		});
+
+		classify_provider_error(&mut provider_error, code_kind);
+		provider_error
+	}
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_anthropic_provider_error_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let overloaded_event = json!({"type": "error", "error": {"type": "overloaded_error", "message": "Overloaded"}});
+		let too_long_body = json!({
+			"type": "error",
+			"error": {"type": "invalid_request_error", "message": "prompt is too long: 210000 tokens > 200000 maximum"},
+			"request_id": "req_011"
+		});
+
+		// -- Exec
+		let overloaded = AnthropicAdapter::to_provider_error(None, None, &overloaded_event);
+		let too_long = AnthropicAdapter::to_provider_error(Some(StatusCode::BAD_REQUEST), None, &too_long_body);
+
+		// -- Check
+		assert_eq!(overloaded.kind, ErrorKind::Overloaded);
+		assert!(overloaded.status.is_none());
+		assert_eq!(too_long.kind, ErrorKind::ContextLengthExceeded);
+		assert_eq!(too_long.code.as_deref(), Some("invalid_request_error"));
+		assert_eq!(too_long.request_id.as_deref(), Some("req_011"));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
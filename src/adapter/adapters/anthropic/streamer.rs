@@ -1,8 +1,9 @@
 use crate::adapter::adapters::support::{StreamerCapturedData, StreamerOptions};
 use crate::adapter::inter_stream::{InterStreamEnd, InterStreamEvent};
 use crate::chat::{ChatOptionsSet, ToolCall, Usage};
+use crate::webc::EventSource;
 use crate::{Error, ModelIden, Result};
-use reqwest_eventsource::{Event, EventSource};
+use reqwest_eventsource::Event;
 use serde_json::Value;
 use std::pin::Pin;
 use std::task::{Context, Poll};
@@ -60,6 +61,11 @@ impl futures::Stream for AnthropicStreamer {
 						}
 						"message_delta" => {
 							self.capture_usage(message_type, &message.data)?;
+							if let Ok(data) = serde_json::from_str::<Value>(&message.data)
+								&& let Some(stop_reason) = data.pointer("/delta/stop_reason").and_then(Value::as_str)
+							{
+								self.captured_data.finish_reason = Some(stop_reason.to_string());
+							}
 							continue;
 						}
 						"content_block_start" => {
@@ -184,12 +190,28 @@ impl futures::Stream for AnthropicStreamer {
 								captured_reasoning_content: self.captured_data.reasoning_content.take(),
 								captured_tool_calls: self.captured_data.tool_calls.take(),
 								captured_thought_signature: None,
+								timings: None,
+								captured_content_filter_results: None,
+								captured_finish_reason: self.captured_data.finish_reason.take(),
 							};
 
 							// TODO: Need to capture the data as needed
 							return Poll::Ready(Some(Ok(InterStreamEvent::End(inter_stream_end))));
 						}
 
+						// e.g., an `overloaded_error` in the middle of the stream (see `Error::provider_error`)
+						"error" => {
+							self.done = true;
+							let body: Value =
+								serde_json::from_str(&message.data).map_err(|serde_error| Error::StreamParse {
+									model_iden: self.options.model_iden.clone(),
+									serde_error,
+								})?;
+							return Poll::Ready(Some(Err(Error::ChatResponse {
+								model_iden: self.options.model_iden.clone(),
+								body,
+							})));
+						}
 						"ping" => continue, // Loop to the next event
 						other => tracing::warn!("UNKNOWN MESSAGE TYPE: {other}"),
 					}
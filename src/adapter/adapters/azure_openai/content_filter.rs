@@ -0,0 +1,100 @@
+//! Azure OpenAI content filtering: <https://learn.microsoft.com/en-us/azure/ai-foundry/openai/concepts/content-filter>
+//!
+//! Body: `prompt_filter_results: [{"prompt_index", "content_filter_results": {..}}]` and
+//! `choices[].content_filter_results: {..}`, each being `{"<category>": {"filtered", "severity" | "detected"}}`.
+
+use crate::adapter::azure_openai::AzureOpenAIAdapter;
+use crate::chat::{ContentFilterResult, ContentFilterResults};
+use serde_json::Value;
+
+impl AzureOpenAIAdapter {
+	/// The content filter results of a chat completion (or of a stream chunk), if any.
+	pub(in crate::adapter::adapters) fn into_content_filter_results(body: &Value) -> Option<ContentFilterResults> {
+		let prompt: Vec<ContentFilterResult> = body
+			.get("prompt_filter_results")
+			.and_then(Value::as_array)
+			.into_iter()
+			.flatten()
+			.filter_map(|prompt_result| prompt_result.get("content_filter_results"))
+			.flat_map(into_category_results)
+			.collect();
+		let completion = body
+			.pointer("/choices/0/content_filter_results")
+			.map(into_category_results)
+			.unwrap_or_default();
+
+		if prompt.is_empty() && completion.is_empty() {
+			None
+		} else {
+			Some(ContentFilterResults { prompt, completion })
+		}
+	}
+}
+
+/// `{"hate": {"filtered": false, "severity": "safe"}, "jailbreak": {"filtered": false, "detected": false}}`
+///
+/// Note: The non category entries (e.g., an `error` when the filter did not run) are skipped.
+fn into_category_results(categories: &Value) -> Vec<ContentFilterResult> {
+	let Some(categories) = categories.as_object() else {
+		return Vec::new();
+	};
+	categories
+		.iter()
+		.filter_map(|(category, result)| {
+			let filtered = result.get("filtered")?.as_bool()?;
+			Some(ContentFilterResult {
+				category: category.to_string(),
+				filtered,
+				severity: result.get("severity").and_then(Value::as_str).map(String::from),
+				detected: result.get("detected").and_then(Value::as_bool),
+			})
+		})
+		.collect()
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_azure_openai_content_filter_results_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let body = json!({
+			"prompt_filter_results": [{
+				"prompt_index": 0,
+				"content_filter_results": {
+					"hate": {"filtered": false, "severity": "safe"},
+					"jailbreak": {"filtered": false, "detected": false}
+				}
+			}],
+			"choices": [{
+				"finish_reason": "content_filter",
+				"content_filter_results": {
+					"violence": {"filtered": true, "severity": "high"},
+					"error": {"code": "content_filter_error", "message": "The contents are not filtered"}
+				}
+			}]
+		});
+
+		// -- Exec
+		let results = AzureOpenAIAdapter::into_content_filter_results(&body).ok_or("Should have results")?;
+		let none = AzureOpenAIAdapter::into_content_filter_results(&json!({"choices": [{"message": {}}]}));
+
+		// -- Check
+		assert_eq!(results.prompt.len(), 2);
+		assert_eq!(results.completion.len(), 1);
+		assert_eq!(results.completion[0].severity.as_deref(), Some("high"));
+		assert!(results.is_filtered());
+		assert_eq!(results.filtered_categories(), vec!["violence"]);
+		assert!(none.is_none());
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
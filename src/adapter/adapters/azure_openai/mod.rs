@@ -0,0 +1,13 @@
+//! API Documentation:     <https://learn.microsoft.com/en-us/azure/ai-foundry/openai/reference>
+//! Authentication:        <https://learn.microsoft.com/en-us/azure/ai-foundry/openai/reference#authentication>
+//! API Versions:          <https://learn.microsoft.com/en-us/azure/ai-foundry/openai/api-version-lifecycle>
+//! Content Filtering:     <https://learn.microsoft.com/en-us/azure/ai-foundry/openai/concepts/content-filter>
+
+// region:    --- Modules
+
+mod adapter_impl;
+mod content_filter;
+
+pub use adapter_impl::*;
+
+// endregion: --- Modules
@@ -0,0 +1,618 @@
+use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::bedrock::sigv4::{self, AwsCredentials};
+use crate::adapter::bedrock::streamer::BedrockStreamer;
+use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
+use crate::chat::{
+	Binary, BinarySource, ChatOptionsSet, ChatRequest, ChatResponse, ChatRole, ChatStream, ChatStreamResponse,
+	ContentPart, MessageContent, PromptTokensDetails, ReasoningEffort, ToolCall, Usage,
+};
+use crate::embed::{EmbedOptionsSet, EmbedRequest, EmbedResponse};
+use crate::resolver::{self, AuthData, Endpoint};
+use crate::webc::{AwsEventStream, WebResponse, WebStreamRequest};
+use crate::{Error, Headers, ModelIden, Result, ServiceTarget};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::time::SystemTime;
+use value_ext::JsonValueExt;
+
+/// The AWS Bedrock adapter, with the model agnostic Converse and ConverseStream APIs.
+///
+/// - Auth with `AuthData::MultiKeys` (`AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY`, and optional
+///   `AWS_SESSION_TOKEN` and `AWS_REGION` keys) signs the requests with SigV4, right before each send
+///   (see `BedrockAdapter::sign_web_request`).
+///   By default, they are taken from the environment variables of the same names.
+/// - Auth with a single key (e.g., `AWS_BEARER_TOKEN_BEDROCK`, a Bedrock API key) sends it as a bearer token.
+/// - The `{region}` of the endpoint is replaced by the auth region (default `us-east-1`),
+///   so a custom endpoint (e.g., a local stub) can be set with a `ServiceTargetResolver`.
+pub struct BedrockAdapter;
+
+const MODELS: &[&str] = &[
+	"us.anthropic.claude-sonnet-4-5-20250929-v1:0",
+	"us.anthropic.claude-haiku-4-5-20251001-v1:0",
+	"anthropic.claude-3-5-haiku-20241022-v1:0",
+	"amazon.nova-pro-v1:0",
+	"amazon.nova-lite-v1:0",
+	"amazon.nova-micro-v1:0",
+	"mistral.mistral-large-2407-v1:0",
+];
+
+const REGION_DEFAULT: &str = "us-east-1";
+/// The SigV4 signing name of the Bedrock runtime.
+const SIGNING_SERVICE: &str = "bedrock";
+
+const REASONING_LOW: u32 = 1024;
+const REASONING_MEDIUM: u32 = 8000;
+const REASONING_HIGH: u32 = 24000;
+
+impl BedrockAdapter {
+	pub const API_KEY_DEFAULT_ENV_NAME: &str = "AWS_ACCESS_KEY_ID";
+	pub const SECRET_ACCESS_KEY_ENV_NAME: &str = "AWS_SECRET_ACCESS_KEY";
+	pub const SESSION_TOKEN_ENV_NAME: &str = "AWS_SESSION_TOKEN";
+	pub const REGION_ENV_NAME: &str = "AWS_REGION";
+	pub const BEARER_TOKEN_ENV_NAME: &str = "AWS_BEARER_TOKEN_BEDROCK";
+}
+
+impl Adapter for BedrockAdapter {
+	fn default_endpoint() -> Endpoint {
+		const BASE_URL: &str = "https://bedrock-runtime.{region}.amazonaws.com/";
+		Endpoint::from_static(BASE_URL)
+	}
+
+	/// The Bedrock API key if `AWS_BEARER_TOKEN_BEDROCK` is set, otherwise the AWS credentials of the environment.
+	fn default_auth() -> AuthData {
+		if std::env::var(Self::BEARER_TOKEN_ENV_NAME).is_ok() {
+			return AuthData::from_env(Self::BEARER_TOKEN_ENV_NAME);
+		}
+		let keys = [
+			Self::API_KEY_DEFAULT_ENV_NAME,
+			Self::SECRET_ACCESS_KEY_ENV_NAME,
+			Self::SESSION_TOKEN_ENV_NAME,
+			Self::REGION_ENV_NAME,
+		]
+		.into_iter()
+		.filter_map(|name| std::env::var(name).ok().map(|value| (name.to_string(), value)))
+		.collect();
+		AuthData::from_multi(keys)
+	}
+
+	/// Note: For now, this returns the common models (see above)
+	async fn all_model_names(_kind: AdapterKind) -> Result<Vec<String>> {
+		Ok(MODELS.iter().map(|s| s.to_string()).collect())
+	}
+
+	fn get_service_url(model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> Result<String> {
+		let base_url = endpoint.base_url();
+		let (model_name, _) = model.model_name.as_model_name_and_namespace();
+		// The model ids have `:` (e.g., `amazon.nova-pro-v1:0`)
+		let model_id = sigv4::uri_encode(model_name);
+		let url = match service_type {
+			ServiceType::Chat => format!("{base_url}model/{model_id}/converse"),
+			ServiceType::ChatStream => format!("{base_url}model/{model_id}/converse-stream"),
+			ServiceType::Embed | ServiceType::ImageGenerationImagen | ServiceType::VideoGenerationVeo => {
+				format!("{base_url}model/{model_id}/invoke")
+			}
+		};
+		Ok(url)
+	}
+
+	fn to_web_request_data(
+		target: ServiceTarget,
+		service_type: ServiceType,
+		chat_req: ChatRequest,
+		options_set: ChatOptionsSet<'_, '_>,
+	) -> Result<WebRequestData> {
+		let ServiceTarget { endpoint, auth, model } = target;
+		let (model_name, _) = model.model_name.as_model_name_and_namespace();
+
+		// -- Parts
+		let BedrockRequestParts {
+			system,
+			messages,
+			tools,
+		} = Self::into_bedrock_request_parts(&model, chat_req)?;
+
+		// -- Payload
+		let mut payload = json!({ "messages": messages });
+		if let Some(system) = system {
+			payload.x_insert("system", system)?;
+		}
+		if let Some(tools) = tools {
+			payload.x_insert("toolConfig", json!({ "tools": tools }))?;
+		}
+
+		// -- Add supported ChatOptions
+		if let Some(max_tokens) = options_set.max_tokens() {
+			payload.x_insert("/inferenceConfig/maxTokens", max_tokens)?;
+		}
+		if let Some(temperature) = options_set.temperature() {
+			payload.x_insert("/inferenceConfig/temperature", temperature)?;
+		}
+		if let Some(top_p) = options_set.top_p() {
+			payload.x_insert("/inferenceConfig/topP", top_p)?;
+		}
+		if !options_set.stop_sequences().is_empty() {
+			payload.x_insert("/inferenceConfig/stopSequences", options_set.stop_sequences())?;
+		}
+
+		// -- Reasoning (Claude extended thinking, as a model specific field)
+		if model_name.contains("anthropic.claude")
+			&& let Some(effort) = options_set.reasoning_effort()
+		{
+			let budget = match effort {
+				ReasoningEffort::Minimal => None,
+				ReasoningEffort::Low => Some(REASONING_LOW),
+				ReasoningEffort::Medium => Some(REASONING_MEDIUM),
+				ReasoningEffort::High => Some(REASONING_HIGH),
+				ReasoningEffort::Budget(budget) => Some(*budget),
+			};
+			if let Some(budget) = budget {
+				payload.x_insert(
+					"/additionalModelRequestFields/thinking",
+					json!({ "type": "enabled", "budget_tokens": budget }),
+				)?;
+			}
+		}
+
+		// -- Url & auth headers
+		let url = Self::get_service_url(&model, service_type, endpoint)?;
+		let (url, headers) = match &auth {
+			AuthData::MultiKeys(keys) => {
+				// Signed by the client right before the send (see `sign_web_request`)
+				let credentials = aws_credentials(keys, &model)?;
+				(url.replace("{region}", &credentials.region), Headers::default())
+			}
+			// The url and headers are replaced by the client
+			AuthData::RequestOverride { .. } => (url, Headers::default()),
+			AuthData::FromEnv(_) | AuthData::Key(_) => {
+				let api_key = get_api_key(&auth, &model)?;
+				let url = url.replace("{region}", &region_from_env());
+				(
+					url,
+					Headers::from(("Authorization".to_string(), format!("Bearer {api_key}"))),
+				)
+			}
+		};
+
+		Ok(WebRequestData { url, headers, payload })
+	}
+
+	fn to_chat_response(
+		model_iden: ModelIden,
+		web_response: WebResponse,
+		options_set: ChatOptionsSet<'_, '_>,
+	) -> Result<ChatResponse> {
+		let WebResponse { mut body, .. } = web_response;
+
+		let captured_raw_body = options_set.capture_raw_body().unwrap_or_default().then(|| body.clone());
+
+		// -- Capture the usage & finish reason
+		let usage = body.x_take::<Value>("usage").map(Self::into_usage).unwrap_or_default();
+		let finish_reason: Option<String> = body.x_get("stopReason").ok();
+
+		// -- Capture the content
+		let mut content: MessageContent = MessageContent::default();
+		let mut reasoning_content: Vec<String> = Vec::new();
+
+		let json_content_items: Vec<Value> = body.x_take("/output/message/content")?;
+		for mut item in json_content_items {
+			if let Ok(text) = item.x_take::<String>("text") {
+				content.push(ContentPart::from_text(text));
+			} else if let Ok(mut tool_use) = item.x_take::<Value>("toolUse") {
+				let tool_call = ToolCall {
+					call_id: tool_use.x_take("toolUseId")?,
+					fn_name: tool_use.x_take("name")?,
+					fn_arguments: tool_use.x_take::<Value>("input").unwrap_or_default(),
+					thought_signature: None,
+				};
+				content.push(ContentPart::ToolCall(tool_call));
+			} else if let Ok(reasoning) = item.x_take::<String>("/reasoningContent/reasoningText/text") {
+				reasoning_content.push(reasoning);
+			}
+		}
+
+		let reasoning_content = if !reasoning_content.is_empty() {
+			Some(reasoning_content.join("\n"))
+		} else {
+			None
+		};
+
+		Ok(ChatResponse {
+			content,
+			reasoning_content,
+			model_iden: model_iden.clone(),
+			provider_model_iden: model_iden,
+			usage,
+			finish_reason,
+			captured_raw_body,
+			fallback_attempts: Vec::new(),
+			cached: false,
+			timings: None,
+			content_filter_results: None,
+		})
+	}
+
+	fn to_chat_stream(
+		model_iden: ModelIden,
+		stream_request: WebStreamRequest,
+		options_set: ChatOptionsSet<'_, '_>,
+	) -> Result<ChatStreamResponse> {
+		let event_stream = AwsEventStream::new(stream_request);
+		let bedrock_stream = BedrockStreamer::new(event_stream, model_iden.clone(), options_set);
+		let chat_stream = ChatStream::from_inter_stream(bedrock_stream);
+
+		Ok(ChatStreamResponse {
+			model_iden,
+			stream: chat_stream,
+		})
+	}
+
+	fn to_embed_request_data(
+		_service_target: ServiceTarget,
+		_embed_req: EmbedRequest,
+		_options_set: EmbedOptionsSet<'_, '_>,
+	) -> Result<WebRequestData> {
+		Err(Error::AdapterNotSupported {
+			adapter_kind: AdapterKind::Bedrock,
+			feature: "embeddings".to_string(),
+		})
+	}
+
+	fn to_embed_response(
+		_model_iden: ModelIden,
+		_web_response: WebResponse,
+		_options_set: EmbedOptionsSet<'_, '_>,
+	) -> Result<EmbedResponse> {
+		Err(Error::AdapterNotSupported {
+			adapter_kind: AdapterKind::Bedrock,
+			feature: "embeddings".to_string(),
+		})
+	}
+}
+
+// region:    --- Support
+
+impl BedrockAdapter {
+	/// Signs the request with SigV4 when the auth is AWS credentials (`AuthData::MultiKeys`), replacing any previous signature.
+	///
+	/// The signature covers the final URL and body, and its date must be recent, so the client calls this
+	/// right before each send (after the middlewares, and again for each retry).
+	pub(crate) fn sign_web_request(
+		model_iden: &ModelIden,
+		auth: &AuthData,
+		web_request_data: &mut WebRequestData,
+	) -> Result<()> {
+		let AuthData::MultiKeys(keys) = auth else {
+			return Ok(());
+		};
+		let credentials = aws_credentials(keys, model_iden)?;
+		let body = serde_json::to_vec(&web_request_data.payload)?;
+		let signed_headers = sigv4::sign_post(
+			&credentials,
+			SIGNING_SERVICE,
+			&web_request_data.url,
+			&body,
+			SystemTime::now(),
+		)?;
+		web_request_data.headers.merge(signed_headers);
+
+		Ok(())
+	}
+
+	/// `{"inputTokens", "outputTokens", "totalTokens", "cacheReadInputTokens", "cacheWriteInputTokens"}`
+	pub(super) fn into_usage(mut usage_value: Value) -> Usage {
+		// As for Anthropic, the `inputTokens` do not include the cache read/write ones.
+		let input_tokens: i32 = usage_value.x_take("inputTokens").unwrap_or(0);
+		let cache_read_tokens: i32 = usage_value.x_take("cacheReadInputTokens").unwrap_or(0);
+		let cache_write_tokens: i32 = usage_value.x_take("cacheWriteInputTokens").unwrap_or(0);
+		let completion_tokens: i32 = usage_value.x_take("outputTokens").unwrap_or(0);
+
+		let prompt_tokens = input_tokens + cache_read_tokens + cache_write_tokens;
+		let prompt_tokens_details = (cache_read_tokens > 0 || cache_write_tokens > 0).then_some(PromptTokensDetails {
+			cache_creation_tokens: Some(cache_write_tokens),
+			cached_tokens: Some(cache_read_tokens),
+			audio_tokens: None,
+		});
+
+		Usage {
+			prompt_tokens: Some(prompt_tokens),
+			prompt_tokens_details,
+			completion_tokens: Some(completion_tokens),
+			completion_tokens_details: None,
+			total_tokens: Some(prompt_tokens + completion_tokens),
+		}
+	}
+
+	/// Takes the GenAI ChatMessages and constructs the Converse `system`, `messages`, and `toolConfig.tools`.
+	/// - The consecutive messages of the same role are merged (Converse requires alternating roles).
+	/// - The tool responses are `toolResult` blocks of a user message.
+	fn into_bedrock_request_parts(model_iden: &ModelIden, chat_req: ChatRequest) -> Result<BedrockRequestParts> {
+		let mut messages: Vec<(&'static str, Vec<Value>)> = Vec::new();
+		let mut systems: Vec<Value> = Vec::new();
+
+		if let Some(system) = chat_req.system {
+			systems.push(json!({ "text": system }));
+		}
+
+		let mut document_count = 0;
+		for msg in chat_req.messages {
+			let mut blocks: Vec<Value> = Vec::new();
+			let role = match msg.role {
+				ChatRole::System => {
+					if let Some(system_text) = msg.content.joined_texts() {
+						systems.push(json!({ "text": system_text }));
+					}
+					continue;
+				}
+				ChatRole::User | ChatRole::Tool => "user",
+				ChatRole::Assistant => "assistant",
+			};
+
+			for part in msg.content {
+				match part {
+					ContentPart::Text(text) => blocks.push(json!({ "text": text })),
+					ContentPart::Binary(binary) if role == "user" => {
+						document_count += 1;
+						blocks.push(binary_block(model_iden, binary, document_count)?);
+					}
+					ContentPart::ToolCall(tool_call) if role == "assistant" => blocks.push(json!({
+						"toolUse": {
+							"toolUseId": tool_call.call_id,
+							"name": tool_call.fn_name,
+							"input": tool_call.fn_arguments,
+						}
+					})),
+					ContentPart::ToolResponse(tool_response) if role == "user" => blocks.push(json!({
+						"toolResult": {
+							"toolUseId": tool_response.call_id,
+							"content": [{ "text": tool_response.content }],
+						}
+					})),
+					// Not valid for this role, or not supported by Converse
+					ContentPart::Binary(_)
+					| ContentPart::ToolCall(_)
+					| ContentPart::ToolResponse(_)
+					| ContentPart::ThoughtSignature(_) => {}
+				}
+			}
+
+			if blocks.is_empty() {
+				continue;
+			}
+			match messages.last_mut() {
+				Some((last_role, last_blocks)) if *last_role == role => last_blocks.extend(blocks),
+				_ => messages.push((role, blocks)),
+			}
+		}
+
+		let messages = messages
+			.into_iter()
+			.map(|(role, content)| json!({ "role": role, "content": content }))
+			.collect();
+
+		let tools = chat_req.tools.map(|tools| {
+			tools
+				.into_iter()
+				.map(|tool| {
+					let schema = tool.schema.unwrap_or_else(|| json!({ "type": "object", "properties": {} }));
+					let mut tool_spec = json!({
+						"name": tool.name,
+						"inputSchema": { "json": schema },
+					});
+					if let Some(description) = tool.description {
+						let _ = tool_spec.x_insert("description", description);
+					}
+					json!({ "toolSpec": tool_spec })
+				})
+				.collect::<Vec<Value>>()
+		});
+
+		Ok(BedrockRequestParts {
+			system: (!systems.is_empty()).then_some(systems),
+			messages,
+			tools,
+		})
+	}
+}
+
+struct BedrockRequestParts {
+	system: Option<Vec<Value>>,
+	messages: Vec<Value>,
+	tools: Option<Vec<Value>>,
+}
+
+/// An `image` or `document` block, from base64 data or an `s3://` URL.
+fn binary_block(model_iden: &ModelIden, binary: Binary, document_index: usize) -> Result<Value> {
+	let is_image = binary.is_image();
+	let Binary {
+		content_type,
+		source,
+		name,
+	} = binary;
+
+	let source = match source {
+		BinarySource::Base64(content) => json!({ "bytes": content }),
+		BinarySource::Url(url) if url.starts_with("s3://") => json!({ "s3Location": { "uri": url } }),
+		BinarySource::Url(_) => {
+			return Err(Error::MessageContentTypeNotSupported {
+				model_iden: model_iden.clone(),
+				cause: "Bedrock binary content must be base64 or an s3:// URL",
+			});
+		}
+	};
+
+	let Some(format) = binary_format(&content_type, is_image) else {
+		return Err(Error::MessageContentTypeNotSupported {
+			model_iden: model_iden.clone(),
+			cause: "Bedrock supports png/jpeg/gif/webp images and pdf/csv/doc/docx/xls/xlsx/html/txt/md documents",
+		});
+	};
+
+	if is_image {
+		Ok(json!({ "image": { "format": format, "source": source } }))
+	} else {
+		// Document names only allow alphanumeric, whitespace, hyphens, parentheses, and square brackets.
+		let name = name
+			.map(|name| {
+				name.chars()
+					.map(|c| {
+						if c.is_alphanumeric() || "-()[] ".contains(c) {
+							c
+						} else {
+							'-'
+						}
+					})
+					.collect::<String>()
+			})
+			.filter(|name| !name.trim().is_empty())
+			.unwrap_or_else(|| format!("document-{document_index}"));
+		Ok(json!({ "document": { "format": format, "name": name, "source": source } }))
+	}
+}
+
+fn binary_format(content_type: &str, is_image: bool) -> Option<&'static str> {
+	let content_type = content_type.trim().to_ascii_lowercase();
+	let format = match content_type.as_str() {
+		"image/png" => "png",
+		"image/jpeg" | "image/jpg" => "jpeg",
+		"image/gif" => "gif",
+		"image/webp" => "webp",
+		_ if is_image => return None,
+		"application/pdf" => "pdf",
+		"text/csv" => "csv",
+		"application/msword" => "doc",
+		"application/vnd.openxmlformats-officedocument.wordprocessingml.document" => "docx",
+		"application/vnd.ms-excel" => "xls",
+		"application/vnd.openxmlformats-officedocument.spreadsheetml.sheet" => "xlsx",
+		"text/html" => "html",
+		"text/plain" => "txt",
+		"text/markdown" => "md",
+		_ => return None,
+	};
+	Some(format)
+}
+
+fn aws_credentials(keys: &HashMap<String, String>, model_iden: &ModelIden) -> Result<AwsCredentials> {
+	let key = |name: &str| keys.get(name).filter(|value| !value.is_empty()).cloned();
+	let (Some(access_key_id), Some(secret_access_key)) = (
+		key(BedrockAdapter::API_KEY_DEFAULT_ENV_NAME),
+		key(BedrockAdapter::SECRET_ACCESS_KEY_ENV_NAME),
+	) else {
+		return Err(Error::Resolver {
+			model_iden: model_iden.clone(),
+			resolver_error: resolver::Error::Custom(format!(
+				"Bedrock AuthData::MultiKeys requires the '{}' and '{}' keys",
+				BedrockAdapter::API_KEY_DEFAULT_ENV_NAME,
+				BedrockAdapter::SECRET_ACCESS_KEY_ENV_NAME
+			)),
+		});
+	};
+
+	Ok(AwsCredentials {
+		access_key_id,
+		secret_access_key,
+		session_token: key(BedrockAdapter::SESSION_TOKEN_ENV_NAME),
+		region: key(BedrockAdapter::REGION_ENV_NAME).unwrap_or_else(region_from_env),
+	})
+}
+
+fn region_from_env() -> String {
+	std::env::var(BedrockAdapter::REGION_ENV_NAME).unwrap_or_else(|_| REGION_DEFAULT.to_string())
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::chat::{ChatMessage, ChatOptions, Tool, ToolResponse};
+
+	#[test]
+	fn test_bedrock_web_request_data_converse() -> Result<()> {
+		// -- Setup & Fixtures
+		let keys = HashMap::from([
+			("AWS_ACCESS_KEY_ID".to_string(), "AKIDEXAMPLE".to_string()),
+			("AWS_SECRET_ACCESS_KEY".to_string(), "secret".to_string()),
+			("AWS_SESSION_TOKEN".to_string(), "session".to_string()),
+			("AWS_REGION".to_string(), "eu-west-3".to_string()),
+		]);
+		let target = ServiceTarget {
+			endpoint: BedrockAdapter::default_endpoint(),
+			auth: AuthData::from_multi(keys),
+			model: ModelIden::new(AdapterKind::Bedrock, "bedrock::amazon.nova-pro-v1:0"),
+		};
+		let tool_call = ToolCall {
+			call_id: "call-1".to_string(),
+			fn_name: "get_weather".to_string(),
+			fn_arguments: json!({"city": "Paris"}),
+			thought_signature: None,
+		};
+		let image = ContentPart::from_binary_base64("image/png", "iVBORw0KGgo=", None);
+		let chat_req = ChatRequest::new(vec![
+			ChatMessage::user(MessageContent::from_text("Weather in this city?").append(image)),
+			ChatMessage::assistant(MessageContent::from(vec![ContentPart::ToolCall(tool_call)])),
+			ChatMessage::from(ToolResponse::new("call-1", "sunny")),
+			ChatMessage::user("And tomorrow?"),
+		])
+		.with_system("Be brief")
+		.with_tools([Tool::new("get_weather")]);
+		let options = ChatOptions::default().with_max_tokens(100).with_temperature(0.2);
+
+		let auth = target.auth.clone();
+		let model_iden = target.model.clone();
+
+		// -- Exec
+		let mut request_data = BedrockAdapter::to_web_request_data(
+			target,
+			ServiceType::Chat,
+			chat_req,
+			ChatOptionsSet::default().with_chat_options(Some(&options)),
+		)?;
+		let unsigned_headers = request_data.headers.clone();
+		BedrockAdapter::sign_web_request(&model_iden, &auth, &mut request_data)?;
+
+		// -- Check
+		assert!(
+			unsigned_headers.iter().next().is_none(),
+			"should be signed only before the send"
+		);
+		let WebRequestData { url, headers, payload } = request_data;
+		assert_eq!(
+			url,
+			"https://bedrock-runtime.eu-west-3.amazonaws.com/model/amazon.nova-pro-v1%3A0/converse"
+		);
+		let header = |name: &str| headers.iter().find(|(n, _)| n.as_str() == name).map(|(_, v)| v.clone());
+		let authorization = header("authorization").ok_or("should have authorization")?;
+		assert!(authorization.starts_with("AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/"));
+		assert!(authorization.contains("/eu-west-3/bedrock/aws4_request"));
+		assert!(authorization.contains("SignedHeaders=host;x-amz-date;x-amz-security-token"));
+		assert_eq!(header("x-amz-security-token").as_deref(), Some("session"));
+
+		assert_eq!(payload.x_get::<String>("/system/0/text")?, "Be brief");
+		assert_eq!(payload.x_get::<i64>("/inferenceConfig/maxTokens")?, 100);
+		assert_eq!(
+			payload.x_get::<String>("/toolConfig/tools/0/toolSpec/name")?,
+			"get_weather"
+		);
+		// user, assistant, user (tool result merged with the next user message)
+		let messages: Vec<Value> = payload.x_get("messages")?;
+		assert_eq!(messages.len(), 3);
+		assert_eq!(payload.x_get::<String>("/messages/0/content/1/image/format")?, "png");
+		assert_eq!(
+			payload.x_get::<String>("/messages/1/content/0/toolUse/toolUseId")?,
+			"call-1"
+		);
+		assert_eq!(
+			payload.x_get::<String>("/messages/2/content/0/toolResult/toolUseId")?,
+			"call-1"
+		);
+		assert_eq!(payload.x_get::<String>("/messages/2/content/1/text")?, "And tomorrow?");
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
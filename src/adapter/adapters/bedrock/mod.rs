@@ -0,0 +1,15 @@
+//! API Documentation:     <https://docs.aws.amazon.com/bedrock/latest/APIReference/API_runtime_Converse.html>
+//! Converse Stream:       <https://docs.aws.amazon.com/bedrock/latest/APIReference/API_runtime_ConverseStream.html>
+//! Model Names:           <https://docs.aws.amazon.com/bedrock/latest/userguide/models-supported.html>
+//! Pricing:               <https://aws.amazon.com/bedrock/pricing/>
+
+// region:    --- Modules
+
+mod adapter_impl;
+mod provider_error;
+mod sigv4;
+mod streamer;
+
+pub use adapter_impl::*;
+
+// endregion: --- Modules
@@ -0,0 +1,101 @@
+//! Bedrock errors: <https://docs.aws.amazon.com/bedrock/latest/APIReference/CommonErrors.html>
+//!
+//! Body: `{"message": ".."}`, with the code in the `x-amzn-ErrorType` header (e.g., `ThrottlingException:http://..`).
+//! The stream exception events have the code in their `:exception-type` header, passed as `{"__type", "message"}`.
+
+use crate::adapter::adapters::support::classify_provider_error;
+use crate::adapter::bedrock::BedrockAdapter;
+use crate::{ErrorKind, ProviderError};
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+use serde_json::Value;
+
+impl BedrockAdapter {
+	pub(crate) fn to_provider_error(
+		status: Option<StatusCode>,
+		headers: Option<&HeaderMap>,
+		body: &Value,
+	) -> ProviderError {
+		let mut provider_error = ProviderError::new(status, headers);
+
+		let header = |name: &str| headers.and_then(|headers| headers.get(name)?.to_str().ok());
+		provider_error.code = body
+			.get("__type")
+			.and_then(Value::as_str)
+			.or_else(|| header("x-amzn-ErrorType"))
+			// e.g., `ThrottlingException:http://internal.amazon.com/coral/com.amazon.bedrock/`
+			.map(|code| code.split(':').next().unwrap_or(code).to_string());
+		provider_error.message = ["message", "Message"]
+			.into_iter()
+			.find_map(|name| body.get(name).and_then(Value::as_str))
+			.or_else(|| body.as_str())
+			.map(String::from);
+		if let Some(request_id) = header("x-amzn-RequestId") {
+			provider_error.request_id = Some(request_id.to_string());
+		}
+
+		// `ValidationException` is left to the message (e.g., "Input is too long for requested model").
+		// Note: The stream exceptions are camel case (e.g., `throttlingException`).
+		let code_kind = provider_error
+			.code
+			.as_deref()
+			.and_then(|code| match code.to_ascii_lowercase().as_str() {
+				"throttlingexception" => Some(ErrorKind::RateLimited),
+				"servicequotaexceededexception" => Some(ErrorKind::QuotaExhausted),
+				"accessdeniedexception" | "unrecognizedclientexception" | "expiredtokenexception" => {
+					Some(ErrorKind::InvalidAuth)
+				}
+				"resourcenotfoundexception" => Some(ErrorKind::ModelNotFound),
+				"serviceunavailableexception" | "modelnotreadyexception" => Some(ErrorKind::Overloaded),
+				"internalserverexception" | "modelstreamerrorexception" => Some(ErrorKind::ServerError),
+				"modeltimeoutexception" => Some(ErrorKind::Timeout),
+				_ => None,
+			});
+
+		classify_provider_error(&mut provider_error, code_kind);
+		provider_error
+	}
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use reqwest::header::HeaderValue;
+	use serde_json::json;
+
+	#[test]
+	fn test_bedrock_provider_error_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let mut headers = HeaderMap::new();
+		headers.insert(
+			"x-amzn-ErrorType",
+			HeaderValue::from_static("ThrottlingException:http://internal.amazon.com/coral/com.amazon.bedrock/"),
+		);
+		headers.insert("x-amzn-RequestId", HeaderValue::from_static("b1f0c6a2"));
+		let throttling_body = json!({"message": "Too many requests, please wait before trying again."});
+		let exception_event = json!({"__type": "modelStreamErrorException", "message": "Model stream error"});
+		let too_long_body = json!({"message": "Input is too long for requested model."});
+
+		// -- Exec
+		let throttling =
+			BedrockAdapter::to_provider_error(Some(StatusCode::TOO_MANY_REQUESTS), Some(&headers), &throttling_body);
+		let exception = BedrockAdapter::to_provider_error(None, None, &exception_event);
+		let too_long = BedrockAdapter::to_provider_error(Some(StatusCode::BAD_REQUEST), None, &too_long_body);
+
+		// -- Check
+		assert_eq!(throttling.kind, ErrorKind::RateLimited);
+		assert_eq!(throttling.code.as_deref(), Some("ThrottlingException"));
+		assert_eq!(throttling.request_id.as_deref(), Some("b1f0c6a2"));
+		assert_eq!(exception.kind, ErrorKind::ServerError);
+		assert!(exception.status.is_none());
+		assert_eq!(too_long.kind, ErrorKind::ContextLengthExceeded);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
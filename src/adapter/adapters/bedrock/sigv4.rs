@@ -0,0 +1,184 @@
+//! AWS Signature Version 4 of the Bedrock requests.
+//! Doc: <https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_sigv-create-signed-request.html>
+//!
+//! Note: The signature covers the exact JSON body bytes, so the payload must not change after signing.
+
+use crate::{Error, Result, support};
+use reqwest::Url;
+use ring::{digest, hmac};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const ALGORITHM: &str = "AWS4-HMAC-SHA256";
+
+/// The AWS credentials of a request, with the region of the signing scope.
+pub(super) struct AwsCredentials {
+	pub access_key_id: String,
+	pub secret_access_key: String,
+	pub session_token: Option<String>,
+	pub region: String,
+}
+
+/// Returns the `x-amz-date`, `x-amz-security-token` (if any), and `authorization` headers of a POST request.
+pub(super) fn sign_post(
+	credentials: &AwsCredentials,
+	service: &str,
+	url: &str,
+	body: &[u8],
+	time: SystemTime,
+) -> Result<Vec<(String, String)>> {
+	let url = Url::parse(url).map_err(|err| Error::Internal(format!("Cannot sign the URL '{url}': {err}")))?;
+	let host = match (url.host_str(), url.port()) {
+		(Some(host), Some(port)) => format!("{host}:{port}"),
+		(Some(host), None) => host.to_string(),
+		(None, _) => return Err(Error::Internal(format!("Cannot sign the URL '{url}': no host"))),
+	};
+
+	let amz_date = amz_date(time);
+	let date = &amz_date[..8];
+	let scope = format!("{date}/{}/{service}/aws4_request", credentials.region);
+
+	// -- Canonical request (headers sorted by name)
+	let mut headers = vec![("host", host), ("x-amz-date", amz_date.clone())];
+	if let Some(session_token) = &credentials.session_token {
+		headers.push(("x-amz-security-token", session_token.clone()));
+	}
+	let canonical_headers: String = headers.iter().map(|(name, value)| format!("{name}:{value}\n")).collect();
+	let signed_headers = headers.iter().map(|(name, _)| *name).collect::<Vec<_>>().join(";");
+	let canonical_request = format!(
+		"POST\n{}\n{}\n{canonical_headers}\n{signed_headers}\n{}",
+		canonical_uri(&url),
+		canonical_query(&url),
+		hex_sha256(body)
+	);
+
+	// -- Signature
+	let string_to_sign = format!(
+		"{ALGORITHM}\n{amz_date}\n{scope}\n{}",
+		hex_sha256(canonical_request.as_bytes())
+	);
+	let signing_key = [date, credentials.region.as_str(), service, "aws4_request"].iter().fold(
+		format!("AWS4{}", credentials.secret_access_key).into_bytes(),
+		|key, data| hmac_sha256(&key, data.as_bytes()),
+	);
+	let signature = hex(&hmac_sha256(&signing_key, string_to_sign.as_bytes()));
+
+	let authorization = format!(
+		"{ALGORITHM} Credential={}/{scope}, SignedHeaders={signed_headers}, Signature={signature}",
+		credentials.access_key_id
+	);
+
+	let mut signed = vec![("x-amz-date".to_string(), amz_date)];
+	if let Some(session_token) = &credentials.session_token {
+		signed.push(("x-amz-security-token".to_string(), session_token.clone()));
+	}
+	signed.push(("authorization".to_string(), authorization));
+
+	Ok(signed)
+}
+
+/// URI-encodes all but the unreserved characters (`A-Z a-z 0-9 - _ . ~`).
+pub(super) fn uri_encode(value: &str) -> String {
+	let mut encoded = String::with_capacity(value.len());
+	for byte in value.bytes() {
+		if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+			encoded.push(byte as char);
+		} else {
+			encoded.push_str(&format!("%{byte:02X}"));
+		}
+	}
+	encoded
+}
+
+// region:    --- Support
+
+/// Each path segment is encoded again (as for all the services but S3), e.g., `v1%3A0` is `v1%253A0`.
+fn canonical_uri(url: &Url) -> String {
+	let path = url.path();
+	if path.is_empty() {
+		return "/".to_string();
+	}
+	path.split('/').map(uri_encode).collect::<Vec<_>>().join("/")
+}
+
+fn canonical_query(url: &Url) -> String {
+	let mut pairs: Vec<(String, String)> = url
+		.query_pairs()
+		.map(|(name, value)| (uri_encode(&name), uri_encode(&value)))
+		.collect();
+	pairs.sort();
+	pairs
+		.into_iter()
+		.map(|(name, value)| format!("{name}={value}"))
+		.collect::<Vec<_>>()
+		.join("&")
+}
+
+/// `YYYYMMDDTHHMMSSZ` (UTC)
+fn amz_date(time: SystemTime) -> String {
+	let secs = time.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default() as i64;
+	let (days, day_secs) = (secs.div_euclid(86_400), secs.rem_euclid(86_400));
+	let (year, month, day) = support::civil_from_days(days);
+
+	format!(
+		"{year:04}{month:02}{day:02}T{:02}{:02}{:02}Z",
+		day_secs / 3_600,
+		(day_secs % 3_600) / 60,
+		day_secs % 60
+	)
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+	let key = hmac::Key::new(hmac::HMAC_SHA256, key);
+	hmac::sign(&key, data).as_ref().to_vec()
+}
+
+fn hex_sha256(data: &[u8]) -> String {
+	hex(digest::digest(&digest::SHA256, data).as_ref())
+}
+
+fn hex(bytes: &[u8]) -> String {
+	bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use std::time::Duration;
+
+	/// The `post-vanilla` case of the AWS SigV4 test suite.
+	#[test]
+	fn test_sigv4_sign_post_vanilla() -> Result<()> {
+		// -- Setup & Fixtures
+		let credentials = AwsCredentials {
+			access_key_id: "AKIDEXAMPLE".to_string(),
+			secret_access_key: "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY".to_string(),
+			session_token: None,
+			region: "us-east-1".to_string(),
+		};
+		let time = UNIX_EPOCH + Duration::from_secs(1_440_938_160); // 2015-08-30T12:36:00Z
+
+		// -- Exec
+		let headers = sign_post(&credentials, "service", "https://example.amazonaws.com/", b"", time)?;
+
+		// -- Check
+		assert_eq!(headers[0], ("x-amz-date".to_string(), "20150830T123600Z".to_string()));
+		assert_eq!(
+			headers[1].1,
+			"AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+			SignedHeaders=host;x-amz-date, \
+			Signature=5da7c1a2acd57cee7505fc6676e4e544621c30862966e37dddb68e92efbe5d6b"
+		);
+		let model_url = Url::parse("https://bedrock-runtime.us-east-1.amazonaws.com/model/a.b-v1%3A0/converse")?;
+		assert_eq!(canonical_uri(&model_url), "/model/a.b-v1%253A0/converse");
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
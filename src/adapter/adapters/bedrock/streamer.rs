@@ -0,0 +1,201 @@
+use crate::adapter::adapters::support::{StreamerCapturedData, StreamerOptions};
+use crate::adapter::bedrock::BedrockAdapter;
+use crate::adapter::inter_stream::{InterStreamEnd, InterStreamEvent};
+use crate::chat::{ChatOptionsSet, ToolCall};
+use crate::webc::{AwsEventMessage, AwsEventStream};
+use crate::{Error, ModelIden, Result};
+use serde_json::{Value, json};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use value_ext::JsonValueExt;
+
+/// The ConverseStream events: `messageStart`, `contentBlockStart` (tool use only), `contentBlockDelta`,
+/// `contentBlockStop`, `messageStop`, and `metadata` (usage) as the last one.
+pub struct BedrockStreamer {
+	inner: AwsEventStream,
+	options: StreamerOptions,
+
+	// -- Set by the poll_next
+	/// Flag to prevent polling the event stream after the metadata event (or an exception)
+	done: bool,
+	captured_data: StreamerCapturedData,
+	in_progress_tool_use: Option<InProgressToolUse>,
+}
+
+struct InProgressToolUse {
+	id: String,
+	name: String,
+	input: String,
+}
+
+impl BedrockStreamer {
+	pub fn new(inner: AwsEventStream, model_iden: ModelIden, options_set: ChatOptionsSet<'_, '_>) -> Self {
+		Self {
+			inner,
+			done: false,
+			options: StreamerOptions::new(model_iden, &options_set),
+			captured_data: Default::default(),
+			in_progress_tool_use: None,
+		}
+	}
+}
+
+impl futures::Stream for BedrockStreamer {
+	type Item = Result<InterStreamEvent>;
+
+	fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		if self.done {
+			return Poll::Ready(None);
+		}
+
+		while let Poll::Ready(item) = Pin::new(&mut self.inner).poll_next(cx) {
+			let message = match item {
+				Some(Ok(message)) => message,
+				Some(Err(webc_error)) => {
+					self.done = true;
+					return Poll::Ready(Some(Err(Error::WebModelCall {
+						model_iden: self.options.model_iden.clone(),
+						webc_error,
+					})));
+				}
+				// The stream ended without a metadata event
+				None => {
+					self.done = true;
+					return Poll::Ready(Some(Ok(InterStreamEvent::End(self.take_end(None)))));
+				}
+			};
+
+			// e.g., a `throttlingException` in the middle of the stream (see `Error::provider_error`)
+			if message.message_type() != Some("event") {
+				self.done = true;
+				return Poll::Ready(Some(Err(self.exception_error(&message))));
+			}
+
+			let mut data = self.parse_payload(&message)?;
+			match message.event_type().unwrap_or_default() {
+				"messageStart" => return Poll::Ready(Some(Ok(InterStreamEvent::Start))),
+				"contentBlockStart" => {
+					if let Ok(mut tool_use) = data.x_take::<Value>("/start/toolUse") {
+						self.in_progress_tool_use = Some(InProgressToolUse {
+							id: tool_use.x_take("toolUseId")?,
+							name: tool_use.x_take("name")?,
+							input: String::new(),
+						});
+					}
+					continue;
+				}
+				"contentBlockDelta" => {
+					let mut delta: Value = data.x_take("delta")?;
+					if let Ok(text) = delta.x_take::<String>("text") {
+						if self.options.capture_content {
+							match self.captured_data.content {
+								Some(ref mut c) => c.push_str(&text),
+								None => self.captured_data.content = Some(text.clone()),
+							}
+						}
+						return Poll::Ready(Some(Ok(InterStreamEvent::Chunk(text))));
+					} else if let Ok(input) = delta.x_take::<String>("/toolUse/input") {
+						if let Some(tool_use) = self.in_progress_tool_use.as_mut() {
+							tool_use.input.push_str(&input);
+						}
+						continue;
+					} else if let Ok(reasoning) = delta.x_take::<String>("/reasoningContent/text") {
+						if self.options.capture_reasoning_content {
+							match self.captured_data.reasoning_content {
+								Some(ref mut r) => r.push_str(&reasoning),
+								None => self.captured_data.reasoning_content = Some(reasoning.clone()),
+							}
+						}
+						return Poll::Ready(Some(Ok(InterStreamEvent::ReasoningChunk(reasoning))));
+					}
+					continue;
+				}
+				"contentBlockStop" => {
+					let Some(InProgressToolUse { id, name, input }) = self.in_progress_tool_use.take() else {
+						continue;
+					};
+					// Note: A tool without parameters has an empty input.
+					let fn_arguments = if input.is_empty() {
+						json!({})
+					} else {
+						serde_json::from_str(&input)?
+					};
+					let tc = ToolCall {
+						call_id: id,
+						fn_name: name,
+						fn_arguments,
+						thought_signature: None,
+					};
+
+					// Add to the captured_tool_calls if chat options say so
+					if self.options.capture_tool_calls {
+						match self.captured_data.tool_calls {
+							Some(ref mut t) => t.push(tc.clone()),
+							None => self.captured_data.tool_calls = Some(vec![tc.clone()]),
+						}
+					}
+
+					return Poll::Ready(Some(Ok(InterStreamEvent::ToolCallChunk(tc))));
+				}
+				// The `stopReason` (the usage comes with the next `metadata` event)
+				"messageStop" => {
+					self.captured_data.finish_reason = data.x_take("stopReason").ok();
+					continue;
+				}
+				"metadata" => {
+					self.done = true;
+					let usage = data.x_take::<Value>("usage").ok();
+					return Poll::Ready(Some(Ok(InterStreamEvent::End(self.take_end(usage)))));
+				}
+				other => tracing::warn!("UNKNOWN BEDROCK EVENT TYPE: {other}"),
+			}
+		}
+		Poll::Pending
+	}
+}
+
+// Support
+impl BedrockStreamer {
+	fn take_end(&mut self, usage: Option<Value>) -> InterStreamEnd {
+		let captured_usage = if self.options.capture_usage {
+			usage.map(BedrockAdapter::into_usage)
+		} else {
+			None
+		};
+
+		InterStreamEnd {
+			captured_usage,
+			captured_text_content: self.captured_data.content.take(),
+			captured_reasoning_content: self.captured_data.reasoning_content.take(),
+			captured_tool_calls: self.captured_data.tool_calls.take(),
+			captured_thought_signature: None,
+			timings: None,
+			captured_content_filter_results: None,
+			captured_finish_reason: self.captured_data.finish_reason.take(),
+		}
+	}
+
+	/// The exception payload is `{"message": ".."}`, with the code in the `:exception-type` header.
+	fn exception_error(&self, message: &AwsEventMessage) -> Error {
+		let payload: Value = serde_json::from_slice(message.payload()).unwrap_or_default();
+		let error_message = payload
+			.get("message")
+			.and_then(Value::as_str)
+			.map(String::from)
+			.unwrap_or_else(|| String::from_utf8_lossy(message.payload()).to_string());
+		Error::ChatResponse {
+			model_iden: self.options.model_iden.clone(),
+			body: json!({
+				"__type": message.exception_type().or_else(|| message.header(":error-code")).unwrap_or("unknownException"),
+				"message": error_message,
+			}),
+		}
+	}
+
+	fn parse_payload(&self, message: &AwsEventMessage) -> Result<Value> {
+		serde_json::from_slice(message.payload()).map_err(|serde_error| Error::StreamParse {
+			model_iden: self.options.model_iden.clone(),
+			serde_error,
+		})
+	}
+}
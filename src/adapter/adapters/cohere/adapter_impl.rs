@@ -5,10 +5,9 @@ use crate::chat::{
 	ChatOptionsSet, ChatRequest, ChatResponse, ChatRole, ChatStream, ChatStreamResponse, MessageContent, Usage,
 };
 use crate::resolver::{AuthData, Endpoint};
-use crate::webc::{WebResponse, WebStream};
+use crate::webc::{WebResponse, WebStream, WebStreamRequest};
 use crate::{Error, Headers, Result};
 use crate::{ModelIden, ServiceTarget};
-use reqwest::RequestBuilder;
 use serde_json::{Value, json};
 use value_ext::JsonValueExt;
 
@@ -132,6 +131,9 @@ impl Adapter for CohereAdapter {
 		let provider_model_name = None;
 		let provider_model_iden = model_iden.from_optional_name(provider_model_name);
 
+		// -- Get finish reason
+		let finish_reason: Option<String> = body.x_get("finish_reason").ok();
+
 		// -- Get usage
 		let usage = body.x_take("/meta/tokens").map(Self::into_usage).unwrap_or_default();
 
@@ -151,16 +153,21 @@ impl Adapter for CohereAdapter {
 			model_iden,
 			provider_model_iden,
 			usage,
+			finish_reason,
 			captured_raw_body,
+			fallback_attempts: Vec::new(),
+			cached: false,
+			timings: None,
+			content_filter_results: None,
 		})
 	}
 
 	fn to_chat_stream(
 		model_iden: ModelIden,
-		reqwest_builder: RequestBuilder,
+		stream_request: WebStreamRequest,
 		options_set: ChatOptionsSet<'_, '_>,
 	) -> Result<ChatStreamResponse> {
-		let web_stream = WebStream::new_with_delimiter(reqwest_builder, "\n");
+		let web_stream = WebStream::new_with_delimiter(stream_request, "\n");
 		let cohere_stream = CohereStreamer::new(web_stream, model_iden.clone(), options_set);
 		let chat_stream = ChatStream::from_inter_stream(cohere_stream);
 
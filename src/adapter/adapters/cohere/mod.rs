@@ -6,6 +6,8 @@
 
 mod adapter_impl;
 mod embed;
+mod models;
+mod provider_error;
 mod streamer;
 
 pub use adapter_impl::*;
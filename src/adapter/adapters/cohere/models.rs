@@ -0,0 +1,57 @@
+//! Cohere models endpoint: <https://docs.cohere.com/reference/list-models>
+
+use crate::adapter::adapters::support::{get_api_key, model_list_web_error, take_model_list};
+use crate::adapter::cohere::CohereAdapter;
+use crate::webc::WebClient;
+use crate::{ModelInfo, Result, ServiceTarget};
+use serde_json::Value;
+
+const PAGE_SIZE: u32 = 1000;
+
+impl CohereAdapter {
+	pub(crate) async fn list_models(web_client: &WebClient, target: ServiceTarget) -> Result<Vec<ModelInfo>> {
+		let ServiceTarget { endpoint, auth, model } = target;
+		let adapter_kind = model.adapter_kind;
+		let api_key = get_api_key(&auth, &model)?;
+
+		let headers = [("Authorization".to_string(), format!("Bearer {api_key}"))];
+
+		let mut models = Vec::new();
+		let mut page_token: Option<String> = None;
+		loop {
+			let mut url = format!("{}models?page_size={PAGE_SIZE}", endpoint.base_url());
+			if let Some(page_token) = &page_token {
+				url.push_str(&format!("&page_token={page_token}"));
+			}
+			let mut res = web_client
+				.do_get(&url, &headers)
+				.await
+				.map_err(model_list_web_error(adapter_kind))?;
+
+			// `{"models": [{"name", "endpoints", "context_length"}], "next_page_token"}`
+			let items = take_model_list(adapter_kind, &mut res.body, "/models")?;
+			models.extend(items.into_iter().filter_map(|item| {
+				Some(ModelInfo {
+					id: item.get("name")?.as_str()?.to_string(),
+					context_window: item
+						.get("context_length")
+						.and_then(Value::as_u64)
+						.and_then(|size| u32::try_from(size).ok()),
+					..Default::default()
+				})
+			}));
+
+			page_token = res
+				.body
+				.get("next_page_token")
+				.and_then(Value::as_str)
+				.filter(|token| !token.is_empty())
+				.map(String::from);
+			if page_token.is_none() {
+				break;
+			}
+		}
+
+		Ok(models)
+	}
+}
@@ -0,0 +1,32 @@
+//! Cohere errors: <https://docs.cohere.com/reference/errors>
+//!
+//! Body: `{"id": "..", "message": ".."}` (the `id` is the request id), so the kind comes from the message and status.
+
+use crate::ProviderError;
+use crate::adapter::adapters::support::classify_provider_error;
+use crate::adapter::cohere::CohereAdapter;
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+use serde_json::Value;
+
+impl CohereAdapter {
+	pub(crate) fn to_provider_error(
+		status: Option<StatusCode>,
+		headers: Option<&HeaderMap>,
+		body: &Value,
+	) -> ProviderError {
+		let mut provider_error = ProviderError::new(status, headers);
+
+		provider_error.message = body
+			.get("message")
+			.and_then(Value::as_str)
+			.or_else(|| body.as_str())
+			.map(String::from);
+		if let Some(request_id) = body.get("id").and_then(Value::as_str) {
+			provider_error.request_id = Some(request_id.to_string());
+		}
+
+		classify_provider_error(&mut provider_error, None);
+		provider_error
+	}
+}
@@ -38,6 +38,8 @@ struct CohereStreamMessage {
 	is_finished: bool,
 	event_type: String,
 	text: Option<String>,
+	#[serde(default)]
+	finish_reason: Option<String>,
 	response: Option<CohereStreamMessageResponse>,
 }
 #[derive(Deserialize, Debug)]
@@ -109,6 +111,9 @@ impl futures::Stream for CohereStreamer {
 										captured_reasoning_content: self.captured_data.reasoning_content.take(),
 										captured_tool_calls: self.captured_data.tool_calls.take(),
 										captured_thought_signature: None,
+										timings: None,
+										captured_content_filter_results: None,
+										captured_finish_reason: cohere_message.finish_reason,
 									};
 
 									InterStreamEvent::End(inter_stream_end)
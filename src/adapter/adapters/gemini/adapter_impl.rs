@@ -8,9 +8,8 @@ use crate::chat::{
 	VeoGenerateVideosRequest, VeoGenerateVideosResponse, VeoOperationResult, VeoOperationStatusResponse,
 };
 use crate::resolver::{AuthData, Endpoint};
-use crate::webc::{WebResponse, WebStream};
+use crate::webc::{WebResponse, WebStream, WebStreamRequest};
 use crate::{Error, Headers, ModelIden, Result, ServiceTarget};
-use reqwest::RequestBuilder;
 use serde_json::{Value, json};
 use value_ext::JsonValueExt;
 
@@ -322,6 +321,7 @@ impl Adapter for GeminiAdapter {
 		// TODO: Need to be implemented (if available), for now, just clone model_iden
 		let provider_model_name: Option<String> = body.x_remove("modelVersion").ok();
 		let provider_model_iden = model_iden.from_optional_name(provider_model_name);
+		let finish_reason: Option<String> = body.x_get("/candidates/0/finishReason").ok();
 		let gemini_response = Self::body_to_gemini_chat_response(&model_iden.clone(), body)?;
 		let GeminiChatResponse {
 			content: gemini_content,
@@ -345,16 +345,21 @@ impl Adapter for GeminiAdapter {
 			model_iden,
 			provider_model_iden,
 			usage,
+			finish_reason,
 			captured_raw_body,
+			fallback_attempts: Vec::new(),
+			cached: false,
+			timings: None,
+			content_filter_results: None,
 		})
 	}
 
 	fn to_chat_stream(
 		model_iden: ModelIden,
-		reqwest_builder: RequestBuilder,
+		stream_request: WebStreamRequest,
 		options_set: ChatOptionsSet<'_, '_>,
 	) -> Result<ChatStreamResponse> {
-		let web_stream = WebStream::new_with_pretty_json_array(reqwest_builder);
+		let web_stream = WebStream::new_with_pretty_json_array(stream_request);
 
 		let gemini_stream = GeminiStreamer::new(web_stream, model_iden.clone(), options_set);
 		let chat_stream = ChatStream::from_inter_stream(gemini_stream);
@@ -0,0 +1,199 @@
+//! Gemini Batch Mode: <https://ai.google.dev/gemini-api/docs/batch-mode>
+//!
+//! The requests are sent inline (keyed by their `metadata.key`), and the batch is a long-running operation
+//! whose response has the inlined responses once succeeded.
+
+use crate::adapter::adapters::support::{batch_web_error, get_api_key, json_count};
+use crate::adapter::gemini::GeminiAdapter;
+use crate::adapter::{Adapter, BatchItemData};
+use crate::chat::{BatchRequestCounts, BatchStatus, ChatBatch, ChatBatchResult, ChatOptionsSet};
+use crate::webc::{WebClient, WebResponse};
+use crate::{Error, Headers, ModelIden, Result, ServiceTarget};
+use serde_json::{Value, json};
+use value_ext::JsonValueExt;
+
+impl GeminiAdapter {
+	pub(crate) async fn submit_chat_batch(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		items: Vec<BatchItemData>,
+	) -> Result<ChatBatch> {
+		let (_, headers) = batch_base_and_headers(&target)?;
+		let model_iden = target.model;
+
+		// The batch URL is the one of the chat requests (same resolved provider model), with the batch method.
+		let url = items
+			.first()
+			.map(|item| item.request.url.replace(":generateContent", ":batchGenerateContent"))
+			.ok_or_else(|| Error::BatchResponse {
+				model_iden: model_iden.clone(),
+				cause: "Batch has no requests".to_string(),
+			})?;
+
+		let requests: Vec<Value> = items
+			.into_iter()
+			.map(|item| json!({"request": item.request.payload, "metadata": {"key": item.custom_id}}))
+			.collect();
+		let payload = json!({
+			"batch": {
+				"display_name": format!("genai-batch-{}", model_iden.model_name),
+				"input_config": {"requests": {"requests": requests}},
+			}
+		});
+		let res = web_client
+			.do_post(&url, &headers, payload)
+			.await
+			.map_err(batch_web_error(&model_iden))?;
+
+		to_chat_batch(model_iden, res.body)
+	}
+
+	pub(crate) async fn poll_batch(web_client: &WebClient, target: ServiceTarget, batch_id: &str) -> Result<ChatBatch> {
+		let res = get_batch_operation(web_client, &target, batch_id).await?;
+		to_chat_batch(target.model, res.body)
+	}
+
+	/// Note: The inlined responses are part of the batch operation, so it is fetched again.
+	pub(crate) async fn fetch_batch_results(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		batch: &ChatBatch,
+		options_set: ChatOptionsSet<'_, '_>,
+	) -> Result<Vec<ChatBatchResult>> {
+		let res = get_batch_operation(web_client, &target, &batch.id).await?;
+		Ok(to_chat_batch_results(&batch.model_iden, res.body, options_set))
+	}
+}
+
+// region:    --- Support
+
+fn batch_base_and_headers(target: &ServiceTarget) -> Result<(String, Headers)> {
+	let api_key = get_api_key(&target.auth, &target.model)?;
+	let headers = Headers::from(("x-goog-api-key".to_string(), api_key));
+	Ok((target.endpoint.base_url().to_string(), headers))
+}
+
+async fn get_batch_operation(web_client: &WebClient, target: &ServiceTarget, batch_id: &str) -> Result<WebResponse> {
+	let (base_url, headers) = batch_base_and_headers(target)?;
+	let headers: Vec<(String, String)> = headers.into_iter().collect();
+	web_client
+		.do_get(&format!("{base_url}{batch_id}"), &headers)
+		.await
+		.map_err(batch_web_error(&target.model))
+}
+
+/// The body is the batch operation: `{"name": "batches/..", "metadata": {"state": "BATCH_STATE_..", "batchStats": {..}}, ..}`
+fn to_chat_batch(model_iden: ModelIden, body: Value) -> Result<ChatBatch> {
+	let id: String = body.x_get("name").map_err(|err| Error::BatchResponse {
+		model_iden: model_iden.clone(),
+		cause: format!("Batch has no name: {err}"),
+	})?;
+
+	let state = body
+		.x_get_str("/metadata/state")
+		.or_else(|_| body.x_get_str("state"))
+		.unwrap_or_default();
+	let status = match state.rsplit('_').next().unwrap_or_default() {
+		"SUCCEEDED" => BatchStatus::Completed,
+		"FAILED" => BatchStatus::Failed,
+		"CANCELLED" => BatchStatus::Cancelled,
+		"EXPIRED" => BatchStatus::Expired,
+		// PENDING, RUNNING
+		_ => BatchStatus::InProgress,
+	};
+
+	let request_counts = body.pointer("/metadata/batchStats").map(|stats| BatchRequestCounts {
+		total: json_count(stats.get("requestCount")),
+		succeeded: json_count(stats.get("successfulRequestCount")),
+		failed: json_count(stats.get("failedRequestCount")),
+	});
+
+	Ok(ChatBatch {
+		id,
+		model_iden,
+		status,
+		request_counts,
+		results_ref: None,
+		errors_ref: None,
+	})
+}
+
+fn to_chat_batch_results(
+	model_iden: &ModelIden,
+	mut body: Value,
+	options_set: ChatOptionsSet<'_, '_>,
+) -> Vec<ChatBatchResult> {
+	let inlined_responses: Vec<Value> = body
+		.x_take("/response/inlinedResponses/inlinedResponses")
+		.or_else(|_| body.x_take("/metadata/output/inlinedResponses/inlinedResponses"))
+		.unwrap_or_default();
+
+	inlined_responses
+		.into_iter()
+		.map(|mut item| {
+			let custom_id: String = item.x_take("/metadata/key").unwrap_or_default();
+			let result = match item.x_take::<Value>("response") {
+				Ok(response) => GeminiAdapter::to_chat_response(
+					model_iden.clone(),
+					WebResponse::from_body(response),
+					options_set.clone(),
+				),
+				Err(_) => Err(Error::BatchRequestFailed {
+					model_iden: model_iden.clone(),
+					custom_id: custom_id.clone(),
+					cause: item.x_take::<Value>("error").map(|err| err.to_string()).unwrap_or_default(),
+				}),
+			};
+			ChatBatchResult { custom_id, result }
+		})
+		.collect()
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::adapter::AdapterKind;
+
+	#[test]
+	fn test_gemini_batch_parse_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let model_iden = ModelIden::new(AdapterKind::Gemini, "gemini-2.5-flash");
+		let body = json!({
+			"name": "batches/abc",
+			"metadata": {
+				"state": "BATCH_STATE_SUCCEEDED",
+				"batchStats": {"requestCount": "2", "successfulRequestCount": "1", "failedRequestCount": "1"}
+			},
+			"done": true,
+			"response": {"inlinedResponses": {"inlinedResponses": [
+				{"response": {"candidates": [{"content": {"parts": [{"text": "Hello"}], "role": "model"}}]}, "metadata": {"key": "req-1"}},
+				{"error": {"code": 400, "message": "bad"}, "metadata": {"key": "req-2"}}
+			]}}
+		});
+
+		// -- Exec
+		let batch = to_chat_batch(model_iden.clone(), body.clone())?;
+		let mut results = to_chat_batch_results(&model_iden, body, ChatOptionsSet::default());
+
+		// -- Check
+		assert_eq!(batch.id, "batches/abc");
+		assert_eq!(batch.status, BatchStatus::Completed);
+		assert_eq!(batch.request_counts.map(|c| c.total), Some(2));
+		assert_eq!(results.len(), 2);
+		let failed = results.pop().ok_or("Should have a failed result")?;
+		assert_eq!(failed.custom_id, "req-2");
+		assert!(matches!(failed.result, Err(Error::BatchRequestFailed { .. })));
+		let ok = results.pop().ok_or("Should have a result")?;
+		assert_eq!(ok.result?.first_text(), Some("Hello"));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
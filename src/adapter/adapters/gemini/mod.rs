@@ -5,7 +5,10 @@
 // region:    --- Modules
 
 mod adapter_impl;
+mod batch;
 mod embed;
+mod models;
+mod provider_error;
 mod streamer;
 
 pub use adapter_impl::*;
@@ -0,0 +1,119 @@
+//! Gemini models endpoint: <https://ai.google.dev/api/models#method:-models.list>
+//!
+//! The model names are `models/{id}`, and the list is paginated (`nextPageToken`).
+
+use crate::adapter::AdapterKind;
+use crate::adapter::adapters::support::{get_api_key, model_list_web_error, take_model_list};
+use crate::adapter::gemini::GeminiAdapter;
+use crate::webc::WebClient;
+use crate::{ModelInfo, Result, ServiceTarget};
+use serde_json::Value;
+
+const PAGE_SIZE: u32 = 1000;
+
+impl GeminiAdapter {
+	pub(crate) async fn list_models(web_client: &WebClient, target: ServiceTarget) -> Result<Vec<ModelInfo>> {
+		let ServiceTarget { endpoint, auth, model } = target;
+		let adapter_kind = model.adapter_kind;
+		let api_key = get_api_key(&auth, &model)?;
+
+		let headers = [("x-goog-api-key".to_string(), api_key)];
+
+		let mut models = Vec::new();
+		let mut page_token: Option<String> = None;
+		loop {
+			let mut url = format!("{}models?pageSize={PAGE_SIZE}", endpoint.base_url());
+			if let Some(page_token) = &page_token {
+				url.push_str(&format!("&pageToken={page_token}"));
+			}
+			let mut res = web_client
+				.do_get(&url, &headers)
+				.await
+				.map_err(model_list_web_error(adapter_kind))?;
+
+			models.extend(to_model_infos(adapter_kind, &mut res.body)?);
+
+			page_token = res
+				.body
+				.get("nextPageToken")
+				.and_then(Value::as_str)
+				.filter(|token| !token.is_empty())
+				.map(String::from);
+			if page_token.is_none() {
+				break;
+			}
+		}
+
+		Ok(models)
+	}
+}
+
+// region:    --- Support
+
+/// `{"models": [{"name", "displayName", "inputTokenLimit", ..}], "nextPageToken"}`
+fn to_model_infos(adapter_kind: AdapterKind, body: &mut Value) -> Result<Vec<ModelInfo>> {
+	// A last empty page may have no `models`.
+	if body.get("models").is_none() {
+		return Ok(Vec::new());
+	}
+
+	let models = take_model_list(adapter_kind, body, "/models")?
+		.into_iter()
+		.filter_map(|item| {
+			let name = item.get("name")?.as_str()?;
+			Some(ModelInfo {
+				id: name.strip_prefix("models/").unwrap_or(name).to_string(),
+				display_name: item.get("displayName").and_then(Value::as_str).map(String::from),
+				created: None,
+				owned_by: None,
+				context_window: item
+					.get("inputTokenLimit")
+					.and_then(Value::as_u64)
+					.and_then(|size| u32::try_from(size).ok()),
+			})
+		})
+		.collect();
+
+	Ok(models)
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_gemini_to_model_infos_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let mut body = json!({
+			"models": [{
+				"name": "models/gemini-2.5-flash",
+				"displayName": "Gemini 2.5 Flash",
+				"inputTokenLimit": 1048576,
+				"outputTokenLimit": 65536,
+				"supportedGenerationMethods": ["generateContent", "countTokens"]
+			}],
+			"nextPageToken": ""
+		});
+
+		// -- Exec
+		let models = to_model_infos(AdapterKind::Gemini, &mut body)?;
+
+		// -- Check
+		assert_eq!(models.len(), 1);
+		assert_eq!(models[0].id, "gemini-2.5-flash");
+		assert_eq!(models[0].display_name.as_deref(), Some("Gemini 2.5 Flash"));
+		assert_eq!(models[0].context_window, Some(1048576));
+		assert!(to_model_infos(AdapterKind::Gemini, &mut json!({}))?.is_empty());
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,109 @@
+//! Gemini errors: <https://ai.google.dev/gemini-api/docs/troubleshooting#error-codes>
+//!
+//! Body: `{"error": {"code": 429, "message", "status": "RESOURCE_EXHAUSTED", "details": [..]}}` (in an array for the streams).
+//! A response without candidate content (e.g., blocked by the safety settings) is also an error (see `finishReason`).
+
+use crate::adapter::adapters::support::classify_provider_error;
+use crate::adapter::gemini::GeminiAdapter;
+use crate::{ErrorKind, ProviderError};
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+use serde_json::Value;
+
+/// The finish reasons of a response blocked by the provider.
+const FILTERED_FINISH_REASONS: &[&str] = &[
+	"SAFETY",
+	"RECITATION",
+	"BLOCKLIST",
+	"PROHIBITED_CONTENT",
+	"SPII",
+	"IMAGE_SAFETY",
+];
+
+impl GeminiAdapter {
+	pub(crate) fn to_provider_error(
+		status: Option<StatusCode>,
+		headers: Option<&HeaderMap>,
+		body: &Value,
+	) -> ProviderError {
+		let mut provider_error = ProviderError::new(status, headers);
+
+		let body = body.as_array().and_then(|items| items.first()).unwrap_or(body);
+
+		// -- Response without content
+		if let Some(finish_reason) = body.get("finishReason").and_then(Value::as_str) {
+			provider_error.code = Some(finish_reason.to_string());
+			provider_error.message = Some(format!("No content in response (finish reason: {finish_reason})"));
+			let code_kind = FILTERED_FINISH_REASONS
+				.contains(&finish_reason)
+				.then_some(ErrorKind::ContentFiltered);
+			classify_provider_error(&mut provider_error, code_kind);
+			return provider_error;
+		}
+
+		// -- Error
+		let error = body.get("error").unwrap_or(body);
+		provider_error.code = error.get("status").and_then(Value::as_str).map(String::from);
+		provider_error.message = error
+			.get("message")
+			.and_then(Value::as_str)
+			.or_else(|| error.as_str())
+			.map(String::from);
+
+		// e.g., an invalid API key is an `INVALID_ARGUMENT` with an `API_KEY_INVALID` reason detail.
+		let api_key_invalid = error.get("details").and_then(Value::as_array).is_some_and(|details| {
+			details
+				.iter()
+				.any(|detail| detail.get("reason").and_then(Value::as_str) == Some("API_KEY_INVALID"))
+		});
+		let code_kind = match provider_error.code.as_deref() {
+			_ if api_key_invalid => Some(ErrorKind::InvalidAuth),
+			Some("RESOURCE_EXHAUSTED") => Some(ErrorKind::RateLimited),
+			Some("UNAUTHENTICATED" | "PERMISSION_DENIED") => Some(ErrorKind::InvalidAuth),
+			Some("NOT_FOUND") => Some(ErrorKind::ModelNotFound),
+			Some("UNAVAILABLE") => Some(ErrorKind::Overloaded),
+			Some("INTERNAL") => Some(ErrorKind::ServerError),
+			Some("DEADLINE_EXCEEDED") => Some(ErrorKind::Timeout),
+			_ => None,
+		};
+
+		classify_provider_error(&mut provider_error, code_kind);
+		provider_error
+	}
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_gemini_provider_error_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let invalid_key_body = json!([{"error": {
+			"code": 400,
+			"message": "API key not valid. Please pass a valid API key.",
+			"status": "INVALID_ARGUMENT",
+			"details": [{"@type": "type.googleapis.com/google.rpc.ErrorInfo", "reason": "API_KEY_INVALID"}]
+		}}]);
+		let blocked_body = json!({"finishReason": "SAFETY", "usageMetadata": null});
+
+		// -- Exec
+		let invalid_key = GeminiAdapter::to_provider_error(Some(StatusCode::BAD_REQUEST), None, &invalid_key_body);
+		let blocked = GeminiAdapter::to_provider_error(None, None, &blocked_body);
+
+		// -- Check
+		assert_eq!(invalid_key.kind, ErrorKind::InvalidAuth);
+		assert_eq!(invalid_key.code.as_deref(), Some("INVALID_ARGUMENT"));
+		assert_eq!(blocked.kind, ErrorKind::ContentFiltered);
+		assert_eq!(blocked.code.as_deref(), Some("SAFETY"));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
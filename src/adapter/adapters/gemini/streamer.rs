@@ -56,6 +56,9 @@ impl futures::Stream for GeminiStreamer {
 								captured_reasoning_content: self.captured_data.reasoning_content.take(),
 								captured_tool_calls: self.captured_data.tool_calls.take(),
 								captured_thought_signature: self.captured_data.thought_signature.take(),
+								timings: None,
+								captured_content_filter_results: None,
+								captured_finish_reason: self.captured_data.finish_reason.take(),
 							};
 
 							InterStreamEvent::End(inter_stream_end)
@@ -79,6 +82,13 @@ impl futures::Stream for GeminiStreamer {
 								}
 							};
 
+							// The finish reason is on the last block (with its last content, if any)
+							if let Some(finish_reason) =
+								json_block.pointer("/candidates/0/finishReason").and_then(Value::as_str)
+							{
+								self.captured_data.finish_reason = Some(finish_reason.to_string());
+							}
+
 							// -- Extract the Gemini Response
 							let gemini_response =
 								match GeminiAdapter::body_to_gemini_chat_response(&self.options.model_iden, json_block)
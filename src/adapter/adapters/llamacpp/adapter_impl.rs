@@ -2,6 +2,7 @@
 
 use std::num::NonZeroU32;
 use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
 use llama_cpp_2::{
 	context::{LlamaContext, params::LlamaContextParams},
@@ -17,9 +18,8 @@ use crate::chat::{
 	ChatMessage, ChatOptionsSet, ChatRequest, ChatResponse, ChatRole, ChatStreamResponse, MessageContent, Usage,
 };
 use crate::resolver::{AuthData, Endpoint};
-use crate::webc::WebResponse;
-use crate::{Error, ModelIden, Result, ServiceTarget};
-use reqwest::RequestBuilder;
+use crate::webc::{WebResponse, WebStreamRequest};
+use crate::{CancellationToken, Error, ModelIden, Result, ServiceTarget};
 
 use super::model_manager::{ModelManager, resolve_model_path};
 use super::schema_to_grammar::tools_to_gbnf;
@@ -34,17 +34,49 @@ struct GenerationOptions {
 	temperature: Option<f64>,
 	top_p: Option<f64>,
 	seed: Option<i32>,
+	// -- Stop conditions, checked between tokens
+	timeout: Option<Duration>,
+	deadline: Option<Instant>,
+	cancellation_token: Option<CancellationToken>,
 }
 
 impl GenerationOptions {
 	fn from_options_set(options: &ChatOptionsSet<'_, '_>) -> Self {
+		let timeout = options.timeout();
 		Self {
 			max_tokens: options.max_tokens(),
 			temperature: options.temperature(),
 			top_p: options.top_p(),
 			seed: options.seed().map(|s| s as i32),
+			timeout,
+			deadline: timeout.map(|timeout| Instant::now() + timeout),
+			cancellation_token: options.cancellation_token().cloned(),
 		}
 	}
+
+	/// True when cancelled or past the deadline.
+	fn is_stopped(&self) -> bool {
+		self.cancellation_token.as_ref().is_some_and(CancellationToken::is_cancelled)
+			|| self.deadline.is_some_and(|deadline| Instant::now() >= deadline)
+	}
+
+	/// Returns the error to stop the generation with, if cancelled or past the deadline.
+	fn stop_error(&self, model_iden: &ModelIden) -> Option<Error> {
+		if self.cancellation_token.as_ref().is_some_and(CancellationToken::is_cancelled) {
+			return Some(Error::Cancelled {
+				model_iden: model_iden.clone(),
+			});
+		}
+		if let (Some(timeout), Some(deadline)) = (self.timeout, self.deadline)
+			&& Instant::now() >= deadline
+		{
+			return Some(Error::RequestTimeout {
+				model_iden: model_iden.clone(),
+				timeout,
+			});
+		}
+		None
+	}
 }
 
 /// LlamaCpp adapter for native local model inference using llama.cpp bindings
@@ -105,7 +137,7 @@ impl Adapter for LlamaCppAdapter {
 
 	fn to_chat_stream(
 		_model_iden: ModelIden,
-		_reqwest_builder: RequestBuilder,
+		_stream_request: WebStreamRequest,
 		_options_set: ChatOptionsSet<'_, '_>,
 	) -> Result<ChatStreamResponse> {
 		// Not used for native adapter
@@ -323,6 +355,11 @@ impl LlamaCppAdapter {
 			&tool_config,
 		)?;
 
+		// The generation stops early when cancelled or past the deadline
+		if let Some(err) = options.stop_error(model_iden) {
+			return Err(err);
+		}
+
 		// Parse tool calls if present
 		let content = if let Some(tools) = &chat_req.tools {
 			if !tools.is_empty() && contains_tool_calls(&generated_text, &tool_config) {
@@ -351,7 +388,12 @@ impl LlamaCppAdapter {
 				total_tokens: None,
 				..Default::default()
 			},
+			finish_reason: None,
 			captured_raw_body: None,
+			fallback_attempts: Vec::new(),
+			cached: false,
+			timings: None,
+			content_filter_results: None,
 		})
 	}
 
@@ -414,6 +456,11 @@ impl LlamaCppAdapter {
 
 		// Generation loop
 		for _ in 0..max_tokens {
+			// Cancelled or past the deadline (the error is returned by the caller)
+			if options.is_stopped() {
+				break;
+			}
+
 			// Sample next token
 			let token = sampler.sample(context, (batch.n_tokens() - 1) as i32);
 			sampler.accept(token);
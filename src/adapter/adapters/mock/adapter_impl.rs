@@ -0,0 +1,154 @@
+use crate::adapter::adapters::mock::mock_script::{MockChatBody, MockPayload};
+use crate::adapter::adapters::mock::streamer::MockStreamer;
+use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
+use crate::chat::{ChatOptions, ChatOptionsSet, ChatRequest, ChatResponse, ChatStream, ChatStreamResponse};
+use crate::embed::{EmbedOptionsSet, EmbedRequest, EmbedResponse};
+use crate::resolver::{AuthData, Endpoint};
+use crate::webc::{EventSource, WebResponse, WebStreamRequest};
+use crate::{Error, Headers, ModelIden, Result, ServiceTarget};
+
+pub struct MockAdapter;
+
+impl Adapter for MockAdapter {
+	/// The `mock://` scheme is served by the `Mock` of the `WebClient` (no network call).
+	fn default_endpoint() -> Endpoint {
+		const BASE_URL: &str = "mock://genai/";
+		Endpoint::from_static(BASE_URL)
+	}
+
+	fn default_auth() -> AuthData {
+		AuthData::from_single("mock")
+	}
+
+	async fn all_model_names(_kind: AdapterKind) -> Result<Vec<String>> {
+		Ok(Vec::new())
+	}
+
+	fn get_service_url(_model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> Result<String> {
+		let base_url = endpoint.base_url();
+		let url = match service_type {
+			ServiceType::Chat | ServiceType::ChatStream => format!("{base_url}chat"),
+			ServiceType::Embed => format!("{base_url}embed"),
+			ServiceType::ImageGenerationImagen | ServiceType::VideoGenerationVeo => {
+				unreachable!("Image/Video generation not supported by this adapter")
+			}
+		};
+
+		Ok(url)
+	}
+
+	fn to_web_request_data(
+		target: ServiceTarget,
+		service_type: ServiceType,
+		chat_req: ChatRequest,
+		options_set: ChatOptionsSet<'_, '_>,
+	) -> Result<WebRequestData> {
+		let ServiceTarget { endpoint, model, .. } = target;
+
+		let url = Self::get_service_url(&model, service_type, endpoint)?;
+		let (model_name, _) = model.model_name.as_model_name_and_namespace();
+
+		let payload = MockPayload {
+			model: model_name.to_string(),
+			chat_request: chat_req,
+			chat_options: effective_chat_options(&options_set),
+			stream: matches!(service_type, ServiceType::ChatStream),
+		};
+		let payload = serde_json::to_value(payload)?;
+
+		Ok(WebRequestData {
+			url,
+			headers: Headers::default(),
+			payload,
+		})
+	}
+
+	fn to_chat_response(
+		model_iden: ModelIden,
+		web_response: WebResponse,
+		options_set: ChatOptionsSet<'_, '_>,
+	) -> Result<ChatResponse> {
+		let WebResponse { body, .. } = web_response;
+
+		let captured_raw_body = options_set.capture_raw_body().unwrap_or_default().then(|| body.clone());
+
+		let MockChatBody {
+			content,
+			reasoning_content,
+			usage,
+		} = serde_json::from_value(body)?;
+
+		Ok(ChatResponse {
+			content,
+			reasoning_content,
+			provider_model_iden: model_iden.clone(),
+			model_iden,
+			usage,
+			finish_reason: None,
+			captured_raw_body,
+			fallback_attempts: Vec::new(),
+			cached: false,
+			timings: None,
+			content_filter_results: None,
+		})
+	}
+
+	fn to_chat_stream(
+		model_iden: ModelIden,
+		stream_request: WebStreamRequest,
+		options_set: ChatOptionsSet<'_, '_>,
+	) -> Result<ChatStreamResponse> {
+		let event_source = EventSource::new(stream_request)?;
+		let mock_stream = MockStreamer::new(event_source, model_iden.clone(), options_set);
+		let chat_stream = ChatStream::from_inter_stream(mock_stream);
+		Ok(ChatStreamResponse {
+			model_iden,
+			stream: chat_stream,
+		})
+	}
+
+	fn to_embed_request_data(
+		_service_target: ServiceTarget,
+		_embed_req: EmbedRequest,
+		_options_set: EmbedOptionsSet<'_, '_>,
+	) -> Result<WebRequestData> {
+		Err(Error::AdapterNotSupported {
+			adapter_kind: AdapterKind::Mock,
+			feature: "embeddings".to_string(),
+		})
+	}
+
+	fn to_embed_response(
+		_model_iden: ModelIden,
+		_web_response: WebResponse,
+		_options_set: EmbedOptionsSet<'_, '_>,
+	) -> Result<EmbedResponse> {
+		Err(Error::AdapterNotSupported {
+			adapter_kind: AdapterKind::Mock,
+			feature: "embeddings".to_string(),
+		})
+	}
+}
+
+// region:    --- Support
+
+/// The effective options as one `ChatOptions` (each request level value over the client level one).
+fn effective_chat_options(options_set: &ChatOptionsSet<'_, '_>) -> ChatOptions {
+	let to_map = |options: Option<&ChatOptions>| match options.map(serde_json::to_value) {
+		Some(Ok(serde_json::Value::Object(map))) => map,
+		_ => serde_json::Map::new(),
+	};
+
+	let mut merged = to_map(options_set.client_options());
+	merged.extend(
+		to_map(options_set.chat_options())
+			.into_iter()
+			.filter(|(_, value)| !value.is_null()),
+	);
+	let mut options: ChatOptions = serde_json::from_value(merged.into()).unwrap_or_default();
+	// `stop_sequences` is not optional, so it follows the `stop_sequences()` resolution.
+	options.stop_sequences = options_set.stop_sequences().to_vec();
+	options
+}
+
+// endregion: --- Support
@@ -0,0 +1,379 @@
+use crate::chat::{ChatOptions, ChatRequest, MessageContent, ToolCall, Usage};
+use crate::webc::{self, WebResponse, new_reqwest_response};
+use reqwest::StatusCode;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+// region:    --- Mock
+
+/// The script of the mock adapter: the responses to serve, in order, and the requests received.
+///
+/// Cloning a `Mock` shares the same script, so a clone can be kept to assert on the requests
+/// after giving the mock to `ClientBuilder::with_mock(..)`.
+#[derive(Debug, Clone, Default)]
+pub struct Mock {
+	state: Arc<Mutex<MockState>>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+	responses: VecDeque<MockResponse>,
+	default_response: Option<MockResponse>,
+	requests: Vec<MockRequest>,
+}
+
+/// Constructors & Chainable Setters
+impl Mock {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Add a response to serve (after the ones already scripted).
+	pub fn with_response(self, response: MockResponse) -> Self {
+		self.push_response(response);
+		self
+	}
+
+	/// Set the response served when all the scripted responses were served.
+	/// Without it, an exhausted script returns a `501` error response.
+	pub fn with_default_response(self, response: MockResponse) -> Self {
+		self.lock_state().default_response = Some(response);
+		self
+	}
+}
+
+/// Script
+impl Mock {
+	/// Add a response to serve (e.g., once the client is built).
+	pub fn push_response(&self, response: MockResponse) {
+		self.lock_state().responses.push_back(response);
+	}
+
+	/// The number of scripted responses not yet served.
+	pub fn remaining_responses(&self) -> usize {
+		self.lock_state().responses.len()
+	}
+
+	/// The requests received, in order.
+	pub fn requests(&self) -> Vec<MockRequest> {
+		self.lock_state().requests.clone()
+	}
+
+	/// The last request received.
+	pub fn last_request(&self) -> Option<MockRequest> {
+		self.lock_state().requests.last().cloned()
+	}
+}
+
+/// Crate internals (the "server" side, called by the `WebClient` for the `mock://` URLs)
+impl Mock {
+	pub(crate) async fn do_post(&self, payload: &Value) -> webc::Result<WebResponse> {
+		let response = self.next_response(payload);
+		tokio::time::sleep(response.delay).await;
+
+		let headers = response.header_map();
+		if let Some(error) = response.error {
+			return Err(webc::Error::ResponseFailedStatus {
+				status: error.status_code(),
+				body: error.body,
+				headers: Box::new(headers),
+			});
+		}
+
+		let body = MockChatBody {
+			content: response.content,
+			reasoning_content: response.reasoning_content,
+			usage: response.usage,
+		};
+		Ok(WebResponse {
+			status: StatusCode::OK,
+			// Plain data with string keys, so the serialization cannot fail.
+			body: serde_json::to_value(body).unwrap_or_default(),
+			headers,
+		})
+	}
+
+	/// Returns a `text/event-stream` response of `MockStreamEvent` data, with the chunk delays.
+	pub(crate) fn stream_response(&self, payload: &Value) -> reqwest::Response {
+		let response = self.next_response(payload);
+		let mut headers = response.header_map();
+
+		if let Some(error) = response.error {
+			let body = reqwest::Body::from(error.body.clone());
+			return new_reqwest_response(error.status_code(), headers, body);
+		}
+
+		// -- Build the events (delay before, event)
+		let chunk_delay = response.chunk_delay;
+		let mut events: Vec<(Duration, MockStreamEvent)> = Vec::new();
+		if let Some(reasoning_content) = response.reasoning_content {
+			events.push((Duration::ZERO, MockStreamEvent::ReasoningChunk(reasoning_content)));
+		}
+		let chunks_start = events.len();
+		let chunks = match response.chunks {
+			Some(chunks) => chunks,
+			None => response.content.joined_texts().into_iter().collect(),
+		};
+		events.extend(chunks.into_iter().map(|chunk| (chunk_delay, MockStreamEvent::Chunk(chunk))));
+		events.extend(
+			response
+				.content
+				.into_tool_calls()
+				.into_iter()
+				.map(|tool_call| (chunk_delay, MockStreamEvent::ToolCall(tool_call))),
+		);
+		events.push((Duration::ZERO, MockStreamEvent::End { usage: response.usage }));
+
+		// -- Serialize as SSE, failing after `stream_error_after` chunks
+		let mut items: Vec<(Duration, std::io::Result<String>)> = events
+			.into_iter()
+			.map(|(delay, event)| {
+				let data = serde_json::to_string(&event).unwrap_or_default();
+				(delay, Ok(format!("data: {data}\n\n")))
+			})
+			.collect();
+		if let Some(error_after) = response.stream_error_after {
+			items.truncate(chunks_start + error_after);
+			items.push((chunk_delay, Err(std::io::Error::other("mock stream error"))));
+		}
+		if let Some((first_delay, _)) = items.first_mut() {
+			*first_delay += response.delay;
+		}
+
+		let body = futures::StreamExt::then(futures::stream::iter(items), |(delay, item)| async move {
+			if !delay.is_zero() {
+				tokio::time::sleep(delay).await;
+			}
+			item
+		});
+
+		headers.insert(
+			reqwest::header::CONTENT_TYPE,
+			HeaderValue::from_static("text/event-stream"),
+		);
+		new_reqwest_response(StatusCode::OK, headers, reqwest::Body::wrap_stream(body))
+	}
+
+	/// Record the request and take the next response.
+	fn next_response(&self, payload: &Value) -> MockResponse {
+		let mut state = self.lock_state();
+
+		match serde_json::from_value::<MockPayload>(payload.clone()) {
+			Ok(payload) => state.requests.push(MockRequest {
+				model_name: payload.model,
+				chat_request: payload.chat_request,
+				chat_options: payload.chat_options,
+				stream: payload.stream,
+			}),
+			Err(err) => return MockResponse::error(400, format!("Invalid mock request payload: {err}")),
+		}
+
+		state
+			.responses
+			.pop_front()
+			.or_else(|| state.default_response.clone())
+			.unwrap_or_else(|| MockResponse::error(501, "No scripted mock response left (see Mock::with_response)"))
+	}
+
+	fn lock_state(&self) -> MutexGuard<'_, MockState> {
+		self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+	}
+}
+
+// endregion: --- Mock
+
+// region:    --- MockResponse
+
+/// A scripted response of the `Mock`.
+///
+/// The same response serves both `exec_chat` and `exec_chat_stream` (streamed as reasoning, text chunks, tool calls, end).
+#[derive(Debug, Clone, Default)]
+pub struct MockResponse {
+	/// The response content (text and/or tool calls).
+	pub content: MessageContent,
+
+	/// The reasoning content (streamed as one reasoning chunk).
+	pub reasoning_content: Option<String>,
+
+	/// The usage reported by the response (and the stream end).
+	pub usage: Usage,
+
+	/// The text chunks of the stream (default to the text content as one chunk).
+	pub chunks: Option<Vec<String>>,
+
+	/// The delay before each stream chunk (text or tool call).
+	pub chunk_delay: Duration,
+
+	/// The delay before the response (or the stream first event).
+	pub delay: Duration,
+
+	/// Fail the stream (transport error) after this number of text chunks.
+	pub stream_error_after: Option<usize>,
+
+	/// Respond with this error status rather than the content.
+	pub error: Option<MockError>,
+
+	/// The response headers (e.g., `retry-after`, `x-ratelimit-remaining-requests`).
+	pub headers: Vec<(String, String)>,
+}
+
+/// An HTTP error response of the `Mock`.
+#[derive(Debug, Clone)]
+pub struct MockError {
+	pub status: u16,
+	pub body: String,
+}
+
+/// Constructors
+impl MockResponse {
+	/// A text response.
+	pub fn text(text: impl Into<String>) -> Self {
+		Self {
+			content: MessageContent::from_text(text),
+			..Default::default()
+		}
+	}
+
+	/// A tool calls response.
+	pub fn tool_calls(tool_calls: Vec<ToolCall>) -> Self {
+		Self {
+			content: MessageContent::from_tool_calls(tool_calls),
+			..Default::default()
+		}
+	}
+
+	/// A text response streamed with the given chunks (the text content is their concatenation).
+	pub fn chunks<I, S>(chunks: I) -> Self
+	where
+		I: IntoIterator<Item = S>,
+		S: Into<String>,
+	{
+		let chunks: Vec<String> = chunks.into_iter().map(Into::into).collect();
+		Self {
+			content: MessageContent::from_text(chunks.concat()),
+			chunks: Some(chunks),
+			..Default::default()
+		}
+	}
+
+	/// An error response with the HTTP status and body (e.g., `429`).
+	pub fn error(status: u16, body: impl Into<String>) -> Self {
+		Self {
+			error: Some(MockError {
+				status,
+				body: body.into(),
+			}),
+			..Default::default()
+		}
+	}
+}
+
+/// Chainable Setters
+impl MockResponse {
+	pub fn with_reasoning_content(mut self, reasoning_content: impl Into<String>) -> Self {
+		self.reasoning_content = Some(reasoning_content.into());
+		self
+	}
+
+	pub fn with_usage(mut self, usage: Usage) -> Self {
+		self.usage = usage;
+		self
+	}
+
+	pub fn with_chunk_delay(mut self, chunk_delay: Duration) -> Self {
+		self.chunk_delay = chunk_delay;
+		self
+	}
+
+	pub fn with_delay(mut self, delay: Duration) -> Self {
+		self.delay = delay;
+		self
+	}
+
+	pub fn with_stream_error_after(mut self, chunk_count: usize) -> Self {
+		self.stream_error_after = Some(chunk_count);
+		self
+	}
+
+	pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+		self.headers.push((name.into(), value.into()));
+		self
+	}
+}
+
+impl MockResponse {
+	fn header_map(&self) -> HeaderMap {
+		self.headers
+			.iter()
+			.filter_map(|(name, value)| {
+				let name = HeaderName::from_bytes(name.as_bytes()).ok()?;
+				let value = HeaderValue::from_str(value).ok()?;
+				Some((name, value))
+			})
+			.collect()
+	}
+}
+
+impl MockError {
+	fn status_code(&self) -> StatusCode {
+		StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+	}
+}
+
+// endregion: --- MockResponse
+
+// region:    --- MockRequest
+
+/// A request received by the `Mock`.
+#[derive(Debug, Clone)]
+pub struct MockRequest {
+	/// The model name, without the `mock::` namespace.
+	pub model_name: String,
+
+	pub chat_request: ChatRequest,
+
+	/// The effective options (request options over client options).
+	pub chat_options: ChatOptions,
+
+	/// True for `exec_chat_stream`.
+	pub stream: bool,
+}
+
+// endregion: --- MockRequest
+
+// region:    --- Wire Types
+
+// NOTE: The wire format between the `MockAdapter` and the `Mock` is internal.
+
+/// The request payload built by the `MockAdapter`.
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct MockPayload {
+	pub model: String,
+	pub chat_request: ChatRequest,
+	pub chat_options: ChatOptions,
+	pub stream: bool,
+}
+
+/// The body of a chat response.
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct MockChatBody {
+	pub content: MessageContent,
+	pub reasoning_content: Option<String>,
+	pub usage: Usage,
+}
+
+/// The data of a stream event.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(super) enum MockStreamEvent {
+	ReasoningChunk(String),
+	Chunk(String),
+	ToolCall(ToolCall),
+	End { usage: Usage },
+}
+
+// endregion: --- Wire Types
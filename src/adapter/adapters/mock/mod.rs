@@ -0,0 +1,19 @@
+//! Scripted mock adapter, to unit test code built on `genai` without calling a provider (feature `mock`).
+//!
+//! - Models in the `mock::` namespace (e.g., `mock::my-model`) are served by the `Mock` set with `ClientBuilder::with_mock(..)`.
+//! - Each call takes the next scripted `MockResponse` (text, tool calls, stream chunks with delays, usage, or error).
+//! - The received requests are recorded as `MockRequest` (see `Mock::requests()`).
+//!
+//! The responses go through the same web layer as the other adapters (middlewares, retry, rate limiting, ...),
+//! so an error response (e.g., 429 with `retry-after`) behaves like a provider one.
+
+// region:    --- Modules
+
+mod adapter_impl;
+mod mock_script;
+mod streamer;
+
+pub use adapter_impl::*;
+pub use mock_script::*;
+
+// endregion: --- Modules
@@ -0,0 +1,106 @@
+use crate::adapter::adapters::mock::mock_script::MockStreamEvent;
+use crate::adapter::adapters::support::{StreamerCapturedData, StreamerOptions};
+use crate::adapter::inter_stream::{InterStreamEnd, InterStreamEvent};
+use crate::chat::ChatOptionsSet;
+use crate::webc::EventSource;
+use crate::{Error, ModelIden, Result};
+use reqwest_eventsource::Event;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+pub struct MockStreamer {
+	inner: EventSource,
+	options: StreamerOptions,
+
+	// -- Set by the poll_next
+	/// Flag to prevent polling the EventSource after the End event
+	done: bool,
+
+	captured_data: StreamerCapturedData,
+}
+
+impl MockStreamer {
+	pub fn new(inner: EventSource, model_iden: ModelIden, options_set: ChatOptionsSet<'_, '_>) -> Self {
+		Self {
+			inner,
+			done: false,
+			options: StreamerOptions::new(model_iden, &options_set),
+			captured_data: Default::default(),
+		}
+	}
+}
+
+impl futures::Stream for MockStreamer {
+	type Item = Result<InterStreamEvent>;
+
+	fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		if self.done {
+			return Poll::Ready(None);
+		}
+
+		let Poll::Ready(event) = Pin::new(&mut self.inner).poll_next(cx) else {
+			return Poll::Pending;
+		};
+
+		match event {
+			Some(Ok(Event::Open)) => Poll::Ready(Some(Ok(InterStreamEvent::Start))),
+			Some(Ok(Event::Message(message))) => {
+				let event: MockStreamEvent =
+					serde_json::from_str(&message.data).map_err(|serde_error| Error::StreamParse {
+						model_iden: self.options.model_iden.clone(),
+						serde_error,
+					})?;
+
+				let inter_event = match event {
+					MockStreamEvent::ReasoningChunk(reasoning) => {
+						if self.options.capture_reasoning_content {
+							match self.captured_data.reasoning_content {
+								Some(ref mut r) => r.push_str(&reasoning),
+								None => self.captured_data.reasoning_content = Some(reasoning.clone()),
+							}
+						}
+						InterStreamEvent::ReasoningChunk(reasoning)
+					}
+					MockStreamEvent::Chunk(content) => {
+						if self.options.capture_content {
+							match self.captured_data.content {
+								Some(ref mut c) => c.push_str(&content),
+								None => self.captured_data.content = Some(content.clone()),
+							}
+						}
+						InterStreamEvent::Chunk(content)
+					}
+					MockStreamEvent::ToolCall(tool_call) => {
+						if self.options.capture_tool_calls {
+							self.captured_data
+								.tool_calls
+								.get_or_insert_with(Vec::new)
+								.push(tool_call.clone());
+						}
+						InterStreamEvent::ToolCallChunk(tool_call)
+					}
+					MockStreamEvent::End { usage } => {
+						self.done = true;
+						InterStreamEvent::End(InterStreamEnd {
+							captured_usage: self.options.capture_usage.then_some(usage),
+							captured_text_content: self.captured_data.content.take(),
+							captured_reasoning_content: self.captured_data.reasoning_content.take(),
+							captured_tool_calls: self.captured_data.tool_calls.take(),
+							captured_thought_signature: None,
+							timings: None,
+							captured_content_filter_results: None,
+							captured_finish_reason: None,
+						})
+					}
+				};
+
+				Poll::Ready(Some(Ok(inter_event)))
+			}
+			Some(Err(err)) => {
+				tracing::error!("Error: {}", err);
+				Poll::Ready(Some(Err(Error::ReqwestEventSource(err.into()))))
+			}
+			None => Poll::Ready(None),
+		}
+	}
+}
@@ -1,6 +1,8 @@
 mod support;
 
 pub(super) mod anthropic;
+pub(super) mod azure_openai;
+pub(super) mod bedrock;
 pub(super) mod cohere;
 pub(super) mod deepseek;
 pub(super) mod fireworks;
@@ -8,6 +10,8 @@ pub(super) mod gemini;
 pub(super) mod groq;
 #[cfg(feature = "llamacpp")]
 pub(super) mod llamacpp;
+#[cfg(feature = "mock")]
+pub(super) mod mock;
 pub(super) mod nebius;
 pub(super) mod ollama;
 pub(super) mod openai;
@@ -0,0 +1,232 @@
+//! OpenAI Batch API: <https://platform.openai.com/docs/api-reference/batch>
+//!
+//! The requests are uploaded as a JSONL file (purpose `batch`), then a batch is created for this file.
+//! The results (and the failed requests) are served as JSONL output files.
+
+use crate::adapter::adapters::support::{batch_web_error, get_api_key, json_count, parse_jsonl};
+use crate::adapter::openai::OpenAIAdapter;
+use crate::adapter::{Adapter, BatchItemData};
+use crate::chat::{BatchRequestCounts, BatchStatus, ChatBatch, ChatBatchResult, ChatOptionsSet};
+use crate::webc::{WebClient, WebResponse};
+use crate::{Error, Headers, ModelIden, Result, ServiceTarget};
+use serde_json::{Value, json};
+use value_ext::JsonValueExt;
+
+const BATCH_ENDPOINT: &str = "/v1/chat/completions";
+const COMPLETION_WINDOW: &str = "24h";
+
+impl OpenAIAdapter {
+	pub(crate) async fn submit_chat_batch(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		items: Vec<BatchItemData>,
+	) -> Result<ChatBatch> {
+		let (base_url, headers) = batch_base_and_headers(&target)?;
+		let model_iden = target.model;
+
+		// -- Upload the input file
+		let input = items
+			.into_iter()
+			.map(|item| {
+				json!({
+					"custom_id": item.custom_id,
+					"method": "POST",
+					"url": BATCH_ENDPOINT,
+					"body": item.request.payload,
+				})
+				.to_string()
+			})
+			.collect::<Vec<_>>()
+			.join("\n");
+		let file_res = web_client
+			.do_post_file(
+				&format!("{base_url}files"),
+				&headers,
+				&[("purpose", "batch")],
+				"batch_input.jsonl",
+				input,
+			)
+			.await
+			.map_err(batch_web_error(&model_iden))?;
+		let input_file_id: String = file_res.body.x_get("id").map_err(|err| Error::BatchResponse {
+			model_iden: model_iden.clone(),
+			cause: format!("Uploaded file has no id: {err}"),
+		})?;
+
+		// -- Create the batch
+		let payload = json!({
+			"input_file_id": input_file_id,
+			"endpoint": BATCH_ENDPOINT,
+			"completion_window": COMPLETION_WINDOW,
+		});
+		let res = web_client
+			.do_post(&format!("{base_url}batches"), &headers, payload)
+			.await
+			.map_err(batch_web_error(&model_iden))?;
+
+		to_chat_batch(model_iden, res.body)
+	}
+
+	pub(crate) async fn poll_batch(web_client: &WebClient, target: ServiceTarget, batch_id: &str) -> Result<ChatBatch> {
+		let (base_url, headers) = batch_base_and_headers(&target)?;
+		let headers: Vec<(String, String)> = headers.into_iter().collect();
+		let res = web_client
+			.do_get(&format!("{base_url}batches/{batch_id}"), &headers)
+			.await
+			.map_err(batch_web_error(&target.model))?;
+
+		to_chat_batch(target.model, res.body)
+	}
+
+	pub(crate) async fn fetch_batch_results(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		batch: &ChatBatch,
+		options_set: ChatOptionsSet<'_, '_>,
+	) -> Result<Vec<ChatBatchResult>> {
+		let (base_url, headers) = batch_base_and_headers(&target)?;
+
+		// The output file has the processed requests, the error file the ones which could not be.
+		let mut results = Vec::new();
+		for file_id in [&batch.results_ref, &batch.errors_ref].into_iter().flatten() {
+			let content = web_client
+				.do_get_text(&format!("{base_url}files/{file_id}/content"), &headers)
+				.await
+				.map_err(batch_web_error(&batch.model_iden))?;
+			for line in parse_jsonl(&batch.model_iden, &content)? {
+				results.push(to_chat_batch_result(&batch.model_iden, line, options_set.clone()));
+			}
+		}
+
+		Ok(results)
+	}
+}
+
+// region:    --- Support
+
+fn batch_base_and_headers(target: &ServiceTarget) -> Result<(String, Headers)> {
+	let api_key = get_api_key(&target.auth, &target.model)?;
+	let headers = Headers::from(("Authorization".to_string(), format!("Bearer {api_key}")));
+	Ok((target.endpoint.base_url().to_string(), headers))
+}
+
+fn to_chat_batch(model_iden: ModelIden, body: Value) -> Result<ChatBatch> {
+	let id: String = body.x_get("id").map_err(|err| Error::BatchResponse {
+		model_iden: model_iden.clone(),
+		cause: format!("Batch has no id: {err}"),
+	})?;
+
+	let status = match body.x_get_str("status").unwrap_or_default() {
+		"completed" => BatchStatus::Completed,
+		"failed" => BatchStatus::Failed,
+		"expired" => BatchStatus::Expired,
+		"cancelling" => BatchStatus::Cancelling,
+		"cancelled" => BatchStatus::Cancelled,
+		// validating, in_progress, finalizing
+		_ => BatchStatus::InProgress,
+	};
+
+	let request_counts = body.get("request_counts").map(|counts| BatchRequestCounts {
+		total: json_count(counts.get("total")),
+		succeeded: json_count(counts.get("completed")),
+		failed: json_count(counts.get("failed")),
+	});
+
+	Ok(ChatBatch {
+		id,
+		model_iden,
+		status,
+		request_counts,
+		results_ref: body.x_get("output_file_id").ok(),
+		errors_ref: body.x_get("error_file_id").ok(),
+	})
+}
+
+/// Line format: `{"custom_id": "..", "response": {"status_code": 200, "body": {..}}, "error": null}`
+fn to_chat_batch_result(
+	model_iden: &ModelIden,
+	mut line: Value,
+	options_set: ChatOptionsSet<'_, '_>,
+) -> ChatBatchResult {
+	let custom_id: String = line.x_take("custom_id").unwrap_or_default();
+	let status_code = line
+		.pointer("/response/status_code")
+		.and_then(Value::as_u64)
+		.unwrap_or_default();
+
+	let result = match (line.x_take::<Value>("/response/body"), status_code) {
+		(Ok(body), 200..=299) => {
+			OpenAIAdapter::to_chat_response(model_iden.clone(), WebResponse::from_body(body), options_set)
+		}
+		(body, _) => {
+			let cause = match line.x_take::<Value>("error") {
+				Ok(error) if !error.is_null() => error.to_string(),
+				_ => body.map(|body| body.to_string()).unwrap_or_else(|_| "No response".to_string()),
+			};
+			Err(Error::BatchRequestFailed {
+				model_iden: model_iden.clone(),
+				custom_id: custom_id.clone(),
+				cause,
+			})
+		}
+	};
+
+	ChatBatchResult { custom_id, result }
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::adapter::AdapterKind;
+
+	#[test]
+	fn test_openai_batch_parse_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let model_iden = ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini");
+		let batch_body = json!({
+			"id": "batch_abc",
+			"status": "finalizing",
+			"request_counts": {"total": 2, "completed": 1, "failed": 1},
+			"output_file_id": "file-out",
+			"error_file_id": null,
+		});
+		let line = json!({
+			"custom_id": "req-1",
+			"response": {"status_code": 200, "body": {
+				"model": "gpt-4o-mini-2024-07-18",
+				"choices": [{"message": {"role": "assistant", "content": "Hello"}}]
+			}},
+			"error": null,
+		});
+		let error_line = json!({
+			"custom_id": "req-2",
+			"response": {"status_code": 400, "body": {"error": {"message": "bad"}}},
+			"error": null,
+		});
+
+		// -- Exec
+		let batch = to_chat_batch(model_iden.clone(), batch_body)?;
+		let ok = to_chat_batch_result(&model_iden, line, ChatOptionsSet::default());
+		let failed = to_chat_batch_result(&model_iden, error_line, ChatOptionsSet::default());
+
+		// -- Check
+		assert_eq!(batch.id, "batch_abc");
+		assert_eq!(batch.status, BatchStatus::InProgress);
+		assert_eq!(batch.request_counts.map(|c| c.failed), Some(1));
+		assert_eq!(batch.results_ref.as_deref(), Some("file-out"));
+		assert!(batch.errors_ref.is_none());
+		assert_eq!(ok.custom_id, "req-1");
+		assert_eq!(ok.result?.first_text(), Some("Hello"));
+		assert!(matches!(failed.result, Err(Error::BatchRequestFailed { .. })));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -5,8 +5,11 @@
 // region:    --- Modules
 
 mod adapter_impl;
+mod batch;
 mod embed;
+mod models;
 mod openai_custom;
+mod provider_error;
 mod streamer;
 
 pub use adapter_impl::*;
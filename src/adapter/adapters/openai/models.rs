@@ -0,0 +1,99 @@
+//! OpenAI models endpoint: <https://platform.openai.com/docs/api-reference/models/list>
+//!
+//! Also used by the OpenAI compatible adapters (Groq, xAI, DeepSeek, Together, Fireworks, Ollama, ...),
+//! which may add a context window property (e.g., `context_window` for Groq, `context_length` for Together).
+
+use crate::adapter::AdapterKind;
+use crate::adapter::adapters::support::{get_api_key, model_list_web_error, take_model_list};
+use crate::adapter::openai::OpenAIAdapter;
+use crate::webc::WebClient;
+use crate::{ModelInfo, Result, ServiceTarget};
+use serde_json::Value;
+
+/// The context window properties of the OpenAI compatible providers, in order of precedence.
+const CONTEXT_WINDOW_PROPS: &[&str] = &["context_window", "context_length", "max_model_len"];
+
+impl OpenAIAdapter {
+	pub(crate) async fn util_list_models(web_client: &WebClient, target: ServiceTarget) -> Result<Vec<ModelInfo>> {
+		let ServiceTarget { endpoint, auth, model } = target;
+		let adapter_kind = model.adapter_kind;
+		let api_key = get_api_key(&auth, &model)?;
+
+		let url = format!("{}models", endpoint.base_url());
+		let headers = [("Authorization".to_string(), format!("Bearer {api_key}"))];
+		let res = web_client
+			.do_get(&url, &headers)
+			.await
+			.map_err(model_list_web_error(adapter_kind))?;
+
+		to_model_infos(adapter_kind, res.body)
+	}
+}
+
+// region:    --- Support
+
+/// `{"object": "list", "data": [..]}`, or a bare array (e.g., Together).
+fn to_model_infos(adapter_kind: AdapterKind, mut body: Value) -> Result<Vec<ModelInfo>> {
+	let items = match body {
+		Value::Array(items) => items,
+		_ => take_model_list(adapter_kind, &mut body, "/data")?,
+	};
+
+	let models = items
+		.into_iter()
+		.filter_map(|item| {
+			let id = item.get("id")?.as_str()?.to_string();
+			Some(ModelInfo {
+				id,
+				display_name: item.get("display_name").and_then(Value::as_str).map(String::from),
+				created: item.get("created").and_then(Value::as_i64),
+				owned_by: item.get("owned_by").and_then(Value::as_str).map(String::from),
+				context_window: CONTEXT_WINDOW_PROPS
+					.iter()
+					.find_map(|prop| item.get(prop)?.as_u64())
+					.and_then(|size| u32::try_from(size).ok()),
+			})
+		})
+		.collect();
+
+	Ok(models)
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_openai_to_model_infos_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let groq = json!({"object": "list", "data": [
+			{"id": "llama-3.3-70b-versatile", "object": "model", "created": 1733447754, "owned_by": "Meta", "context_window": 131072}
+		]});
+		let together =
+			json!([{"id": "Qwen/Qwen3-235B", "created": 0, "organization": "Qwen", "context_length": 40960}]);
+
+		// -- Exec
+		let groq = to_model_infos(AdapterKind::Groq, groq)?;
+		let together = to_model_infos(AdapterKind::Together, together)?;
+
+		// -- Check
+		assert_eq!(groq[0].id, "llama-3.3-70b-versatile");
+		assert_eq!(groq[0].created, Some(1733447754));
+		assert_eq!(groq[0].owned_by.as_deref(), Some("Meta"));
+		assert_eq!(groq[0].context_window, Some(131072));
+		assert_eq!(together[0].id, "Qwen/Qwen3-235B");
+		assert_eq!(together[0].context_window, Some(40960));
+		assert!(to_model_infos(AdapterKind::OpenAI, json!({"error": "nope"})).is_err());
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,105 @@
+//! OpenAI error codes: <https://platform.openai.com/docs/guides/error-codes>
+//!
+//! Also used by the OpenAI compatible adapters, which mostly share the `{"error": {"message", "type", "code"}}` body.
+
+use crate::adapter::adapters::support::classify_provider_error;
+use crate::adapter::openai::OpenAIAdapter;
+use crate::{ErrorKind, ProviderError};
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+use serde_json::Value;
+
+impl OpenAIAdapter {
+	pub(crate) fn to_provider_error(
+		status: Option<StatusCode>,
+		headers: Option<&HeaderMap>,
+		body: &Value,
+	) -> ProviderError {
+		let mut provider_error = ProviderError::new(status, headers);
+
+		// `{"error": {..}}`, `{"error": "message"}` (e.g., Ollama), or a plain text body.
+		let error = body.get("error").unwrap_or(body);
+		provider_error.message = error
+			.get("message")
+			.and_then(Value::as_str)
+			.or_else(|| error.as_str())
+			.map(String::from);
+
+		// The `code` is the most specific (e.g., `context_length_exceeded` for an `invalid_request_error` type).
+		let codes: Vec<&str> = ["code", "type"]
+			.into_iter()
+			.filter_map(|name| error.get(name)?.as_str())
+			.collect();
+		provider_error.code = codes.first().map(|code| code.to_string());
+		let code_kind = codes.into_iter().find_map(code_kind);
+
+		classify_provider_error(&mut provider_error, code_kind);
+		provider_error
+	}
+}
+
+fn code_kind(code: &str) -> Option<ErrorKind> {
+	let kind = match code {
+		"rate_limit_exceeded" | "rate_limit_error" => ErrorKind::RateLimited,
+		"insufficient_quota" | "billing_hard_limit_reached" | "billing_not_active" => ErrorKind::QuotaExhausted,
+		"context_length_exceeded" | "string_above_max_length" => ErrorKind::ContextLengthExceeded,
+		"content_filter" | "content_policy_violation" => ErrorKind::ContentFiltered,
+		"invalid_api_key" | "invalid_authentication" | "authentication_error" => ErrorKind::InvalidAuth,
+		"model_not_found" => ErrorKind::ModelNotFound,
+		"server_overloaded" | "overloaded" => ErrorKind::Overloaded,
+		"server_error" => ErrorKind::ServerError,
+		_ => return None,
+	};
+	Some(kind)
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use reqwest::header::HeaderValue;
+	use serde_json::json;
+
+	#[test]
+	fn test_openai_provider_error_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let mut headers = HeaderMap::new();
+		headers.insert("x-request-id", HeaderValue::from_static("req_123"));
+		let quota_body = json!({"error": {
+			"message": "You exceeded your current quota, please check your plan and billing details.",
+			"type": "insufficient_quota",
+			"param": null,
+			"code": "insufficient_quota"
+		}});
+		let context_body = json!({"error": {
+			"message": "This model's maximum context length is 128000 tokens.",
+			"type": "invalid_request_error",
+			"code": null
+		}});
+
+		// -- Exec
+		let quota = OpenAIAdapter::to_provider_error(Some(StatusCode::TOO_MANY_REQUESTS), Some(&headers), &quota_body);
+		let context = OpenAIAdapter::to_provider_error(Some(StatusCode::BAD_REQUEST), None, &context_body);
+		let ollama = OpenAIAdapter::to_provider_error(
+			Some(StatusCode::NOT_FOUND),
+			None,
+			&json!({"error": "model 'llama9' not found"}),
+		);
+
+		// -- Check
+		assert_eq!(quota.kind, ErrorKind::QuotaExhausted);
+		assert_eq!(quota.code.as_deref(), Some("insufficient_quota"));
+		assert_eq!(quota.request_id.as_deref(), Some("req_123"));
+		assert_eq!(context.kind, ErrorKind::ContextLengthExceeded);
+		assert_eq!(context.code.as_deref(), Some("invalid_request_error"));
+		assert_eq!(ollama.kind, ErrorKind::ModelNotFound);
+		assert_eq!(ollama.message.as_deref(), Some("model 'llama9' not found"));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
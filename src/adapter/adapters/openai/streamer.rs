@@ -1,10 +1,12 @@
 use crate::adapter::AdapterKind;
 use crate::adapter::adapters::support::{StreamerCapturedData, StreamerOptions};
+use crate::adapter::azure_openai::AzureOpenAIAdapter;
 use crate::adapter::inter_stream::{InterStreamEnd, InterStreamEvent};
 use crate::adapter::openai::OpenAIAdapter;
-use crate::chat::{ChatOptionsSet, ToolCall};
+use crate::chat::{ChatOptionsSet, ContentFilterResults, ToolCall};
+use crate::webc::EventSource;
 use crate::{Error, ModelIden, Result};
-use reqwest_eventsource::{Event, EventSource};
+use reqwest_eventsource::Event;
 use serde_json::Value;
 use std::pin::Pin;
 use std::task::{Context, Poll};
@@ -103,6 +105,13 @@ impl futures::Stream for OpenAIStreamer {
 							captured_reasoning_content: self.captured_data.reasoning_content.take(),
 							captured_tool_calls,
 							captured_thought_signature: None,
+							timings: None,
+							captured_content_filter_results: self
+								.captured_data
+								.content_filter_results
+								.take()
+								.map(Box::new),
+							captured_finish_reason: self.captured_data.finish_reason.take(),
 						};
 
 						return Poll::Ready(Some(Ok(InterStreamEvent::End(inter_stream_end))));
@@ -116,10 +125,18 @@ impl futures::Stream for OpenAIStreamer {
 							serde_error,
 						})?;
 
-					let first_choice: Option<Value> = message_data.x_take("/choices/0").ok();
-
 					let adapter_kind = self.options.model_iden.adapter_kind;
 
+					// -- Azure content filter results
+					// The prompt ones come first (with empty choices), then the completion ones with the choice deltas.
+					if matches!(adapter_kind, AdapterKind::AzureOpenAI)
+						&& let Some(results) = AzureOpenAIAdapter::into_content_filter_results(&message_data)
+					{
+						self.capture_content_filter_results(results);
+					}
+
+					let first_choice: Option<Value> = message_data.x_take("/choices/0").ok();
+
 					// If we have a first choice, then it's a normal message
 					if let Some(mut first_choice) = first_choice {
 						// -- Finish Reason
@@ -127,7 +144,8 @@ impl futures::Stream for OpenAIStreamer {
 						// Since we support only a single choice, we can proceed,
 						// as there might be other messages, and the last one contains data: `[DONE]`
 						// NOTE: xAI has no `finish_reason` when not finished, so, need to just account for both null/absent
-						if let Ok(_finish_reason) = first_choice.x_take::<String>("finish_reason") {
+						if let Ok(finish_reason) = first_choice.x_take::<String>("finish_reason") {
+							self.captured_data.finish_reason = Some(finish_reason);
 							// NOTE: For Groq, the usage is captured when finish_reason indicates stopping, and in the `/x_groq/usage`
 							if self.options.capture_usage {
 								match adapter_kind {
@@ -259,9 +277,14 @@ impl futures::Stream for OpenAIStreamer {
 					}
 					// -- Usage message
 					else {
+						// Azure also has empty choices for the prompt filter results (without usage)
+						let azure_without_usage = matches!(adapter_kind, AdapterKind::AzureOpenAI)
+							&& message_data.get("usage").is_none_or(Value::is_null);
+
 						// If it's not Groq, xAI, DeepSeek the usage is captured at the end when choices are empty or null
 						if !matches!(adapter_kind, AdapterKind::Groq)
 							&& !matches!(adapter_kind, AdapterKind::DeepSeek)
+							&& !azure_without_usage
 							&& self.captured_data.usage.is_none() // this might be redundant
 							&& self.options.capture_usage
 						{
@@ -286,3 +309,13 @@ impl futures::Stream for OpenAIStreamer {
 		Poll::Pending
 	}
 }
+
+// Support
+impl OpenAIStreamer {
+	fn capture_content_filter_results(&mut self, results: ContentFilterResults) {
+		let ContentFilterResults { prompt, completion } = results;
+		let captured = self.captured_data.content_filter_results.get_or_insert_with(Default::default);
+		captured.prompt.extend(prompt);
+		captured.merge_completion(completion);
+	}
+}
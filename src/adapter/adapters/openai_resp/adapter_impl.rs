@@ -7,11 +7,9 @@ use crate::chat::{
 	ContentPart, MessageContent, ReasoningEffort, Usage,
 };
 use crate::resolver::{AuthData, Endpoint};
-use crate::webc::WebResponse;
+use crate::webc::{EventSource, WebResponse, WebStreamRequest};
 use crate::{Error, Headers, Result};
 use crate::{ModelIden, ServiceTarget};
-use reqwest::RequestBuilder;
-use reqwest_eventsource::EventSource;
 use serde_json::{Map, Value, json};
 use value_ext::JsonValueExt;
 
@@ -211,6 +209,10 @@ impl Adapter for OpenAIRespAdapter {
 
 		let captured_raw_body = options_set.capture_raw_body().unwrap_or_default().then(|| body.clone());
 
+		// The incomplete reason (e.g., `max_output_tokens`), otherwise the status (e.g., `completed`).
+		let finish_reason: Option<String> =
+			body.x_get("/incomplete_details/reason").or_else(|_| body.x_get("status")).ok();
+
 		let resp: RespResponse = serde_json::from_value(body)?;
 
 		// -- Capture the provider_model_iden
@@ -235,16 +237,21 @@ impl Adapter for OpenAIRespAdapter {
 			model_iden,
 			provider_model_iden,
 			usage,
+			finish_reason,
 			captured_raw_body,
+			fallback_attempts: Vec::new(),
+			cached: false,
+			timings: None,
+			content_filter_results: None,
 		})
 	}
 
 	fn to_chat_stream(
 		model_iden: ModelIden,
-		reqwest_builder: RequestBuilder,
+		stream_request: WebStreamRequest,
 		options_sets: ChatOptionsSet<'_, '_>,
 	) -> Result<ChatStreamResponse> {
-		let event_source = EventSource::new(reqwest_builder)?;
+		let event_source = EventSource::new(stream_request)?;
 		let openai_stream = OpenAIStreamer::new(event_source, model_iden.clone(), options_sets);
 		let chat_stream = ChatStream::from_inter_stream(openai_stream);
 
@@ -2,9 +2,11 @@
 //! It should be private to the `crate::adapter::adapters` module.
 
 use crate::ModelIden;
-use crate::chat::{ChatOptionsSet, Usage};
+use crate::adapter::AdapterKind;
+use crate::chat::{ChatOptionsSet, ContentFilterResults, Usage};
 use crate::resolver::AuthData;
-use crate::{Error, Result};
+use crate::{Error, ErrorKind, ProviderError, Result, support, webc};
+use serde_json::Value;
 
 pub fn get_api_key(auth: &AuthData, model: &ModelIden) -> Result<String> {
 	auth.single_key_value().map_err(|resolver_error| Error::Resolver {
@@ -47,6 +49,145 @@ pub struct StreamerCapturedData {
 	pub reasoning_content: Option<String>,
 	pub tool_calls: Option<Vec<crate::chat::ToolCall>>,
 	pub thought_signature: Option<String>,
+	pub content_filter_results: Option<ContentFilterResults>,
+	pub finish_reason: Option<String>,
 }
 
 // endregion: --- Streamer Captured Data
+
+// region:    --- Batch Support
+
+/// Maps a web call error of a batch API call.
+pub fn batch_web_error(model_iden: &ModelIden) -> impl FnOnce(webc::Error) -> Error + '_ {
+	|webc_error| Error::WebModelCall {
+		model_iden: model_iden.clone(),
+		webc_error,
+	}
+}
+
+/// Parses a JSONL body (e.g., a batch results file), skipping the empty lines.
+pub fn parse_jsonl(model_iden: &ModelIden, content: &str) -> Result<Vec<Value>> {
+	content
+		.lines()
+		.filter(|line| !line.trim().is_empty())
+		.map(|line| {
+			serde_json::from_str(line).map_err(|err| Error::BatchResponse {
+				model_iden: model_iden.clone(),
+				cause: format!("Invalid JSONL line: {err}"),
+			})
+		})
+		.collect()
+}
+
+/// Reads a count that can be a JSON number or a string (e.g., int64 values of the Google APIs).
+pub fn json_count(value: Option<&Value>) -> u64 {
+	match value {
+		Some(Value::Number(number)) => number.as_u64().unwrap_or_default(),
+		Some(Value::String(string)) => string.parse().unwrap_or_default(),
+		_ => 0,
+	}
+}
+
+// endregion: --- Batch Support
+
+// region:    --- Model Listing Support
+
+/// Maps a web call error of a models endpoint call.
+pub fn model_list_web_error(adapter_kind: AdapterKind) -> impl FnOnce(webc::Error) -> Error {
+	move |webc_error| Error::WebAdapterCall {
+		adapter_kind,
+		webc_error,
+	}
+}
+
+/// Takes the array of models at `path` of a models endpoint response.
+pub fn take_model_list(adapter_kind: AdapterKind, body: &mut Value, path: &str) -> Result<Vec<Value>> {
+	match body.pointer_mut(path).map(Value::take) {
+		Some(Value::Array(items)) => Ok(items),
+		_ => Err(Error::ModelListResponse {
+			adapter_kind,
+			cause: format!("No model array at '{path}'"),
+		}),
+	}
+}
+
+/// Parses an RFC 3339 date time (e.g., `2025-02-19T00:00:00Z`) to Unix time in seconds.
+pub fn rfc3339_to_unix_secs(text: &str) -> Option<i64> {
+	let (date, time) = text.split_once(['T', 't', ' '])?;
+
+	let mut date_parts = date.splitn(3, '-').map(|part| part.parse::<i64>().ok());
+	let (year, month, day) = (date_parts.next()??, date_parts.next()??, date_parts.next()??);
+
+	let (clock, offset_secs) = match time.strip_suffix(['Z', 'z']) {
+		Some(clock) => (clock, 0),
+		None => {
+			let (clock, offset) = time.split_at(time.rfind(['+', '-'])?);
+			let sign = if offset.starts_with('-') { -1 } else { 1 };
+			let (hours, minutes) = offset[1..].split_once(':')?;
+			(
+				clock,
+				sign * (hours.parse::<i64>().ok()? * 3600 + minutes.parse::<i64>().ok()? * 60),
+			)
+		}
+	};
+	// The fractional seconds are ignored.
+	let clock = clock.split('.').next()?;
+	let mut clock_parts = clock.splitn(3, ':').map(|part| part.parse::<i64>().ok());
+	let (hour, minute, second) = (clock_parts.next()??, clock_parts.next()??, clock_parts.next()??);
+
+	let days = support::days_from_civil(year, month, day);
+
+	Some(days * 86_400 + hour * 3600 + minute * 60 + second - offset_secs)
+}
+
+// endregion: --- Model Listing Support
+
+// region:    --- Provider Error Support
+
+/// Sets the kind of a `ProviderError` completed by an adapter (code and message):
+/// the adapter classification of the provider code if any, otherwise inferred from the message,
+/// otherwise the one of the status.
+pub fn classify_provider_error(provider_error: &mut ProviderError, code_kind: Option<ErrorKind>) {
+	if let Some(kind) = code_kind.or_else(|| provider_error.message.as_deref().and_then(message_error_kind)) {
+		provider_error.kind = kind;
+	}
+}
+
+/// Infers the kind from the common provider error messages, for the errors without a specific code
+/// (e.g., an `invalid_request_error` for a too long prompt).
+fn message_error_kind(message: &str) -> Option<ErrorKind> {
+	let message = message.to_lowercase();
+	let has = |patterns: &[&str]| patterns.iter().any(|pattern| message.contains(pattern));
+
+	if has(&[
+		"context length",
+		"context_length",
+		"context window",
+		"maximum context",
+		"prompt is too long",
+		"input is too long",
+		"too many tokens",
+		"exceeds the maximum number of tokens",
+	]) {
+		Some(ErrorKind::ContextLengthExceeded)
+	} else if has(&["insufficient_quota", "credit balance", "billing", "exceeded your current quota"]) {
+		Some(ErrorKind::QuotaExhausted)
+	} else if has(&[
+		"content filter",
+		"content_filter",
+		"content policy",
+		"content management policy",
+	]) {
+		Some(ErrorKind::ContentFiltered)
+	} else if has(&["api key not valid", "invalid api key", "incorrect api key", "invalid x-api-key"]) {
+		Some(ErrorKind::InvalidAuth)
+	} else if has(&["model not found", "does not exist", "no such model"]) && message.contains("model") {
+		Some(ErrorKind::ModelNotFound)
+	} else if has(&["overloaded"]) {
+		Some(ErrorKind::Overloaded)
+	} else {
+		None
+	}
+}
+
+// endregion: --- Provider Error Support
@@ -0,0 +1,289 @@
+use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::anthropic::AnthropicAdapter;
+use crate::adapter::gemini::GeminiAdapter;
+use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
+use crate::chat::{ChatOptionsSet, ChatRequest, ChatResponse, ChatStreamResponse};
+use crate::embed::{EmbedOptionsSet, EmbedRequest, EmbedResponse};
+use crate::resolver::{self, AuthData, Endpoint};
+use crate::webc::{WebResponse, WebStreamRequest};
+use crate::{Error, Headers, ModelIden, Result, ServiceTarget};
+use value_ext::JsonValueExt;
+
+/// The Google Vertex AI adapter, for the Gemini models (publisher `google`)
+/// and the Claude models (publisher `anthropic`) of a Google Cloud project.
+///
+/// - The endpoint is the project location one (see `VertexAdapter::endpoint`),
+///   by default from the `GOOGLE_CLOUD_PROJECT` and `GOOGLE_CLOUD_LOCATION` environment variables.
+/// - The auth is an OAuth2 access token sent as `Authorization: Bearer ..`.
+///   As those tokens expire, an async `AuthResolver` fetching them (e.g., with `gcp_auth`) is the way to go.
+pub struct VertexAdapter;
+
+const MODELS: &[&str] = &[
+	"gemini-2.5-pro",
+	"gemini-2.5-flash",
+	"gemini-2.5-flash-lite",
+	"claude-sonnet-4-5@20250929",
+	"claude-opus-4-1@20250805",
+	"claude-haiku-4-5@20251001",
+];
+
+/// The `anthropic_version` of the Claude request bodies (instead of the `anthropic-version` header).
+const ANTHROPIC_VERTEX_VERSION: &str = "vertex-2023-10-16";
+
+const LOCATION_DEFAULT: &str = "us-central1";
+
+impl VertexAdapter {
+	pub const API_KEY_DEFAULT_ENV_NAME: &str = "GOOGLE_CLOUD_ACCESS_TOKEN";
+	pub const PROJECT_ID_ENV_NAME: &str = "GOOGLE_CLOUD_PROJECT";
+	pub const LOCATION_ENV_NAME: &str = "GOOGLE_CLOUD_LOCATION";
+
+	/// The endpoint of a project location (e.g., `us-central1`, or `global`),
+	/// to be set with a `ServiceTargetResolver` when not using the environment variables.
+	pub fn endpoint(project_id: &str, location: &str) -> Endpoint {
+		let host = if location == "global" {
+			"aiplatform.googleapis.com".to_string()
+		} else {
+			format!("{location}-aiplatform.googleapis.com")
+		};
+		Endpoint::from_owned(format!("https://{host}/v1/projects/{project_id}/locations/{location}/"))
+	}
+}
+
+impl Adapter for VertexAdapter {
+	/// Note: When `GOOGLE_CLOUD_PROJECT` is not set, the requests fail until an endpoint is resolved.
+	fn default_endpoint() -> Endpoint {
+		let project_id = std::env::var(Self::PROJECT_ID_ENV_NAME).unwrap_or_default();
+		let location = std::env::var(Self::LOCATION_ENV_NAME).unwrap_or_else(|_| LOCATION_DEFAULT.to_string());
+		Self::endpoint(&project_id, &location)
+	}
+
+	fn default_auth() -> AuthData {
+		AuthData::from_env(Self::API_KEY_DEFAULT_ENV_NAME)
+	}
+
+	/// Note: For now, this returns the common models (see above)
+	async fn all_model_names(_kind: AdapterKind) -> Result<Vec<String>> {
+		Ok(MODELS.iter().map(|s| s.to_string()).collect())
+	}
+
+	fn get_service_url(model: &ModelIden, service_type: ServiceType, endpoint: Endpoint) -> Result<String> {
+		let base_url = endpoint.base_url();
+		let (model_name, _) = model.model_name.as_model_name_and_namespace();
+		let publisher = Publisher::from_model_name(model_name);
+		let method = match (publisher, service_type) {
+			(Publisher::Google, ServiceType::Chat) => "generateContent",
+			(Publisher::Google, ServiceType::ChatStream) => "streamGenerateContent",
+			(Publisher::Anthropic, ServiceType::Chat) => "rawPredict",
+			(Publisher::Anthropic, ServiceType::ChatStream) => "streamRawPredict",
+			(_, ServiceType::Embed | ServiceType::ImageGenerationImagen) => "predict",
+			(_, ServiceType::VideoGenerationVeo) => "predictLongRunning",
+		};
+		let publisher = publisher.as_str();
+
+		Ok(format!("{base_url}publishers/{publisher}/models/{model_name}:{method}"))
+	}
+
+	fn to_web_request_data(
+		target: ServiceTarget,
+		service_type: ServiceType,
+		chat_req: ChatRequest,
+		options_set: ChatOptionsSet<'_, '_>,
+	) -> Result<WebRequestData> {
+		let ServiceTarget { endpoint, auth, model } = target;
+		check_endpoint(&endpoint, &model)?;
+
+		// -- headers
+		let api_key = get_api_key(&auth, &model)?;
+		let headers = Headers::from(("Authorization".to_string(), format!("Bearer {api_key}")));
+
+		let (model_name, _) = model.model_name.as_model_name_and_namespace();
+		match Publisher::from_model_name(model_name) {
+			// Same body as the Gemini API, with the Gemini URL scheme under the publisher path.
+			Publisher::Google => {
+				let gemini_endpoint = Endpoint::from_owned(format!("{}publishers/google/", endpoint.base_url()));
+				let gemini_target = ServiceTarget {
+					endpoint: gemini_endpoint,
+					auth,
+					model,
+				};
+				let WebRequestData { url, payload, .. } =
+					GeminiAdapter::to_web_request_data(gemini_target, service_type, chat_req, options_set)?;
+				Ok(WebRequestData { url, headers, payload })
+			}
+			// Same body as the Anthropic API, but with the model in the URL and the version in the body.
+			Publisher::Anthropic => {
+				let anthropic_target = ServiceTarget {
+					endpoint: endpoint.clone(),
+					auth,
+					model: model.clone(),
+				};
+				let WebRequestData { mut payload, .. } =
+					AnthropicAdapter::to_web_request_data(anthropic_target, service_type, chat_req, options_set)?;
+				// Note: The Anthropic adapter trims the reasoning suffix (e.g., `-high`) of the model name.
+				let provider_model_name: String = payload.x_remove("model")?;
+				payload.x_insert("anthropic_version", ANTHROPIC_VERTEX_VERSION)?;
+				let url = Self::get_service_url(&model.from_name(provider_model_name), service_type, endpoint)?;
+				Ok(WebRequestData { url, headers, payload })
+			}
+		}
+	}
+
+	fn to_chat_response(
+		model_iden: ModelIden,
+		web_response: WebResponse,
+		options_set: ChatOptionsSet<'_, '_>,
+	) -> Result<ChatResponse> {
+		match Publisher::from_model(&model_iden) {
+			Publisher::Google => GeminiAdapter::to_chat_response(model_iden, web_response, options_set),
+			Publisher::Anthropic => AnthropicAdapter::to_chat_response(model_iden, web_response, options_set),
+		}
+	}
+
+	fn to_chat_stream(
+		model_iden: ModelIden,
+		stream_request: WebStreamRequest,
+		options_set: ChatOptionsSet<'_, '_>,
+	) -> Result<ChatStreamResponse> {
+		match Publisher::from_model(&model_iden) {
+			Publisher::Google => GeminiAdapter::to_chat_stream(model_iden, stream_request, options_set),
+			Publisher::Anthropic => AnthropicAdapter::to_chat_stream(model_iden, stream_request, options_set),
+		}
+	}
+
+	fn to_embed_request_data(
+		service_target: ServiceTarget,
+		embed_req: EmbedRequest,
+		options_set: EmbedOptionsSet<'_, '_>,
+	) -> Result<WebRequestData> {
+		check_endpoint(&service_target.endpoint, &service_target.model)?;
+		if Publisher::from_model(&service_target.model) == Publisher::Anthropic {
+			return Err(Error::AdapterNotSupported {
+				adapter_kind: AdapterKind::Vertex,
+				feature: "embeddings with the Anthropic models".to_string(),
+			});
+		}
+		super::embed::to_embed_request_data(service_target, embed_req, options_set)
+	}
+
+	fn to_embed_response(
+		model_iden: ModelIden,
+		web_response: WebResponse,
+		options_set: EmbedOptionsSet<'_, '_>,
+	) -> Result<EmbedResponse> {
+		super::embed::to_embed_response(model_iden, web_response, options_set)
+	}
+}
+
+// region:    --- Support
+
+/// The Vertex AI model publisher, which decides the URL and the request/response mapping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Publisher {
+	Google,
+	Anthropic,
+}
+
+impl Publisher {
+	pub(super) fn from_model(model: &ModelIden) -> Self {
+		let (model_name, _) = model.model_name.as_model_name_and_namespace();
+		Self::from_model_name(model_name)
+	}
+
+	fn from_model_name(model_name: &str) -> Self {
+		if model_name.starts_with("claude") {
+			Publisher::Anthropic
+		} else {
+			Publisher::Google
+		}
+	}
+
+	fn as_str(self) -> &'static str {
+		match self {
+			Publisher::Google => "google",
+			Publisher::Anthropic => "anthropic",
+		}
+	}
+}
+
+/// Fails early when the default endpoint has no project (rather than a 404 from Google).
+fn check_endpoint(endpoint: &Endpoint, model: &ModelIden) -> Result<()> {
+	if endpoint.base_url().contains("/projects//") {
+		return Err(Error::Resolver {
+			model_iden: model.clone(),
+			resolver_error: resolver::Error::Custom(format!(
+				"Vertex AI project not set (env '{}' or a ServiceTargetResolver endpoint)",
+				VertexAdapter::PROJECT_ID_ENV_NAME
+			)),
+		});
+	}
+	Ok(())
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::chat::ChatMessage;
+	use serde_json::Value;
+
+	#[test]
+	fn test_vertex_web_request_data_publishers() -> Result<()> {
+		// -- Setup & Fixtures
+		let endpoint = VertexAdapter::endpoint("my-project", "europe-west1");
+		let target = |model: &str| ServiceTarget {
+			endpoint: endpoint.clone(),
+			auth: AuthData::from_single("ya29.token"),
+			model: ModelIden::new(AdapterKind::Vertex, model),
+		};
+		let chat_req = ChatRequest::new(vec![ChatMessage::user("Why is the sky blue?")]);
+
+		// -- Exec
+		let gemini = VertexAdapter::to_web_request_data(
+			target("vertex::gemini-2.5-flash"),
+			ServiceType::ChatStream,
+			chat_req.clone(),
+			ChatOptionsSet::default(),
+		)?;
+		let claude = VertexAdapter::to_web_request_data(
+			target("claude-sonnet-4-5@20250929"),
+			ServiceType::Chat,
+			chat_req,
+			ChatOptionsSet::default(),
+		)?;
+		let global = VertexAdapter::endpoint("my-project", "global");
+
+		// -- Check
+		let base_url = "https://europe-west1-aiplatform.googleapis.com/v1/projects/my-project/locations/europe-west1/";
+		assert_eq!(
+			gemini.url,
+			format!("{base_url}publishers/google/models/gemini-2.5-flash:streamGenerateContent")
+		);
+		assert!(gemini.payload.get("contents").is_some());
+		assert_eq!(
+			claude.url,
+			format!("{base_url}publishers/anthropic/models/claude-sonnet-4-5@20250929:rawPredict")
+		);
+		assert!(claude.payload.get("model").is_none());
+		assert_eq!(
+			claude.payload.get("anthropic_version").and_then(Value::as_str),
+			Some(ANTHROPIC_VERTEX_VERSION)
+		);
+		for request_data in [&gemini, &claude] {
+			let auth_header = request_data.headers.iter().find(|(name, _)| name.as_str() == "Authorization");
+			assert_eq!(auth_header.map(|(_, value)| value.as_str()), Some("Bearer ya29.token"));
+		}
+		assert_eq!(
+			global.base_url(),
+			"https://aiplatform.googleapis.com/v1/projects/my-project/locations/global/"
+		);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,141 @@
+//! Vertex AI text embeddings (`:predict`), for the Google embedding models (e.g., `text-embedding-005`, `gemini-embedding-001`).
+//! API Documentation: <https://cloud.google.com/vertex-ai/generative-ai/docs/model-reference/text-embeddings-api>
+
+use crate::adapter::adapters::support::get_api_key;
+use crate::adapter::vertex::VertexAdapter;
+use crate::adapter::{Adapter, ServiceType, WebRequestData};
+use crate::chat::Usage;
+use crate::embed::{EmbedInput, EmbedOptionsSet, EmbedRequest, EmbedResponse, Embedding};
+use crate::webc::WebResponse;
+use crate::{Error, Headers, ModelIden, Result, ServiceTarget};
+use serde::{Deserialize, Serialize};
+
+// region:    --- Vertex Embed Request
+
+#[derive(Debug, Serialize)]
+struct VertexEmbedRequest {
+	instances: Vec<VertexEmbedInstance>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	parameters: Option<VertexEmbedParameters>,
+}
+
+#[derive(Debug, Serialize)]
+struct VertexEmbedInstance {
+	content: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	task_type: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct VertexEmbedParameters {
+	output_dimensionality: usize,
+}
+
+// endregion: --- Vertex Embed Request
+
+// region:    --- Vertex Embed Response
+
+#[derive(Debug, Deserialize)]
+struct VertexEmbedResponse {
+	predictions: Vec<VertexPrediction>,
+}
+
+#[derive(Debug, Deserialize)]
+struct VertexPrediction {
+	embeddings: VertexEmbeddings,
+}
+
+#[derive(Debug, Deserialize)]
+struct VertexEmbeddings {
+	values: Vec<f32>,
+	statistics: Option<VertexEmbeddingStatistics>,
+}
+
+#[derive(Debug, Deserialize)]
+struct VertexEmbeddingStatistics {
+	token_count: Option<f64>,
+}
+
+// endregion: --- Vertex Embed Response
+
+// region:    --- Public Functions
+
+pub fn to_embed_request_data(
+	service_target: ServiceTarget,
+	embed_req: EmbedRequest,
+	options_set: EmbedOptionsSet<'_, '_>,
+) -> Result<WebRequestData> {
+	let ServiceTarget { endpoint, auth, model } = service_target;
+	let api_key = get_api_key(&auth, &model)?;
+
+	let mut headers = Headers::from(("Authorization".to_string(), format!("Bearer {api_key}")));
+	if let Some(custom_headers) = options_set.headers() {
+		headers.merge_with(custom_headers);
+	}
+
+	// -- Payload (one instance per input, single or batch)
+	let texts = match embed_req.input {
+		EmbedInput::Single(text) => vec![text],
+		EmbedInput::Batch(texts) => texts,
+	};
+	let task_type = options_set.embedding_type().map(String::from);
+	let vertex_req = VertexEmbedRequest {
+		instances: texts
+			.into_iter()
+			.map(|content| VertexEmbedInstance {
+				content,
+				task_type: task_type.clone(),
+			})
+			.collect(),
+		parameters: options_set
+			.dimensions()
+			.map(|output_dimensionality| VertexEmbedParameters { output_dimensionality }),
+	};
+	let payload = serde_json::to_value(vertex_req)?;
+
+	let url = VertexAdapter::get_service_url(&model, ServiceType::Embed, endpoint)?;
+
+	Ok(WebRequestData { url, headers, payload })
+}
+
+pub fn to_embed_response(
+	model_iden: ModelIden,
+	web_response: WebResponse,
+	options_set: EmbedOptionsSet<'_, '_>,
+) -> Result<EmbedResponse> {
+	let WebResponse { body, .. } = web_response;
+
+	let vertex_res: VertexEmbedResponse =
+		serde_json::from_value(body.clone()).map_err(|serde_error| Error::StreamParse {
+			model_iden: model_iden.clone(),
+			serde_error,
+		})?;
+
+	// -- Usage (the token count is per instance)
+	let mut prompt_tokens: Option<i32> = None;
+	let mut embeddings = Vec::with_capacity(vertex_res.predictions.len());
+	for (index, prediction) in vertex_res.predictions.into_iter().enumerate() {
+		let VertexEmbeddings { values, statistics } = prediction.embeddings;
+		if let Some(token_count) = statistics.and_then(|s| s.token_count) {
+			*prompt_tokens.get_or_insert(0) += token_count as i32;
+		}
+		embeddings.push(Embedding::new(values, index));
+	}
+	let usage = Usage {
+		prompt_tokens,
+		total_tokens: prompt_tokens,
+		..Default::default()
+	};
+
+	let provider_model_iden = model_iden.clone();
+	let mut response = EmbedResponse::new(embeddings, model_iden, provider_model_iden, usage);
+
+	if options_set.capture_raw_body() {
+		response = response.with_captured_raw_body(body);
+	}
+
+	Ok(response)
+}
+
+// endregion: --- Public Functions
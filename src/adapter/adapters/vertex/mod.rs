@@ -0,0 +1,14 @@
+//! API Documentation:     <https://cloud.google.com/vertex-ai/generative-ai/docs/reference/rest>
+//! Claude on Vertex:      <https://cloud.google.com/vertex-ai/generative-ai/docs/partner-models/claude>
+//! Model Names:           <https://cloud.google.com/vertex-ai/generative-ai/docs/models>
+//! Pricing:               <https://cloud.google.com/vertex-ai/generative-ai/pricing>
+
+// region:    --- Modules
+
+mod adapter_impl;
+mod embed;
+mod provider_error;
+
+pub use adapter_impl::*;
+
+// endregion: --- Modules
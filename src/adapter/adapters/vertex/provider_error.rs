@@ -0,0 +1,72 @@
+//! Vertex AI errors: the Google ones (`{"error": {"code", "message", "status"}}`) for the Gemini models,
+//! and the Anthropic ones (`{"type": "error", "error": {"type", "message"}}`) from the Claude `rawPredict`.
+//!
+//! Note: For the Claude models, the errors of Vertex itself (e.g., a `PERMISSION_DENIED` of the project)
+//!       still have the Google body, so the body shape decides for them.
+
+use crate::adapter::anthropic::AnthropicAdapter;
+use crate::adapter::gemini::GeminiAdapter;
+use crate::adapter::vertex::VertexAdapter;
+use crate::adapter::vertex::adapter_impl::Publisher;
+use crate::{ModelIden, ProviderError};
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+use serde_json::Value;
+
+impl VertexAdapter {
+	pub(crate) fn to_provider_error(
+		model_iden: &ModelIden,
+		status: Option<StatusCode>,
+		headers: Option<&HeaderMap>,
+		body: &Value,
+	) -> ProviderError {
+		let is_anthropic_body = body.pointer("/error/type").is_some_and(Value::is_string);
+		match Publisher::from_model(model_iden) {
+			Publisher::Anthropic if is_anthropic_body => AnthropicAdapter::to_provider_error(status, headers, body),
+			_ => GeminiAdapter::to_provider_error(status, headers, body),
+		}
+	}
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::ErrorKind;
+	use crate::adapter::AdapterKind;
+	use serde_json::json;
+
+	#[test]
+	fn test_vertex_provider_error_publishers() -> Result<()> {
+		// -- Setup & Fixtures
+		let claude = ModelIden::new(AdapterKind::Vertex, "vertex::claude-sonnet-4-5@20250929");
+		let gemini = ModelIden::new(AdapterKind::Vertex, "gemini-2.5-flash");
+		let overloaded_body = json!({"type": "error", "error": {"type": "overloaded_error", "message": "Overloaded"}});
+		let denied_body = json!({"error": {
+			"code": 403,
+			"message": "Permission 'aiplatform.endpoints.predict' denied",
+			"status": "PERMISSION_DENIED"
+		}});
+
+		// -- Exec
+		let claude_overloaded =
+			VertexAdapter::to_provider_error(&claude, Some(StatusCode::from_u16(529)?), None, &overloaded_body);
+		let claude_denied = VertexAdapter::to_provider_error(&claude, Some(StatusCode::FORBIDDEN), None, &denied_body);
+		let gemini_denied = VertexAdapter::to_provider_error(&gemini, Some(StatusCode::FORBIDDEN), None, &denied_body);
+
+		// -- Check
+		assert_eq!(claude_overloaded.kind, ErrorKind::Overloaded);
+		assert_eq!(claude_overloaded.code.as_deref(), Some("overloaded_error"));
+		assert_eq!(claude_overloaded.message.as_deref(), Some("Overloaded"));
+		assert_eq!(claude_denied.kind, ErrorKind::InvalidAuth);
+		assert_eq!(claude_denied.kind, gemini_denied.kind);
+		assert_eq!(claude_denied.message, gemini_denied.message);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
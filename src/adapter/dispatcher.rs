@@ -4,10 +4,14 @@ use crate::adapter::adapters::llamacpp;
 use crate::adapter::adapters::together::TogetherAdapter;
 use crate::adapter::adapters::zai::ZaiAdapter;
 use crate::adapter::anthropic::AnthropicAdapter;
+use crate::adapter::azure_openai::AzureOpenAIAdapter;
+use crate::adapter::bedrock::BedrockAdapter;
 use crate::adapter::cohere::CohereAdapter;
 use crate::adapter::deepseek::DeepSeekAdapter;
 use crate::adapter::fireworks::FireworksAdapter;
 use crate::adapter::gemini::GeminiAdapter;
+#[cfg(feature = "mock")]
+use crate::adapter::mock::MockAdapter;
 use crate::adapter::nebius::NebiusAdapter;
 use crate::adapter::ollama::OllamaAdapter;
 use crate::adapter::openai::OpenAIAdapter;
@@ -15,14 +19,16 @@ use crate::adapter::openai_resp::OpenAIRespAdapter;
 #[cfg(feature = "vertex")]
 use crate::adapter::vertex::VertexAdapter;
 use crate::adapter::xai::XaiAdapter;
-use crate::adapter::{Adapter, AdapterKind, ServiceType, WebRequestData};
-use crate::chat::{ChatOptionsSet, ChatRequest, ChatResponse, ChatStreamResponse};
+use crate::adapter::{Adapter, AdapterKind, BatchItemData, ServiceType, WebRequestData};
+use crate::chat::{ChatBatch, ChatBatchResult, ChatOptionsSet, ChatRequest, ChatResponse, ChatStreamResponse};
 use crate::embed::{EmbedOptionsSet, EmbedRequest, EmbedResponse};
 use crate::resolver::{AuthData, Endpoint};
-use crate::webc::WebResponse;
-use crate::{Error, ModelIden};
+use crate::webc::{WebClient, WebResponse, WebStreamRequest};
+use crate::{Error, ModelIden, ModelInfo, ProviderError};
 use crate::{Result, ServiceTarget};
-use reqwest::RequestBuilder;
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+use serde_json::Value;
 
 /// A construct that allows dispatching calls to the Adapters.
 ///
@@ -47,10 +53,14 @@ impl AdapterDispatcher {
 			AdapterKind::Zai => ZaiAdapter::default_endpoint(),
 			AdapterKind::Cohere => CohereAdapter::default_endpoint(),
 			AdapterKind::Ollama => OllamaAdapter::default_endpoint(),
+			AdapterKind::Bedrock => BedrockAdapter::default_endpoint(),
+			AdapterKind::AzureOpenAI => AzureOpenAIAdapter::default_endpoint(),
 			#[cfg(feature = "vertex")]
 			AdapterKind::Vertex => VertexAdapter::default_endpoint(),
 			#[cfg(feature = "llamacpp")]
 			AdapterKind::LlamaCpp => llamacpp::LlamaCppAdapter::default_endpoint(),
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => MockAdapter::default_endpoint(),
 		}
 	}
 
@@ -69,10 +79,14 @@ impl AdapterDispatcher {
 			AdapterKind::Zai => ZaiAdapter::default_auth(),
 			AdapterKind::Cohere => CohereAdapter::default_auth(),
 			AdapterKind::Ollama => OllamaAdapter::default_auth(),
+			AdapterKind::Bedrock => BedrockAdapter::default_auth(),
+			AdapterKind::AzureOpenAI => AzureOpenAIAdapter::default_auth(),
 			#[cfg(feature = "vertex")]
 			AdapterKind::Vertex => VertexAdapter::default_auth(),
 			#[cfg(feature = "llamacpp")]
 			AdapterKind::LlamaCpp => llamacpp::LlamaCppAdapter::default_auth(),
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => MockAdapter::default_auth(),
 		}
 	}
 
@@ -91,10 +105,14 @@ impl AdapterDispatcher {
 			AdapterKind::Zai => ZaiAdapter::all_model_names(kind).await,
 			AdapterKind::Cohere => CohereAdapter::all_model_names(kind).await,
 			AdapterKind::Ollama => OllamaAdapter::all_model_names(kind).await,
+			AdapterKind::Bedrock => BedrockAdapter::all_model_names(kind).await,
+			AdapterKind::AzureOpenAI => AzureOpenAIAdapter::all_model_names(kind).await,
 			#[cfg(feature = "vertex")]
 			AdapterKind::Vertex => VertexAdapter::all_model_names(kind).await,
 			#[cfg(feature = "llamacpp")]
 			AdapterKind::LlamaCpp => llamacpp::LlamaCppAdapter::all_model_names(kind).await,
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => MockAdapter::all_model_names(kind).await,
 		}
 	}
 
@@ -113,10 +131,14 @@ impl AdapterDispatcher {
 			AdapterKind::Zai => ZaiAdapter::get_service_url(model, service_type, endpoint),
 			AdapterKind::Cohere => CohereAdapter::get_service_url(model, service_type, endpoint),
 			AdapterKind::Ollama => OllamaAdapter::get_service_url(model, service_type, endpoint),
+			AdapterKind::Bedrock => BedrockAdapter::get_service_url(model, service_type, endpoint),
+			AdapterKind::AzureOpenAI => AzureOpenAIAdapter::get_service_url(model, service_type, endpoint),
 			#[cfg(feature = "vertex")]
 			AdapterKind::Vertex => VertexAdapter::get_service_url(model, service_type, endpoint),
 			#[cfg(feature = "llamacpp")]
 			AdapterKind::LlamaCpp => llamacpp::LlamaCppAdapter::get_service_url(model, service_type, endpoint),
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => MockAdapter::get_service_url(model, service_type, endpoint),
 		}
 	}
 
@@ -147,10 +169,16 @@ impl AdapterDispatcher {
 			AdapterKind::Zai => ZaiAdapter::to_web_request_data(target, service_type, chat_req, options_set),
 			AdapterKind::Cohere => CohereAdapter::to_web_request_data(target, service_type, chat_req, options_set),
 			AdapterKind::Ollama => OllamaAdapter::to_web_request_data(target, service_type, chat_req, options_set),
+			AdapterKind::Bedrock => BedrockAdapter::to_web_request_data(target, service_type, chat_req, options_set),
+			AdapterKind::AzureOpenAI => {
+				AzureOpenAIAdapter::to_web_request_data(target, service_type, chat_req, options_set)
+			}
 			#[cfg(feature = "vertex")]
 			AdapterKind::Vertex => VertexAdapter::to_web_request_data(target, service_type, chat_req, options_set),
 			#[cfg(feature = "llamacpp")]
 			AdapterKind::LlamaCpp => llamacpp::LlamaCppAdapter::to_web_request_data(target, service_type, chat_req, options_set),
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => MockAdapter::to_web_request_data(target, service_type, chat_req, options_set),
 		}
 	}
 
@@ -173,39 +201,47 @@ impl AdapterDispatcher {
 			AdapterKind::Zai => ZaiAdapter::to_chat_response(model_iden, web_response, options_set),
 			AdapterKind::Cohere => CohereAdapter::to_chat_response(model_iden, web_response, options_set),
 			AdapterKind::Ollama => OllamaAdapter::to_chat_response(model_iden, web_response, options_set),
+			AdapterKind::Bedrock => BedrockAdapter::to_chat_response(model_iden, web_response, options_set),
+			AdapterKind::AzureOpenAI => AzureOpenAIAdapter::to_chat_response(model_iden, web_response, options_set),
 			#[cfg(feature = "vertex")]
 			AdapterKind::Vertex => VertexAdapter::to_chat_response(model_iden, web_response, options_set),
 			#[cfg(feature = "llamacpp")]
 			AdapterKind::LlamaCpp => llamacpp::LlamaCppAdapter::to_chat_response(model_iden, web_response, options_set),
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => MockAdapter::to_chat_response(model_iden, web_response, options_set),
 		}
 	}
 
 	pub fn to_chat_stream(
 		model_iden: ModelIden,
-		reqwest_builder: RequestBuilder,
+		stream_request: WebStreamRequest,
 		options_set: ChatOptionsSet<'_, '_>,
 	) -> Result<ChatStreamResponse> {
 		match model_iden.adapter_kind {
-			AdapterKind::OpenAI => OpenAIAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
+			AdapterKind::OpenAI => OpenAIAdapter::to_chat_stream(model_iden, stream_request, options_set),
 			AdapterKind::OpenAIResp => Err(Error::AdapterNotSupported {
 				adapter_kind: model_iden.adapter_kind,
 				feature: "stream".to_string(),
 			}),
-			AdapterKind::Gemini => GeminiAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
-			AdapterKind::Anthropic => AnthropicAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
-			AdapterKind::Fireworks => FireworksAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
-			AdapterKind::Together => TogetherAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
-			AdapterKind::Groq => GroqAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
-			AdapterKind::Nebius => NebiusAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
-			AdapterKind::Xai => XaiAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
-			AdapterKind::DeepSeek => DeepSeekAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
-			AdapterKind::Zai => ZaiAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
-			AdapterKind::Cohere => CohereAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
-			AdapterKind::Ollama => OllamaAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
+			AdapterKind::Gemini => GeminiAdapter::to_chat_stream(model_iden, stream_request, options_set),
+			AdapterKind::Anthropic => AnthropicAdapter::to_chat_stream(model_iden, stream_request, options_set),
+			AdapterKind::Fireworks => FireworksAdapter::to_chat_stream(model_iden, stream_request, options_set),
+			AdapterKind::Together => TogetherAdapter::to_chat_stream(model_iden, stream_request, options_set),
+			AdapterKind::Groq => GroqAdapter::to_chat_stream(model_iden, stream_request, options_set),
+			AdapterKind::Nebius => NebiusAdapter::to_chat_stream(model_iden, stream_request, options_set),
+			AdapterKind::Xai => XaiAdapter::to_chat_stream(model_iden, stream_request, options_set),
+			AdapterKind::DeepSeek => DeepSeekAdapter::to_chat_stream(model_iden, stream_request, options_set),
+			AdapterKind::Zai => ZaiAdapter::to_chat_stream(model_iden, stream_request, options_set),
+			AdapterKind::Cohere => CohereAdapter::to_chat_stream(model_iden, stream_request, options_set),
+			AdapterKind::Ollama => OllamaAdapter::to_chat_stream(model_iden, stream_request, options_set),
+			AdapterKind::Bedrock => BedrockAdapter::to_chat_stream(model_iden, stream_request, options_set),
+			AdapterKind::AzureOpenAI => AzureOpenAIAdapter::to_chat_stream(model_iden, stream_request, options_set),
 			#[cfg(feature = "vertex")]
-			AdapterKind::Vertex => VertexAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
+			AdapterKind::Vertex => VertexAdapter::to_chat_stream(model_iden, stream_request, options_set),
 			#[cfg(feature = "llamacpp")]
-			AdapterKind::LlamaCpp => llamacpp::LlamaCppAdapter::to_chat_stream(model_iden, reqwest_builder, options_set),
+			AdapterKind::LlamaCpp => llamacpp::LlamaCppAdapter::to_chat_stream(model_iden, stream_request, options_set),
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => MockAdapter::to_chat_stream(model_iden, stream_request, options_set),
 		}
 	}
 
@@ -232,10 +268,14 @@ impl AdapterDispatcher {
 			AdapterKind::Zai => ZaiAdapter::to_embed_request_data(target, embed_req, options_set),
 			AdapterKind::Cohere => CohereAdapter::to_embed_request_data(target, embed_req, options_set),
 			AdapterKind::Ollama => OllamaAdapter::to_embed_request_data(target, embed_req, options_set),
+			AdapterKind::Bedrock => BedrockAdapter::to_embed_request_data(target, embed_req, options_set),
+			AdapterKind::AzureOpenAI => AzureOpenAIAdapter::to_embed_request_data(target, embed_req, options_set),
 			#[cfg(feature = "vertex")]
 			AdapterKind::Vertex => VertexAdapter::to_embed_request_data(target, embed_req, options_set),
 			#[cfg(feature = "llamacpp")]
 			AdapterKind::LlamaCpp => llamacpp::LlamaCppAdapter::to_embed_request_data(target, embed_req, options_set),
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => MockAdapter::to_embed_request_data(target, embed_req, options_set),
 		}
 	}
 
@@ -261,10 +301,27 @@ impl AdapterDispatcher {
 			AdapterKind::Zai => ZaiAdapter::to_embed_response(model_iden, web_response, options_set),
 			AdapterKind::Cohere => CohereAdapter::to_embed_response(model_iden, web_response, options_set),
 			AdapterKind::Ollama => OllamaAdapter::to_embed_response(model_iden, web_response, options_set),
+			AdapterKind::Bedrock => BedrockAdapter::to_embed_response(model_iden, web_response, options_set),
+			AdapterKind::AzureOpenAI => AzureOpenAIAdapter::to_embed_response(model_iden, web_response, options_set),
 			#[cfg(feature = "vertex")]
 			AdapterKind::Vertex => VertexAdapter::to_embed_response(model_iden, web_response, options_set),
 			#[cfg(feature = "llamacpp")]
 			AdapterKind::LlamaCpp => llamacpp::LlamaCppAdapter::to_embed_response(model_iden, web_response, options_set),
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => MockAdapter::to_embed_response(model_iden, web_response, options_set),
+		}
+	}
+
+	/// Signs the web request right before it is sent (after the middlewares, and for each retry),
+	/// for the adapters whose signature covers the full request (i.e., Bedrock SigV4). A no-op for the others.
+	pub fn sign_web_request(
+		model_iden: &ModelIden,
+		auth: &AuthData,
+		web_request_data: &mut WebRequestData,
+	) -> Result<()> {
+		match model_iden.adapter_kind {
+			AdapterKind::Bedrock => BedrockAdapter::sign_web_request(model_iden, auth, web_request_data),
+			_ => Ok(()),
 		}
 	}
 
@@ -359,4 +416,131 @@ impl AdapterDispatcher {
 	}
 
 	// endregion: --- Veo Video Generation Methods
+
+	// region:    --- Model Listing
+
+	/// Lists the models of the provider models endpoint, with the target endpoint and auth.
+	pub async fn list_models(web_client: &WebClient, target: ServiceTarget) -> Result<Vec<ModelInfo>> {
+		let adapter_kind = target.model.adapter_kind;
+		match adapter_kind {
+			AdapterKind::OpenAI
+			| AdapterKind::OpenAIResp
+			| AdapterKind::Fireworks
+			| AdapterKind::Together
+			| AdapterKind::Groq
+			| AdapterKind::Nebius
+			| AdapterKind::Xai
+			| AdapterKind::DeepSeek
+			| AdapterKind::Zai
+			| AdapterKind::Ollama => OpenAIAdapter::util_list_models(web_client, target).await,
+			AdapterKind::Anthropic => AnthropicAdapter::list_models(web_client, target).await,
+			AdapterKind::Gemini => GeminiAdapter::list_models(web_client, target).await,
+			AdapterKind::Cohere => CohereAdapter::list_models(web_client, target).await,
+			AdapterKind::Bedrock => Err(Error::AdapterNotSupported {
+				adapter_kind,
+				feature: "model listing".to_string(),
+			}),
+			// The deployments are listed by the Azure management API (not the inference one)
+			AdapterKind::AzureOpenAI => Err(Error::AdapterNotSupported {
+				adapter_kind,
+				feature: "model listing".to_string(),
+			}),
+			#[cfg(feature = "vertex")]
+			AdapterKind::Vertex => Err(Error::AdapterNotSupported {
+				adapter_kind,
+				feature: "model listing".to_string(),
+			}),
+			#[cfg(feature = "llamacpp")]
+			AdapterKind::LlamaCpp => Err(Error::AdapterNotSupported {
+				adapter_kind,
+				feature: "model listing".to_string(),
+			}),
+			#[cfg(feature = "mock")]
+			AdapterKind::Mock => Err(Error::AdapterNotSupported {
+				adapter_kind,
+				feature: "model listing".to_string(),
+			}),
+		}
+	}
+
+	// endregion: --- Model Listing
+
+	// region:    --- Batch Methods
+
+	pub async fn submit_chat_batch(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		items: Vec<BatchItemData>,
+	) -> Result<ChatBatch> {
+		let adapter_kind = target.model.adapter_kind;
+		match adapter_kind {
+			AdapterKind::OpenAI => OpenAIAdapter::submit_chat_batch(web_client, target, items).await,
+			AdapterKind::Anthropic => AnthropicAdapter::submit_chat_batch(web_client, target, items).await,
+			AdapterKind::Gemini => GeminiAdapter::submit_chat_batch(web_client, target, items).await,
+			_ => Err(Error::AdapterNotSupported {
+				adapter_kind,
+				feature: "batch".to_string(),
+			}),
+		}
+	}
+
+	pub async fn poll_batch(web_client: &WebClient, target: ServiceTarget, batch_id: &str) -> Result<ChatBatch> {
+		let adapter_kind = target.model.adapter_kind;
+		match adapter_kind {
+			AdapterKind::OpenAI => OpenAIAdapter::poll_batch(web_client, target, batch_id).await,
+			AdapterKind::Anthropic => AnthropicAdapter::poll_batch(web_client, target, batch_id).await,
+			AdapterKind::Gemini => GeminiAdapter::poll_batch(web_client, target, batch_id).await,
+			_ => Err(Error::AdapterNotSupported {
+				adapter_kind,
+				feature: "batch".to_string(),
+			}),
+		}
+	}
+
+	pub async fn fetch_batch_results(
+		web_client: &WebClient,
+		target: ServiceTarget,
+		batch: &ChatBatch,
+		options_set: ChatOptionsSet<'_, '_>,
+	) -> Result<Vec<ChatBatchResult>> {
+		let adapter_kind = target.model.adapter_kind;
+		match adapter_kind {
+			AdapterKind::OpenAI => OpenAIAdapter::fetch_batch_results(web_client, target, batch, options_set).await,
+			AdapterKind::Anthropic => {
+				AnthropicAdapter::fetch_batch_results(web_client, target, batch, options_set).await
+			}
+			AdapterKind::Gemini => GeminiAdapter::fetch_batch_results(web_client, target, batch, options_set).await,
+			_ => Err(Error::AdapterNotSupported {
+				adapter_kind,
+				feature: "batch".to_string(),
+			}),
+		}
+	}
+
+	// endregion: --- Batch Methods
+
+	// region:    --- Provider Error Methods
+
+	/// Parse a provider error body (of a failed status, or of a stream error event when `status` is `None`).
+	///
+	/// The model name decides the parser when an adapter serves several API families (e.g., Vertex AI Claude models).
+	pub fn to_provider_error(
+		model_iden: &ModelIden,
+		status: Option<StatusCode>,
+		headers: Option<&HeaderMap>,
+		body: &Value,
+	) -> ProviderError {
+		match model_iden.adapter_kind {
+			AdapterKind::Anthropic => AnthropicAdapter::to_provider_error(status, headers, body),
+			AdapterKind::Gemini => GeminiAdapter::to_provider_error(status, headers, body),
+			#[cfg(feature = "vertex")]
+			AdapterKind::Vertex => VertexAdapter::to_provider_error(model_iden, status, headers, body),
+			AdapterKind::Cohere => CohereAdapter::to_provider_error(status, headers, body),
+			AdapterKind::Bedrock => BedrockAdapter::to_provider_error(status, headers, body),
+			// The other adapters use the OpenAI error body (or a close variant).
+			_ => OpenAIAdapter::to_provider_error(status, headers, body),
+		}
+	}
+
+	// endregion: --- Provider Error Methods
 }
@@ -5,7 +5,7 @@
 //!
 //! NOTE: This might be removed at some point as it may not be needed, and we could go directly to the GenAI stream.
 
-use crate::chat::Usage;
+use crate::chat::{ChatTimings, ContentFilterResults, Usage};
 
 #[derive(Debug, Default)]
 pub struct InterStreamEnd {
@@ -21,6 +21,16 @@ pub struct InterStreamEnd {
 	// When `ChatOptions..capture_tool_calls == true`
 	pub captured_tool_calls: Option<Vec<crate::chat::ToolCall>>,
 	pub captured_thought_signature: Option<String>,
+
+	// Set by the client stream timing wrapper
+	pub timings: Option<ChatTimings>,
+
+	// Reported by the provider (e.g., Azure OpenAI), regardless of the capture options
+	// (boxed, as it is rarely set and keeps the `InterStreamEvent` small)
+	pub captured_content_filter_results: Option<Box<ContentFilterResults>>,
+
+	// The provider finish reason (e.g., `stop`, `end_turn`), regardless of the capture options
+	pub captured_finish_reason: Option<String>,
 }
 
 /// Intermediary StreamEvent
@@ -31,4 +41,9 @@ pub enum InterStreamEvent {
 	ReasoningChunk(String),
 	ToolCallChunk(crate::chat::ToolCall),
 	End(InterStreamEnd),
+	/// The stream was resumed (see `ResumePolicy`).
+	Resumed {
+		attempt: u32,
+		cause: String,
+	},
 }
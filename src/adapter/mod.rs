@@ -18,9 +18,14 @@ mod dispatcher;
 // -- Flatten (private, crate, public)
 #[cfg(feature = "llamacpp")]
 use adapters::llamacpp;
+#[cfg(feature = "mock")]
+use adapters::mock;
 #[cfg(feature = "vertex")]
 use adapters::vertex;
-use adapters::{anthropic, cohere, deepseek, fireworks, gemini, groq, nebius, ollama, openai, openai_resp, xai};
+use adapters::{
+	anthropic, azure_openai, bedrock, cohere, deepseek, fireworks, gemini, groq, nebius, ollama, openai, openai_resp,
+	xai,
+};
 #[allow(unused_imports)]
 use adapters::{together, zai};
 
@@ -28,6 +33,9 @@ pub(crate) use adapter_types::*;
 pub(crate) use dispatcher::*;
 
 pub use adapter_kind::*;
+pub use adapter_types::WebRequestData;
+#[cfg(feature = "mock")]
+pub use mock::{Mock, MockError, MockRequest, MockResponse};
 
 // -- Crate modules
 pub(crate) mod inter_stream;
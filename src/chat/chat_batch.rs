@@ -0,0 +1,91 @@
+//! Types of the provider batch APIs (OpenAI Batch, Anthropic Message Batches, Gemini batch mode).
+//! See `Client::submit_chat_batch(..)`, `Client::poll_batch(..)`, and `Client::fetch_batch_results(..)`.
+
+use crate::ModelIden;
+use crate::chat::{ChatRequest, ChatResponse};
+use serde::{Deserialize, Serialize};
+
+/// One request of a chat batch, identified by a caller provided id (unique within the batch).
+#[derive(Debug, Clone)]
+pub struct ChatBatchItem {
+	pub custom_id: String,
+	pub chat_req: ChatRequest,
+}
+
+impl ChatBatchItem {
+	pub fn new(custom_id: impl Into<String>, chat_req: ChatRequest) -> Self {
+		Self {
+			custom_id: custom_id.into(),
+			chat_req,
+		}
+	}
+}
+
+impl<S: Into<String>> From<(S, ChatRequest)> for ChatBatchItem {
+	fn from((custom_id, chat_req): (S, ChatRequest)) -> Self {
+		Self::new(custom_id, chat_req)
+	}
+}
+
+/// A submitted provider batch.
+///
+/// Serializable, so it can be stored to poll and fetch the results later (e.g., from another process).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatBatch {
+	/// The provider batch id (e.g., `batch_...` for OpenAI, `msgbatch_...` for Anthropic, `batches/...` for Gemini).
+	pub id: String,
+
+	/// The model of the batch requests.
+	pub model_iden: ModelIden,
+
+	pub status: BatchStatus,
+
+	/// The request counts, when reported by the provider.
+	pub request_counts: Option<BatchRequestCounts>,
+
+	/// Where the provider serves the results (OpenAI output file id, Anthropic results URL), once available.
+	pub results_ref: Option<String>,
+
+	/// Where the provider serves the failed requests, when separate from the results (OpenAI error file id).
+	pub errors_ref: Option<String>,
+}
+
+/// Processing status of a `ChatBatch`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BatchStatus {
+	/// Validating, queued, or running.
+	InProgress,
+	/// Being cancelled (some requests may still complete).
+	Cancelling,
+	/// Processed, the results can be fetched (some requests may have failed).
+	Completed,
+	/// Failed as a whole (e.g., invalid input).
+	Failed,
+	Cancelled,
+	/// Not completed in the provider processing window.
+	Expired,
+}
+
+impl BatchStatus {
+	/// Returns true once the batch will not change anymore.
+	pub fn is_done(&self) -> bool {
+		matches!(self, Self::Completed | Self::Failed | Self::Cancelled | Self::Expired)
+	}
+}
+
+/// Request counts of a `ChatBatch`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BatchRequestCounts {
+	pub total: u64,
+	pub succeeded: u64,
+	pub failed: u64,
+}
+
+/// The result of one `ChatBatchItem`, keyed by its `custom_id`.
+#[derive(Debug)]
+pub struct ChatBatchResult {
+	pub custom_id: String,
+
+	/// The response, as a synchronous call would have returned it, or the request error.
+	pub result: crate::Result<ChatResponse>,
+}
@@ -5,17 +5,18 @@
 //! Note 1: Additional client-level defaults may be added over time.
 //! Note 2: Kept separate from `ChatRequest` for easier reuse and composition.
 
-use crate::Headers;
 use crate::chat::chat_req_response_format::ChatResponseFormat;
-use crate::{Error, Result};
+use crate::{CancellationToken, Error, Headers, Result, ResumePolicy, RetryPolicy};
 use serde::{Deserialize, Serialize};
 use std::ops::Deref;
+use std::time::Duration;
 
 /// Options considered by all `Client::exec_*` chat calls.
 ///
 /// A default can be set on the `Client` during builder configuration.
 /// Per-call options take precedence over client defaults.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ChatOptions {
 	/// Sampling temperature (if supported by the provider).
 	pub temperature: Option<f64>,
@@ -72,6 +73,38 @@ pub struct ChatOptions {
 	/// Additional HTTP headers to include with the request.
 	pub extra_headers: Option<Headers>,
 
+	// -- Call Control Options
+	// Deserialized (e.g., from the config file aliases) but not serialized, as they do not change the response
+	// (so they are not part of the `ResponseCache` key).
+	/// Retry policy for this request (overrides the client `RetryPolicy`).
+	#[serde(skip_serializing)]
+	pub retry_policy: Option<RetryPolicy>,
+
+	/// Models to try, in order, when the requested model fails with a retryable or provider-down error.
+	#[serde(skip_serializing)]
+	pub fallback_models: Option<Vec<String>>,
+
+	/// Deadline of the call, retries and fallbacks included (for streams, until the first event).
+	/// Unlike `WebConfig` timeout, it does not bound a stream once started (see `stream_idle_timeout`).
+	#[serde(skip_serializing)]
+	pub timeout: Option<Duration>,
+
+	/// (streaming) Maximum wait between two stream events.
+	#[serde(skip_serializing)]
+	pub stream_idle_timeout: Option<Duration>,
+
+	/// (streaming) Resume the stream, with the content so far as an assistant prefill, when the connection drops.
+	#[serde(skip_serializing)]
+	pub resume_policy: Option<ResumePolicy>,
+
+	/// Validate the request against the model capabilities before sending it (see `Client::validate_chat_request`).
+	#[serde(skip_serializing)]
+	pub validate_request: Option<bool>,
+
+	/// Cancels the in-flight call (or ends the stream) with `Error::Cancelled` when cancelled.
+	#[serde(skip)]
+	pub cancellation_token: Option<CancellationToken>,
+
 	// -- Gemini Specific Options
 	/// Corresponds to `topK` in Gemini.
 	pub top_k: Option<i32>,
@@ -224,6 +257,52 @@ impl ChatOptions {
 		self
 	}
 
+	/// Sets the retry policy for this request.
+	pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.retry_policy = Some(retry_policy);
+		self
+	}
+
+	/// Sets the ordered list of fallback models (e.g., `["gpt-5", "gemini-2.5-pro"]`).
+	pub fn with_fallback_models<I>(mut self, models: I) -> Self
+	where
+		I: IntoIterator,
+		I::Item: Into<String>,
+	{
+		self.fallback_models = Some(models.into_iter().map(Into::into).collect());
+		self
+	}
+
+	/// Sets the deadline of the call (for streams, until the first event).
+	pub fn with_timeout(mut self, timeout: Duration) -> Self {
+		self.timeout = Some(timeout);
+		self
+	}
+
+	/// Sets the maximum wait between two stream events.
+	pub fn with_stream_idle_timeout(mut self, timeout: Duration) -> Self {
+		self.stream_idle_timeout = Some(timeout);
+		self
+	}
+
+	/// Sets the resume policy for streams (only for adapters supporting an assistant prefill).
+	pub fn with_resume_policy(mut self, resume_policy: ResumePolicy) -> Self {
+		self.resume_policy = Some(resume_policy);
+		self
+	}
+
+	/// Sets whether to validate the request against the model capabilities before sending it.
+	pub fn with_validate_request(mut self, value: bool) -> Self {
+		self.validate_request = Some(value);
+		self
+	}
+
+	/// Sets the token to cancel the call (keep a clone to call `cancel()`).
+	pub fn with_cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+		self.cancellation_token = Some(cancellation_token);
+		self
+	}
+
 	// -- Deprecated
 
 	/// Deprecated: use `with_response_format(ChatResponseFormat::JsonMode)`.
@@ -627,6 +706,16 @@ impl<'a, 'b> ChatOptionsSet<'a, 'b> {
 		self.chat = options;
 		self
 	}
+
+	/// The raw chat (request) level options.
+	pub fn chat_options(&self) -> Option<&'b ChatOptions> {
+		self.chat
+	}
+
+	/// The raw client level options.
+	pub fn client_options(&self) -> Option<&'a ChatOptions> {
+		self.client
+	}
 }
 
 impl ChatOptionsSet<'_, '_> {
@@ -751,6 +840,49 @@ impl ChatOptionsSet<'_, '_> {
 			.or_else(|| self.client.and_then(|client| client.extra_headers.as_ref()))
 	}
 
+	pub fn retry_policy(&self) -> Option<&RetryPolicy> {
+		self.chat
+			.and_then(|chat| chat.retry_policy.as_ref())
+			.or_else(|| self.client.and_then(|client| client.retry_policy.as_ref()))
+	}
+
+	pub fn timeout(&self) -> Option<Duration> {
+		self.chat
+			.and_then(|chat| chat.timeout)
+			.or_else(|| self.client.and_then(|client| client.timeout))
+	}
+
+	pub fn stream_idle_timeout(&self) -> Option<Duration> {
+		self.chat
+			.and_then(|chat| chat.stream_idle_timeout)
+			.or_else(|| self.client.and_then(|client| client.stream_idle_timeout))
+	}
+
+	pub fn resume_policy(&self) -> Option<&ResumePolicy> {
+		self.chat
+			.and_then(|chat| chat.resume_policy.as_ref())
+			.or_else(|| self.client.and_then(|client| client.resume_policy.as_ref()))
+	}
+
+	pub fn validate_request(&self) -> Option<bool> {
+		self.chat
+			.and_then(|chat| chat.validate_request)
+			.or_else(|| self.client.and_then(|client| client.validate_request))
+	}
+
+	pub fn cancellation_token(&self) -> Option<&CancellationToken> {
+		self.chat
+			.and_then(|chat| chat.cancellation_token.as_ref())
+			.or_else(|| self.client.and_then(|client| client.cancellation_token.as_ref()))
+	}
+
+	pub fn fallback_models(&self) -> &[String] {
+		self.chat
+			.and_then(|chat| chat.fallback_models.as_deref())
+			.or_else(|| self.client.and_then(|client| client.fallback_models.as_deref()))
+			.unwrap_or(&[])
+	}
+
 	/// Returns true only if there is a ChatResponseFormat::JsonMode
 	#[deprecated(note = "Use .response_format()")]
 	#[allow(unused)]
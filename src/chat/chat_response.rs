@@ -2,8 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::ModelIden;
-use crate::chat::{ChatStream, MessageContent, ToolCall, Usage};
+use crate::chat::{ChatStream, ChatTimings, ContentFilterResults, MessageContent, ToolCall, Usage};
+use crate::{FallbackAttempt, ModelIden};
 
 // region:    --- ChatResponse
 
@@ -30,8 +30,28 @@ pub struct ChatResponse {
 	/// Token usage reported by the provider.
 	pub usage: Usage,
 
+	/// Provider-reported reason the generation stopped (e.g., `stop`, `end_turn`, `STOP`, `length`), when available.
+	#[serde(default)]
+	pub finish_reason: Option<String>,
+
 	/// Raw response body for provider-specific features.
 	pub captured_raw_body: Option<serde_json::Value>,
+
+	/// Models that failed before `model_iden` answered (empty when no fallback was needed).
+	#[serde(default)]
+	pub fallback_attempts: Vec<FallbackAttempt>,
+
+	/// True when served from the client `ResponseCache`.
+	#[serde(default)]
+	pub cached: bool,
+
+	/// Latency and throughput of this call, set by the client (for a cached response, the ones of the cache hit).
+	#[serde(default)]
+	pub timings: Option<ChatTimings>,
+
+	/// Content filtering results reported by the provider (e.g., Azure OpenAI prompt and completion filters).
+	#[serde(default)]
+	pub content_filter_results: Option<ContentFilterResults>,
 }
 
 // Getters
@@ -1,5 +1,6 @@
 use crate::adapter::inter_stream::{InterStreamEnd, InterStreamEvent};
-use crate::chat::{MessageContent, ToolCall, Usage};
+use crate::chat::{ChatOptionsSet, ChatResponse, ChatTimings, ContentFilterResults, MessageContent, ToolCall, Usage};
+use crate::client::StreamMiddlewares;
 use futures::Stream;
 use serde::{Deserialize, Serialize};
 use std::pin::Pin;
@@ -10,11 +11,15 @@ type InterStreamType = Pin<Box<dyn Stream<Item = crate::Result<InterStreamEvent>
 /// A stream of chat events produced by a streaming chat request.
 pub struct ChatStream {
 	inter_stream: InterStreamType,
+	middlewares: Option<StreamMiddlewares>,
 }
 
 impl ChatStream {
 	pub(crate) fn new(inter_stream: InterStreamType) -> Self {
-		ChatStream { inter_stream }
+		ChatStream {
+			inter_stream,
+			middlewares: None,
+		}
 	}
 
 	pub(crate) fn from_inter_stream<T>(inter_stream: T) -> Self
@@ -24,6 +29,57 @@ impl ChatStream {
 		let boxed_stream: InterStreamType = Box::pin(inter_stream);
 		ChatStream::new(boxed_stream)
 	}
+
+	/// Replay a complete `ChatResponse` as a stream (Start, ReasoningChunk, Chunk(s), ToolCallChunk(s), End).
+	///
+	/// The `End` captured data follows the `capture_...` chat options, as for a provider stream.
+	pub(crate) fn from_chat_response(chat_res: ChatResponse, options_set: &ChatOptionsSet<'_, '_>) -> Self {
+		let ChatResponse {
+			content,
+			reasoning_content,
+			usage,
+			finish_reason,
+			content_filter_results,
+			..
+		} = chat_res;
+
+		let texts: Vec<String> = content.texts().into_iter().map(String::from).collect();
+		let tool_calls = content.into_tool_calls();
+
+		let mut events = vec![InterStreamEvent::Start];
+		if let Some(reasoning_content) = reasoning_content.as_ref() {
+			events.push(InterStreamEvent::ReasoningChunk(reasoning_content.clone()));
+		}
+		events.extend(texts.iter().cloned().map(InterStreamEvent::Chunk));
+		events.extend(tool_calls.iter().cloned().map(InterStreamEvent::ToolCallChunk));
+
+		let end = InterStreamEnd {
+			captured_usage: options_set.capture_usage().unwrap_or(false).then_some(usage),
+			captured_text_content: (options_set.capture_content().unwrap_or(false) && !texts.is_empty())
+				.then(|| texts.concat()),
+			captured_reasoning_content: reasoning_content
+				.filter(|_| options_set.capture_reasoning_content().unwrap_or(false)),
+			captured_tool_calls: (options_set.capture_tool_calls().unwrap_or(false) && !tool_calls.is_empty())
+				.then_some(tool_calls),
+			captured_thought_signature: None,
+			timings: None,
+			captured_content_filter_results: content_filter_results.map(Box::new),
+			captured_finish_reason: finish_reason,
+		};
+		events.push(InterStreamEvent::End(end));
+
+		ChatStream::from_inter_stream(futures::stream::iter(events.into_iter().map(Ok)))
+	}
+
+	pub(crate) fn with_middlewares(mut self, middlewares: StreamMiddlewares) -> Self {
+		self.middlewares = Some(middlewares);
+		self
+	}
+
+	/// Note: Attached middlewares are not carried over (they are attached last, by the client).
+	pub(crate) fn into_inter_stream(self) -> InterStreamType {
+		self.inter_stream
+	}
 }
 
 // region:    --- Stream Impl
@@ -36,7 +92,7 @@ impl Stream for ChatStream {
 
 		match Pin::new(&mut this.inter_stream).poll_next(cx) {
 			Poll::Ready(Some(Ok(event))) => {
-				let chat_event = match event {
+				let mut chat_event = match event {
 					InterStreamEvent::Start => ChatStreamEvent::Start,
 					InterStreamEvent::Chunk(content) => ChatStreamEvent::Chunk(StreamChunk { content }),
 					InterStreamEvent::ReasoningChunk(content) => {
@@ -46,7 +102,15 @@ impl Stream for ChatStream {
 						ChatStreamEvent::ToolCallChunk(ToolChunk { tool_call })
 					}
 					InterStreamEvent::End(inter_end) => ChatStreamEvent::End(inter_end.into()),
+					InterStreamEvent::Resumed { attempt, cause } => {
+						ChatStreamEvent::Resumed(StreamResumed { attempt, cause })
+					}
 				};
+				if let Some(middlewares) = this.middlewares.as_ref()
+					&& let Err(err) = middlewares.on_stream_event(&mut chat_event)
+				{
+					return Poll::Ready(Some(Err(err)));
+				}
 				Poll::Ready(Some(Ok(chat_event)))
 			}
 			Poll::Ready(Some(Err(e))) => Poll::Ready(Some(Err(e))),
@@ -78,6 +142,10 @@ pub enum ChatStreamEvent {
 	/// End of stream.
 	/// May include captured usage and/or content when enabled via `ChatOptions`.
 	End(StreamEnd),
+
+	/// The connection dropped and the request was re-issued (see `ResumePolicy`).
+	/// The following chunks continue the content streamed so far.
+	Resumed(StreamResumed),
 }
 
 /// Content of `ChatStreamEvent::Chunk`.
@@ -95,6 +163,16 @@ pub struct ToolChunk {
 	pub tool_call: ToolCall,
 }
 
+/// Content of `ChatStreamEvent::Resumed`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StreamResumed {
+	/// The resume number (1 for the first resume of the stream).
+	pub attempt: u32,
+
+	/// The error that interrupted the stream.
+	pub cause: String,
+}
+
 /// Terminal event data with optionally captured usage and content.
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct StreamEnd {
@@ -110,6 +188,16 @@ pub struct StreamEnd {
 	/// Captured reasoning content if `ChatOptions.capture_reasoning` is enabled.
 	pub captured_reasoning_content: Option<String>,
 	pub captured_thought_signature: Option<String>,
+
+	/// Latency and throughput of the stream, set by the client.
+	pub timings: Option<ChatTimings>,
+
+	/// Content filtering results reported by the provider (e.g., Azure OpenAI), regardless of the capture options.
+	pub captured_content_filter_results: Option<ContentFilterResults>,
+
+	/// The provider finish reason (as `ChatResponse.finish_reason`), regardless of the capture options.
+	#[serde(default)]
+	pub captured_finish_reason: Option<String>,
 }
 
 impl From<InterStreamEnd> for StreamEnd {
@@ -138,6 +226,9 @@ impl From<InterStreamEnd> for StreamEnd {
 			captured_content,
 			captured_reasoning_content: inter_end.captured_reasoning_content,
 			captured_thought_signature: inter_end.captured_thought_signature,
+			timings: inter_end.timings,
+			captured_content_filter_results: inter_end.captured_content_filter_results.map(|results| *results),
+			captured_finish_reason: inter_end.captured_finish_reason,
 		}
 	}
 }
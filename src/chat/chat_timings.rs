@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Latency and throughput of a chat call, measured by the client from the start of the `exec_chat...` call
+/// (so retries, fallbacks, and resumes are included).
+///
+/// Set on `ChatResponse.timings` and `StreamEnd.timings`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChatTimings {
+	/// Until the response was parsed (non-streaming) or the stream ended.
+	pub total: Duration,
+
+	/// Until the first content chunk of a stream (`None` for a non-streaming call or a stream without content).
+	pub time_to_first_token: Option<Duration>,
+
+	/// Until the first reasoning chunk of a stream (`None` for a non-streaming call or a stream without reasoning).
+	pub time_to_first_reasoning_token: Option<Duration>,
+
+	/// Completion tokens per second, over the generation time (from the first token for a stream, the total otherwise).
+	/// `None` without usage (e.g., a stream without `capture_usage`).
+	pub output_tokens_per_sec: Option<f64>,
+}
+
+impl ChatTimings {
+	/// Create the timings of a completed call.
+	pub(crate) fn new(
+		total: Duration,
+		time_to_first_token: Option<Duration>,
+		time_to_first_reasoning_token: Option<Duration>,
+		completion_tokens: Option<i32>,
+	) -> Self {
+		// The generation starts with the first token (content or reasoning) when streamed.
+		let first_token = match (time_to_first_token, time_to_first_reasoning_token) {
+			(Some(content), Some(reasoning)) => Some(content.min(reasoning)),
+			(first_token, None) | (None, first_token) => first_token,
+		};
+		let generation = total.saturating_sub(first_token.unwrap_or_default()).as_secs_f64();
+		let output_tokens_per_sec = completion_tokens
+			.filter(|tokens| *tokens > 0 && generation > 0.)
+			.map(|tokens| tokens as f64 / generation);
+
+		Self {
+			total,
+			time_to_first_token,
+			time_to_first_reasoning_token,
+			output_tokens_per_sec,
+		}
+	}
+}
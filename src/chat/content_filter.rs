@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+/// The content filtering results of a chat call, as reported by the provider (e.g., Azure OpenAI).
+///
+/// Set on `ChatResponse.content_filter_results` and `StreamEnd.captured_content_filter_results`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ContentFilterResults {
+	/// The results of the prompt (e.g., Azure `prompt_filter_results`).
+	pub prompt: Vec<ContentFilterResult>,
+
+	/// The results of the completion (e.g., Azure `choices[].content_filter_results`).
+	pub completion: Vec<ContentFilterResult>,
+}
+
+/// The result of one content filter category.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContentFilterResult {
+	/// e.g., `hate`, `sexual`, `violence`, `self_harm`, `jailbreak`, `protected_material_text`
+	pub category: String,
+
+	/// True when the content was filtered (blocked or truncated) for this category.
+	pub filtered: bool,
+
+	/// The severity of the severity categories (e.g., `safe`, `low`, `medium`, `high`).
+	#[serde(default)]
+	pub severity: Option<String>,
+
+	/// The detection of the detection categories (e.g., `jailbreak`, `protected_material_code`).
+	#[serde(default)]
+	pub detected: Option<bool>,
+}
+
+/// Getters
+impl ContentFilterResults {
+	/// True when the prompt or the completion was filtered for any category.
+	pub fn is_filtered(&self) -> bool {
+		self.prompt.iter().chain(&self.completion).any(|result| result.filtered)
+	}
+
+	/// The categories that filtered the prompt or the completion (without duplicates).
+	pub fn filtered_categories(&self) -> Vec<&str> {
+		let mut categories: Vec<&str> = Vec::new();
+		for result in self.prompt.iter().chain(&self.completion).filter(|result| result.filtered) {
+			if !categories.contains(&result.category.as_str()) {
+				categories.push(&result.category);
+			}
+		}
+		categories
+	}
+}
+
+/// Stream support
+impl ContentFilterResults {
+	/// Merges the completion results of a stream chunk, per category:
+	/// `filtered` and `detected` stay set once set, and the highest severity is kept.
+	pub(crate) fn merge_completion(&mut self, results: Vec<ContentFilterResult>) {
+		for result in results {
+			match self.completion.iter_mut().find(|existing| existing.category == result.category) {
+				Some(existing) => {
+					existing.filtered |= result.filtered;
+					if result.detected.is_some() {
+						existing.detected = Some(existing.detected.unwrap_or(false) | result.detected.unwrap_or(false));
+					}
+					if severity_rank(result.severity.as_deref()) > severity_rank(existing.severity.as_deref()) {
+						existing.severity = result.severity;
+					}
+				}
+				None => self.completion.push(result),
+			}
+		}
+	}
+}
+
+fn severity_rank(severity: Option<&str>) -> u8 {
+	match severity {
+		Some("safe") => 1,
+		Some("low") => 2,
+		Some("medium") => 3,
+		Some("high") => 4,
+		_ => 0,
+	}
+}
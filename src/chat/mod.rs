@@ -3,29 +3,39 @@
 
 // region:    --- Modules
 
+mod chat_batch;
 mod chat_message;
 mod chat_options;
 mod chat_req_response_format;
 mod chat_request;
 mod chat_response;
 mod chat_stream;
+mod chat_timings;
+mod content_filter;
 mod content_part;
 mod imagen_types;
 mod message_content;
+mod model_capabilities;
+mod pricing;
 mod tool;
 mod usage;
 mod veo_types;
 
 // -- Flatten
+pub use chat_batch::*;
 pub use chat_message::*;
 pub use chat_options::*;
 pub use chat_req_response_format::*;
 pub use chat_request::*;
 pub use chat_response::*;
 pub use chat_stream::*;
+pub use chat_timings::*;
+pub use content_filter::*;
 pub use content_part::*;
 pub use imagen_types::*;
 pub use message_content::*;
+pub use model_capabilities::*;
+pub use pricing::*;
 pub use tool::*;
 pub use usage::*;
 pub use veo_types::*;
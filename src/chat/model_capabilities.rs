@@ -0,0 +1,378 @@
+//! What the models support through genai, to check a request before sending it.
+//! - `ModelCapabilities` holds the supported inputs and features of a model, and its token limits.
+//! - `CapabilityRegistry` maps the models to their capabilities (built-in defaults, which can be overridden).
+//!
+//! Note: A `None` capability is unknown (e.g., a model without built-in data), not unsupported.
+//!       The built-in data is for the common models at the time of writing.
+
+use crate::adapter::AdapterKind;
+use crate::{ModelIden, ModelInfo};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+// region:    --- ModelCapabilities
+
+/// The capabilities of a model (each one `None` when unknown).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModelCapabilities {
+	/// Image input (vision).
+	pub image_input: Option<bool>,
+
+	/// PDF document input.
+	pub pdf_input: Option<bool>,
+
+	/// Function calling (`ChatRequest.tools`).
+	pub tools: Option<bool>,
+
+	/// JSON schema structured output (`ChatResponseFormat::JsonSpec`).
+	pub json_schema: Option<bool>,
+
+	/// Reasoning (thinking) output.
+	pub reasoning: Option<bool>,
+
+	/// Usage reported at the end of a stream (see `ChatOptions.capture_usage`).
+	pub stream_usage: Option<bool>,
+
+	/// The context window, in tokens (input and output).
+	pub context_window: Option<u32>,
+
+	/// The maximum output tokens of a response.
+	pub max_output_tokens: Option<u32>,
+}
+
+/// Chainable Setters
+impl ModelCapabilities {
+	pub fn with_image_input(mut self, supported: bool) -> Self {
+		self.image_input = Some(supported);
+		self
+	}
+
+	pub fn with_pdf_input(mut self, supported: bool) -> Self {
+		self.pdf_input = Some(supported);
+		self
+	}
+
+	pub fn with_tools(mut self, supported: bool) -> Self {
+		self.tools = Some(supported);
+		self
+	}
+
+	pub fn with_json_schema(mut self, supported: bool) -> Self {
+		self.json_schema = Some(supported);
+		self
+	}
+
+	pub fn with_reasoning(mut self, supported: bool) -> Self {
+		self.reasoning = Some(supported);
+		self
+	}
+
+	pub fn with_stream_usage(mut self, supported: bool) -> Self {
+		self.stream_usage = Some(supported);
+		self
+	}
+
+	pub fn with_context_window(mut self, tokens: u32) -> Self {
+		self.context_window = Some(tokens);
+		self
+	}
+
+	pub fn with_max_output_tokens(mut self, tokens: u32) -> Self {
+		self.max_output_tokens = Some(tokens);
+		self
+	}
+}
+
+impl ModelCapabilities {
+	/// Set the capabilities known by the overlay (its `None` ones are left as is).
+	pub fn merge(&mut self, overlay: &ModelCapabilities) {
+		self.image_input = overlay.image_input.or(self.image_input);
+		self.pdf_input = overlay.pdf_input.or(self.pdf_input);
+		self.tools = overlay.tools.or(self.tools);
+		self.json_schema = overlay.json_schema.or(self.json_schema);
+		self.reasoning = overlay.reasoning.or(self.reasoning);
+		self.stream_usage = overlay.stream_usage.or(self.stream_usage);
+		self.context_window = overlay.context_window.or(self.context_window);
+		self.max_output_tokens = overlay.max_output_tokens.or(self.max_output_tokens);
+	}
+}
+
+/// The capabilities given by a provider models endpoint (only the context window for now).
+impl From<&ModelInfo> for ModelCapabilities {
+	fn from(model_info: &ModelInfo) -> Self {
+		Self {
+			context_window: model_info.context_window,
+			..Default::default()
+		}
+	}
+}
+
+// endregion: --- ModelCapabilities
+
+// region:    --- CapabilityRegistry
+
+/// Capabilities of the models, keyed by `ModelIden`.
+///
+/// A model gets the capabilities of all the registered names it starts with (for the same adapter kind),
+/// merged from the shortest to the longest name, so a dated or suffixed name (e.g., `gpt-4o-2024-08-06`)
+/// gets its family ones, and a more specific entry only needs the capabilities that differ.
+/// The OpenAI Responses adapter uses the OpenAI entries.
+///
+/// `CapabilityRegistry::default()` has the built-in defaults, `CapabilityRegistry::empty()` has none.
+#[derive(Debug, Clone)]
+pub struct CapabilityRegistry {
+	entries: HashMap<ModelIden, ModelCapabilities>,
+}
+
+impl Default for CapabilityRegistry {
+	fn default() -> Self {
+		DEFAULT_REGISTRY.clone()
+	}
+}
+
+/// Constructors
+impl CapabilityRegistry {
+	/// Create a registry without any capabilities.
+	pub fn empty() -> Self {
+		Self {
+			entries: HashMap::new(),
+		}
+	}
+}
+
+impl CapabilityRegistry {
+	/// The built-in registry (what `CapabilityRegistry::default()` clones).
+	pub(crate) fn builtin() -> &'static CapabilityRegistry {
+		&DEFAULT_REGISTRY
+	}
+}
+
+/// Chainable Setters
+impl CapabilityRegistry {
+	/// Set (or override) the capabilities of a model (and of the model names starting with it).
+	pub fn with_model_capabilities(mut self, model_iden: ModelIden, capabilities: ModelCapabilities) -> Self {
+		self.set_model_capabilities(model_iden, capabilities);
+		self
+	}
+}
+
+impl CapabilityRegistry {
+	/// Set (or override) the capabilities of a model (and of the model names starting with it).
+	///
+	/// Merged over the capabilities already registered for this exact name (the `None` ones are left as is).
+	pub fn set_model_capabilities(&mut self, model_iden: ModelIden, capabilities: ModelCapabilities) {
+		let model_iden = ModelIden::new(registry_kind(model_iden.adapter_kind), model_iden.model_name);
+		self.entries.entry(model_iden).or_default().merge(&capabilities);
+	}
+
+	/// Returns the capabilities of the model, if any is registered.
+	pub fn get(&self, model_iden: &ModelIden) -> Option<ModelCapabilities> {
+		let adapter_kind = registry_kind(model_iden.adapter_kind);
+		let (model_name, _) = model_iden.model_name.as_model_name_and_namespace();
+
+		let mut matches: Vec<_> = self
+			.entries
+			.iter()
+			.filter(|(iden, _)| iden.adapter_kind == adapter_kind && model_name.starts_with(&*iden.model_name))
+			.collect();
+		matches.sort_by_key(|(iden, _)| iden.model_name.len());
+
+		matches
+			.into_iter()
+			.map(|(_, capabilities)| capabilities)
+			.fold(None, |acc, capabilities| {
+				let mut acc: ModelCapabilities = acc.unwrap_or_default();
+				acc.merge(capabilities);
+				Some(acc)
+			})
+	}
+}
+
+/// The adapter kinds sharing the capabilities of another one.
+fn registry_kind(adapter_kind: AdapterKind) -> AdapterKind {
+	match adapter_kind {
+		AdapterKind::OpenAIResp => AdapterKind::OpenAI,
+		other => other,
+	}
+}
+
+// endregion: --- CapabilityRegistry
+
+// region:    --- Default Capabilities
+
+static DEFAULT_REGISTRY: LazyLock<CapabilityRegistry> = LazyLock::new(|| {
+	let mut registry = CapabilityRegistry::empty();
+	for (adapter_kind, model_name, capabilities) in DEFAULT_CAPABILITIES {
+		registry.set_model_capabilities(ModelIden::new(*adapter_kind, *model_name), *capabilities);
+	}
+	registry
+});
+
+const IMAGE: u8 = 1;
+const PDF: u8 = 1 << 1;
+const TOOLS: u8 = 1 << 2;
+const JSON_SCHEMA: u8 = 1 << 3;
+const REASONING: u8 = 1 << 4;
+const STREAM_USAGE: u8 = 1 << 5;
+
+/// The capabilities of a built-in row (all the flags are known, set or not).
+const fn caps(flags: u8, context_window: u32, max_output_tokens: u32) -> ModelCapabilities {
+	ModelCapabilities {
+		image_input: Some(flags & IMAGE != 0),
+		pdf_input: Some(flags & PDF != 0),
+		tools: Some(flags & TOOLS != 0),
+		json_schema: Some(flags & JSON_SCHEMA != 0),
+		reasoning: Some(flags & REASONING != 0),
+		stream_usage: Some(flags & STREAM_USAGE != 0),
+		context_window: Some(context_window),
+		max_output_tokens: Some(max_output_tokens),
+	}
+}
+
+const OPENAI: u8 = IMAGE | PDF | TOOLS | JSON_SCHEMA | STREAM_USAGE;
+// Note: Anthropic has no `JsonSpec` support in genai yet.
+const ANTHROPIC: u8 = IMAGE | PDF | TOOLS | STREAM_USAGE;
+const GEMINI: u8 = IMAGE | PDF | TOOLS | JSON_SCHEMA | STREAM_USAGE;
+
+/// (adapter_kind, model name prefix, capabilities)
+const DEFAULT_CAPABILITIES: &[(AdapterKind, &str, ModelCapabilities)] = &[
+	// -- OpenAI
+	(AdapterKind::OpenAI, "gpt-5", caps(OPENAI | REASONING, 400_000, 128_000)),
+	(AdapterKind::OpenAI, "gpt-4.1", caps(OPENAI, 1_047_576, 32_768)),
+	(AdapterKind::OpenAI, "gpt-4o", caps(OPENAI, 128_000, 16_384)),
+	(AdapterKind::OpenAI, "o3", caps(OPENAI | REASONING, 200_000, 100_000)),
+	(
+		AdapterKind::OpenAI,
+		"o3-mini",
+		caps(TOOLS | JSON_SCHEMA | REASONING | STREAM_USAGE, 200_000, 100_000),
+	),
+	(
+		AdapterKind::OpenAI,
+		"o4-mini",
+		caps(OPENAI | REASONING, 200_000, 100_000),
+	),
+	(
+		AdapterKind::OpenAI,
+		"gpt-3.5-turbo",
+		caps(TOOLS | STREAM_USAGE, 16_385, 4_096),
+	),
+	// -- Anthropic
+	(
+		AdapterKind::Anthropic,
+		"claude-opus-4",
+		caps(ANTHROPIC | REASONING, 200_000, 32_000),
+	),
+	(
+		AdapterKind::Anthropic,
+		"claude-opus-4-5",
+		caps(ANTHROPIC | REASONING, 200_000, 64_000),
+	),
+	(
+		AdapterKind::Anthropic,
+		"claude-sonnet-4",
+		caps(ANTHROPIC | REASONING, 200_000, 64_000),
+	),
+	(
+		AdapterKind::Anthropic,
+		"claude-haiku-4-5",
+		caps(ANTHROPIC | REASONING, 200_000, 64_000),
+	),
+	(
+		AdapterKind::Anthropic,
+		"claude-3-7-sonnet",
+		caps(ANTHROPIC | REASONING, 200_000, 64_000),
+	),
+	(
+		AdapterKind::Anthropic,
+		"claude-3-5-sonnet",
+		caps(ANTHROPIC, 200_000, 8_192),
+	),
+	(
+		AdapterKind::Anthropic,
+		"claude-3-5-haiku",
+		caps(ANTHROPIC, 200_000, 8_192),
+	),
+	(
+		AdapterKind::Anthropic,
+		"claude-3-opus",
+		caps(IMAGE | TOOLS | STREAM_USAGE, 200_000, 4_096),
+	),
+	(
+		AdapterKind::Anthropic,
+		"claude-3-haiku",
+		caps(IMAGE | TOOLS | STREAM_USAGE, 200_000, 4_096),
+	),
+	// -- Gemini
+	(
+		AdapterKind::Gemini,
+		"gemini-3-pro",
+		caps(GEMINI | REASONING, 1_048_576, 65_536),
+	),
+	(
+		AdapterKind::Gemini,
+		"gemini-2.5",
+		caps(GEMINI | REASONING, 1_048_576, 65_536),
+	),
+	(AdapterKind::Gemini, "gemini-2.0-flash", caps(GEMINI, 1_048_576, 8_192)),
+	// -- DeepSeek (JSON object output only)
+	(
+		AdapterKind::DeepSeek,
+		"deepseek-chat",
+		caps(TOOLS | STREAM_USAGE, 128_000, 8_192),
+	),
+	(
+		AdapterKind::DeepSeek,
+		"deepseek-reasoner",
+		caps(TOOLS | REASONING | STREAM_USAGE, 128_000, 64_000),
+	),
+	// -- xAI
+	(
+		AdapterKind::Xai,
+		"grok-4",
+		caps(IMAGE | TOOLS | JSON_SCHEMA | REASONING | STREAM_USAGE, 256_000, 256_000),
+	),
+	(
+		AdapterKind::Xai,
+		"grok-3-mini",
+		caps(TOOLS | JSON_SCHEMA | REASONING | STREAM_USAGE, 131_072, 131_072),
+	),
+];
+
+// endregion: --- Default Capabilities
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_capability_registry_lookup_and_override() {
+		// Family entry, for a dated name.
+		let registry = CapabilityRegistry::default();
+		let gpt_4o = ModelIden::new(AdapterKind::OpenAIResp, "gpt-4o-2024-08-06");
+		let capabilities = registry.get(&gpt_4o).unwrap();
+		assert_eq!(capabilities.image_input, Some(true));
+		assert_eq!(capabilities.reasoning, Some(false));
+		assert_eq!(capabilities.context_window, Some(128_000));
+		assert!(registry.get(&ModelIden::new(AdapterKind::Ollama, "gemma3")).is_none());
+
+		// Override of one capability, merged over the built-in ones.
+		let registry = registry
+			.with_model_capabilities(
+				ModelIden::new(AdapterKind::OpenAI, "gpt-4o"),
+				ModelCapabilities::default().with_context_window(64_000),
+			)
+			.with_model_capabilities(
+				ModelIden::new(AdapterKind::OpenAI, "gpt-4o-2024-08-06"),
+				ModelCapabilities::default().with_tools(false),
+			);
+		let capabilities = registry.get(&gpt_4o).unwrap();
+		assert_eq!(capabilities.context_window, Some(64_000));
+		assert_eq!(capabilities.tools, Some(false));
+		assert_eq!(capabilities.image_input, Some(true));
+	}
+}
+
+// endregion: --- Tests
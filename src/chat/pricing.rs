@@ -0,0 +1,440 @@
+//! Cost accounting from the normalized `Usage`.
+//! - `ModelPricing` holds the per million tokens rates of a model (USD).
+//! - `PricingRegistry` maps the models to their pricing (built-in defaults, which can be overridden).
+//! - `CostTracker` totals the spend per model across a session or a batch.
+//!
+//! Note: The built-in rates are the provider list prices (standard tier, short context) at the time of writing.
+//!       Providers change them, so set the ones that matter with `PricingRegistry::with_model_pricing(..)`.
+
+use crate::ModelIden;
+use crate::adapter::AdapterKind;
+use crate::chat::{ChatResponse, Usage};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+// region:    --- ModelPricing
+
+/// Rates of a model, in USD per million tokens.
+///
+/// The optional rates default to the `input` rate (cache) or the `output` rate (reasoning) when not set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelPricing {
+	/// Uncached input tokens.
+	pub input: f64,
+
+	/// Output tokens (including the reasoning tokens, unless `reasoning` is set).
+	pub output: f64,
+
+	/// Tokens written to the prompt cache (e.g., Anthropic `cache_creation_input_tokens`).
+	pub cache_write: Option<f64>,
+
+	/// Tokens read from the prompt cache.
+	pub cache_read: Option<f64>,
+
+	/// Reasoning tokens, when billed differently from the other output tokens.
+	pub reasoning: Option<f64>,
+}
+
+/// Constructors
+impl ModelPricing {
+	pub fn new(input: f64, output: f64) -> Self {
+		Self {
+			input,
+			output,
+			cache_write: None,
+			cache_read: None,
+			reasoning: None,
+		}
+	}
+}
+
+/// Chainable Setters
+impl ModelPricing {
+	pub fn with_cache_write(mut self, rate: f64) -> Self {
+		self.cache_write = Some(rate);
+		self
+	}
+
+	pub fn with_cache_read(mut self, rate: f64) -> Self {
+		self.cache_read = Some(rate);
+		self
+	}
+
+	pub fn with_reasoning(mut self, rate: f64) -> Self {
+		self.reasoning = Some(rate);
+		self
+	}
+}
+
+impl ModelPricing {
+	/// Returns the cost (USD) of the usage.
+	///
+	/// `prompt_tokens` includes the cache read and write tokens, and `completion_tokens` the reasoning tokens
+	/// (see `Usage`), so they are priced separately from the rest.
+	pub fn cost(&self, usage: &Usage) -> f64 {
+		let details = usage.prompt_tokens_details.as_ref();
+		let cache_write_tokens = tokens(details.and_then(|d| d.cache_creation_tokens));
+		let cache_read_tokens = tokens(details.and_then(|d| d.cached_tokens));
+		let input_tokens = (tokens(usage.prompt_tokens) - cache_write_tokens - cache_read_tokens).max(0.);
+
+		let reasoning_tokens = match self.reasoning {
+			Some(_) => tokens(usage.completion_tokens_details.as_ref().and_then(|d| d.reasoning_tokens)),
+			None => 0.,
+		};
+		let output_tokens = (tokens(usage.completion_tokens) - reasoning_tokens).max(0.);
+
+		let cost = input_tokens * self.input
+			+ cache_write_tokens * self.cache_write.unwrap_or(self.input)
+			+ cache_read_tokens * self.cache_read.unwrap_or(self.input)
+			+ output_tokens * self.output
+			+ reasoning_tokens * self.reasoning.unwrap_or(self.output);
+
+		cost / 1_000_000.
+	}
+}
+
+fn tokens(count: Option<i32>) -> f64 {
+	count.unwrap_or_default().max(0) as f64
+}
+
+// endregion: --- ModelPricing
+
+// region:    --- PricingRegistry
+
+/// Pricing of the models, keyed by `ModelIden`.
+///
+/// A model name matches the longest registered name it starts with, for the same adapter kind,
+/// so `gpt-4o` covers `gpt-4o-2024-08-06` (but not `gpt-4o-mini`, which has its own entry).
+/// The OpenAI Responses adapter uses the OpenAI entries.
+///
+/// `PricingRegistry::default()` has the built-in defaults, `PricingRegistry::empty()` has none.
+#[derive(Debug, Clone)]
+pub struct PricingRegistry {
+	entries: HashMap<ModelIden, ModelPricing>,
+}
+
+impl Default for PricingRegistry {
+	fn default() -> Self {
+		DEFAULT_REGISTRY.clone()
+	}
+}
+
+/// Constructors
+impl PricingRegistry {
+	/// Create a registry without any pricing.
+	pub fn empty() -> Self {
+		Self {
+			entries: HashMap::new(),
+		}
+	}
+}
+
+/// Chainable Setters
+impl PricingRegistry {
+	/// Set (or override) the pricing of a model (and of the model names starting with it).
+	pub fn with_model_pricing(mut self, model_iden: ModelIden, pricing: ModelPricing) -> Self {
+		self.set_model_pricing(model_iden, pricing);
+		self
+	}
+}
+
+impl PricingRegistry {
+	/// Set (or override) the pricing of a model (and of the model names starting with it).
+	pub fn set_model_pricing(&mut self, model_iden: ModelIden, pricing: ModelPricing) {
+		let model_iden = ModelIden::new(pricing_kind(model_iden.adapter_kind), model_iden.model_name);
+		self.entries.insert(model_iden, pricing);
+	}
+
+	/// Returns the pricing of the model, if known.
+	pub fn get(&self, model_iden: &ModelIden) -> Option<&ModelPricing> {
+		let adapter_kind = pricing_kind(model_iden.adapter_kind);
+		let (model_name, _) = model_iden.model_name.as_model_name_and_namespace();
+
+		self.entries
+			.iter()
+			.filter(|(iden, _)| iden.adapter_kind == adapter_kind && model_name.starts_with(&*iden.model_name))
+			.max_by_key(|(iden, _)| iden.model_name.len())
+			.map(|(_, pricing)| pricing)
+	}
+
+	/// Returns the cost (USD) of the usage for the model, if its pricing is known.
+	pub fn cost(&self, model_iden: &ModelIden, usage: &Usage) -> Option<f64> {
+		self.get(model_iden).map(|pricing| pricing.cost(usage))
+	}
+}
+
+/// The adapter kinds sharing the prices of another one.
+fn pricing_kind(adapter_kind: AdapterKind) -> AdapterKind {
+	match adapter_kind {
+		AdapterKind::OpenAIResp => AdapterKind::OpenAI,
+		other => other,
+	}
+}
+
+// endregion: --- PricingRegistry
+
+// region:    --- Usage Cost
+
+impl Usage {
+	/// Returns the cost (USD) of this usage with the built-in pricing, if the model is known.
+	///
+	/// Use `PricingRegistry::cost(..)` for custom pricing.
+	pub fn cost(&self, model_iden: &ModelIden) -> Option<f64> {
+		DEFAULT_REGISTRY.cost(model_iden, self)
+	}
+}
+
+// endregion: --- Usage Cost
+
+// region:    --- CostTracker
+
+/// Spend of one model in a `CostTracker`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ModelSpend {
+	/// Number of recorded usages.
+	pub requests: u64,
+
+	/// Number of recorded usages without a known pricing (not in `cost`).
+	pub unpriced_requests: u64,
+
+	pub prompt_tokens: u64,
+	pub completion_tokens: u64,
+
+	/// Total cost (USD).
+	pub cost: f64,
+}
+
+/// Accumulates the spend per model (e.g., across a session or a batch).
+///
+/// It can be shared between tasks (e.g., in an `Arc`), as recording only needs `&self`.
+#[derive(Debug, Default)]
+pub struct CostTracker {
+	registry: PricingRegistry,
+	spends: Mutex<HashMap<ModelIden, ModelSpend>>,
+}
+
+/// Constructors
+impl CostTracker {
+	/// Create a tracker with the built-in pricing.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Create a tracker with a custom pricing registry.
+	pub fn with_registry(registry: PricingRegistry) -> Self {
+		Self {
+			registry,
+			spends: Mutex::default(),
+		}
+	}
+}
+
+impl CostTracker {
+	/// Record a usage of the model, and return its cost (if the model pricing is known).
+	pub fn record(&self, model_iden: &ModelIden, usage: &Usage) -> Option<f64> {
+		let cost = self.registry.cost(model_iden, usage);
+
+		let mut spends = self.lock_spends();
+		let spend = spends.entry(model_iden.clone()).or_default();
+		spend.requests += 1;
+		spend.prompt_tokens += usage.prompt_tokens.unwrap_or_default().max(0) as u64;
+		spend.completion_tokens += usage.completion_tokens.unwrap_or_default().max(0) as u64;
+		match cost {
+			Some(cost) => spend.cost += cost,
+			None => spend.unpriced_requests += 1,
+		}
+
+		cost
+	}
+
+	/// Record the usage of a chat response (nothing for a response served from the client cache).
+	pub fn record_response(&self, chat_res: &ChatResponse) -> Option<f64> {
+		if chat_res.cached {
+			return Some(0.);
+		}
+		self.record(&chat_res.model_iden, &chat_res.usage)
+	}
+
+	/// Total cost (USD) of all the recorded usages.
+	pub fn total_cost(&self) -> f64 {
+		self.lock_spends().values().map(|spend| spend.cost).sum()
+	}
+
+	/// Returns the spend of the model, if any was recorded.
+	pub fn spend(&self, model_iden: &ModelIden) -> Option<ModelSpend> {
+		self.lock_spends().get(model_iden).cloned()
+	}
+
+	/// Returns the spend of each model.
+	pub fn spends(&self) -> HashMap<ModelIden, ModelSpend> {
+		self.lock_spends().clone()
+	}
+
+	/// Clear all the recorded spend.
+	pub fn reset(&self) {
+		self.lock_spends().clear();
+	}
+
+	fn lock_spends(&self) -> std::sync::MutexGuard<'_, HashMap<ModelIden, ModelSpend>> {
+		self.spends.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+	}
+}
+
+// endregion: --- CostTracker
+
+// region:    --- Default Pricing
+
+static DEFAULT_REGISTRY: LazyLock<PricingRegistry> = LazyLock::new(|| {
+	let mut registry = PricingRegistry::empty();
+	for (adapter_kind, model_name, input, output, cache_write, cache_read) in DEFAULT_PRICING {
+		let mut pricing = ModelPricing::new(*input, *output);
+		pricing.cache_write = *cache_write;
+		pricing.cache_read = *cache_read;
+		registry.set_model_pricing(ModelIden::new(*adapter_kind, *model_name), pricing);
+	}
+	registry
+});
+
+type PricingRow = (AdapterKind, &'static str, f64, f64, Option<f64>, Option<f64>);
+
+/// (adapter_kind, model name prefix, input, output, cache_write, cache_read) in USD per million tokens.
+const DEFAULT_PRICING: &[PricingRow] = &[
+	// -- OpenAI
+	(AdapterKind::OpenAI, "gpt-5", 1.25, 10., None, Some(0.125)),
+	(AdapterKind::OpenAI, "gpt-5-mini", 0.25, 2., None, Some(0.025)),
+	(AdapterKind::OpenAI, "gpt-5-nano", 0.05, 0.4, None, Some(0.005)),
+	(AdapterKind::OpenAI, "gpt-4.1", 2., 8., None, Some(0.5)),
+	(AdapterKind::OpenAI, "gpt-4.1-mini", 0.4, 1.6, None, Some(0.1)),
+	(AdapterKind::OpenAI, "gpt-4.1-nano", 0.1, 0.4, None, Some(0.025)),
+	(AdapterKind::OpenAI, "gpt-4o", 2.5, 10., None, Some(1.25)),
+	(AdapterKind::OpenAI, "gpt-4o-mini", 0.15, 0.6, None, Some(0.075)),
+	(AdapterKind::OpenAI, "o3", 2., 8., None, Some(0.5)),
+	(AdapterKind::OpenAI, "o3-pro", 20., 80., None, None),
+	(AdapterKind::OpenAI, "o3-mini", 1.1, 4.4, None, Some(0.55)),
+	(AdapterKind::OpenAI, "o4-mini", 1.1, 4.4, None, Some(0.275)),
+	// -- Anthropic (cache write at the 5 minutes TTL rate)
+	(
+		AdapterKind::Anthropic,
+		"claude-opus-4",
+		15.,
+		75.,
+		Some(18.75),
+		Some(1.5),
+	),
+	(
+		AdapterKind::Anthropic,
+		"claude-opus-4-5",
+		5.,
+		25.,
+		Some(6.25),
+		Some(0.5),
+	),
+	(
+		AdapterKind::Anthropic,
+		"claude-sonnet-4",
+		3.,
+		15.,
+		Some(3.75),
+		Some(0.3),
+	),
+	(
+		AdapterKind::Anthropic,
+		"claude-3-7-sonnet",
+		3.,
+		15.,
+		Some(3.75),
+		Some(0.3),
+	),
+	(
+		AdapterKind::Anthropic,
+		"claude-haiku-4-5",
+		1.,
+		5.,
+		Some(1.25),
+		Some(0.1),
+	),
+	(
+		AdapterKind::Anthropic,
+		"claude-3-5-haiku",
+		0.8,
+		4.,
+		Some(1.),
+		Some(0.08),
+	),
+	// -- Gemini (prompts up to 200k tokens)
+	(AdapterKind::Gemini, "gemini-3-pro", 2., 12., None, Some(0.2)),
+	(AdapterKind::Gemini, "gemini-2.5-pro", 1.25, 10., None, Some(0.125)),
+	(AdapterKind::Gemini, "gemini-2.5-flash", 0.3, 2.5, None, Some(0.03)),
+	(AdapterKind::Gemini, "gemini-2.5-flash-lite", 0.1, 0.4, None, Some(0.01)),
+	(AdapterKind::Gemini, "gemini-2.0-flash", 0.1, 0.4, None, Some(0.025)),
+	// -- DeepSeek
+	(AdapterKind::DeepSeek, "deepseek-chat", 0.28, 0.42, None, Some(0.028)),
+	(
+		AdapterKind::DeepSeek,
+		"deepseek-reasoner",
+		0.28,
+		0.42,
+		None,
+		Some(0.028),
+	),
+	// -- xAI
+	(AdapterKind::Xai, "grok-4", 3., 15., None, Some(0.75)),
+	(AdapterKind::Xai, "grok-3-mini", 0.3, 0.5, None, Some(0.075)),
+];
+
+// endregion: --- Default Pricing
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::chat::{CompletionTokensDetails, PromptTokensDetails};
+
+	#[test]
+	fn test_pricing_cost_and_lookup() {
+		let usage = Usage {
+			prompt_tokens: Some(1_000_000),
+			prompt_tokens_details: Some(PromptTokensDetails {
+				cache_creation_tokens: Some(100_000),
+				cached_tokens: Some(400_000),
+				audio_tokens: None,
+			}),
+			completion_tokens: Some(200_000),
+			completion_tokens_details: Some(CompletionTokensDetails {
+				reasoning_tokens: Some(50_000),
+				..Default::default()
+			}),
+			total_tokens: Some(1_200_000),
+		};
+
+		// Longest prefix, dated name, and the 500k uncached + 100k write + 400k read tokens.
+		let sonnet = ModelIden::new(AdapterKind::Anthropic, "claude-sonnet-4-5-20250929");
+		let cost = usage.cost(&sonnet).unwrap();
+		assert!((cost - (1.5 + 0.375 + 0.12 + 3.)).abs() < 1e-9, "{cost}");
+
+		// Override, with the reasoning tokens priced separately.
+		let registry = PricingRegistry::default().with_model_pricing(
+			ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini"),
+			ModelPricing::new(1., 2.).with_reasoning(4.),
+		);
+		let mini = ModelIden::new(AdapterKind::OpenAIResp, "gpt-4o-mini-2024-07-18");
+		let cost = registry.cost(&mini, &usage).unwrap();
+		assert!((cost - (1. + 0.3 + 0.2)).abs() < 1e-9, "{cost}");
+		assert!(usage.cost(&ModelIden::new(AdapterKind::Ollama, "gemma3")).is_none());
+
+		// Tracker
+		let tracker = CostTracker::with_registry(registry);
+		tracker.record(&mini, &usage);
+		tracker.record(&mini, &usage);
+		tracker.record(&ModelIden::new(AdapterKind::Ollama, "gemma3"), &usage);
+		let spend = tracker.spend(&mini).unwrap();
+		assert_eq!(spend.requests, 2);
+		assert_eq!(spend.completion_tokens, 400_000);
+		assert!((tracker.total_cost() - 3.).abs() < 1e-9);
+		assert_eq!(tracker.spends().len(), 2);
+	}
+}
+
+// endregion: --- Tests
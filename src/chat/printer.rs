@@ -125,6 +125,21 @@ async fn print_chat_stream_inner(
 						}
 					}
 
+					ChatStreamEvent::Resumed(resumed) => {
+						if print_events {
+							(
+								Some(format!(
+									"\n-- ChatStreamEvent::Resumed (attempt {}): {}\n",
+									resumed.attempt, resumed.cause
+								)),
+								None,
+								false,
+							)
+						} else {
+							(None, None, false)
+						}
+					}
+
 					ChatStreamEvent::End(end_event) => {
 						if print_events {
 							// TODO: Might implement pretty JSON formatting
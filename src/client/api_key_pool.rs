@@ -0,0 +1,518 @@
+//! ApiKeyPool spreads the calls of an adapter over several API keys (set via `ClientConfig::with_api_key_pool`).
+//! - Keys are added per `AdapterKind`, from an `AuthData` (`MultiKeys` for named keys, `Key` or `FromEnv` for one).
+//! - Each request sent takes the next key, round-robin or least-recently-used (see `KeyRotation`),
+//!   so a retry (or chat stream resume) takes a new key.
+//! - A key is put on cooldown when a request with it is rate limited (429, exhausted quota) or unauthorized (401/403),
+//!   and skipped until the cooldown ends (unless all the keys of the adapter are cooling down).
+//! - `ApiKeyPool::usage` reports the requests and failures per key name (never the key values).
+//!
+//! Note: The pool takes precedence over the `AuthResolver` of the client for the adapters it has keys for,
+//!       and the others use the `AuthResolver` (or their default auth). Resolving a target without sending it
+//!       (e.g., `Client::preview_chat_request`, `Client::list_models`) shows the next key, but does not take it.
+
+use crate::adapter::AdapterKind;
+use crate::chat::ChatStream;
+use crate::resolver::AuthData;
+use crate::{Error, ErrorKind};
+use futures::StreamExt;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+const DEFAULT_COOLDOWN: Duration = Duration::from_secs(60);
+
+// region:    --- KeyRotation
+
+/// How the `ApiKeyPool` picks the key of the next call.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum KeyRotation {
+	/// Each key in turn (in the order they were added).
+	#[default]
+	RoundRobin,
+
+	/// The key used the longest time ago (or never used).
+	LeastRecentlyUsed,
+}
+
+// endregion: --- KeyRotation
+
+// region:    --- ApiKeyUsage
+
+/// The usage report of one key of an `ApiKeyPool`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiKeyUsage {
+	/// The key name (`MultiKeys` name, env name, or `key-{n}`).
+	pub name: String,
+
+	/// The requests sent with this key.
+	pub requests: u64,
+
+	/// The requests that were rate limited (429 or exhausted quota).
+	pub rate_limited: u64,
+
+	/// The requests that were unauthorized (401/403).
+	pub unauthorized: u64,
+
+	/// The time left before the key is used again, if cooling down.
+	pub cooldown_remaining: Option<Duration>,
+}
+
+// endregion: --- ApiKeyUsage
+
+// region:    --- ApiKeyPool
+
+/// Pool of API keys per adapter (see module documentation).
+///
+/// Clones share the same rotation and usage state.
+#[derive(Debug, Clone)]
+pub struct ApiKeyPool {
+	keys: HashMap<AdapterKind, Vec<PoolKey>>,
+	rotation: KeyRotation,
+	cooldown: Duration,
+	state: Arc<Mutex<HashMap<AdapterKind, AdapterKeysState>>>,
+}
+
+impl Default for ApiKeyPool {
+	fn default() -> Self {
+		Self {
+			keys: HashMap::new(),
+			rotation: KeyRotation::default(),
+			cooldown: DEFAULT_COOLDOWN,
+			state: Arc::default(),
+		}
+	}
+}
+
+/// Chainable Setters
+impl ApiKeyPool {
+	/// Create a new empty ApiKeyPool (round-robin, 60s cooldown).
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Add keys for an adapter:
+	/// - `AuthData::MultiKeys` adds each key value under its name (in name order),
+	/// - `AuthData::Key` adds one key named `key-{n}` (its position), and `AuthData::FromEnv` one key named after the env name.
+	///
+	/// A key with the name of an existing one replaces it. `AuthData::RequestOverride` is not a key and is ignored.
+	pub fn with_keys(mut self, adapter_kind: AdapterKind, auth_data: AuthData) -> Self {
+		let keys = self.keys.entry(adapter_kind).or_default();
+		let new_keys = match auth_data {
+			AuthData::MultiKeys(multi_keys) => {
+				let mut multi_keys: Vec<_> = multi_keys.into_iter().collect();
+				multi_keys.sort_by(|(a, _), (b, _)| a.cmp(b));
+				multi_keys
+					.into_iter()
+					.map(|(name, value)| PoolKey::new(name, AuthData::Key(value)))
+					.collect()
+			}
+			AuthData::Key(value) => vec![PoolKey::new(format!("key-{}", keys.len() + 1), AuthData::Key(value))],
+			AuthData::FromEnv(env_name) => vec![PoolKey::new(env_name.clone(), AuthData::FromEnv(env_name))],
+			AuthData::RequestOverride { .. } => {
+				tracing::warn!(%adapter_kind, "ApiKeyPool ignores AuthData::RequestOverride (not a key)");
+				Vec::new()
+			}
+		};
+
+		for new_key in new_keys {
+			match keys.iter_mut().find(|key| key.name == new_key.name) {
+				Some(key) => *key = new_key,
+				None => keys.push(new_key),
+			}
+		}
+		self
+	}
+
+	/// Set how the next key is picked (default `KeyRotation::RoundRobin`).
+	pub fn with_rotation(mut self, rotation: KeyRotation) -> Self {
+		self.rotation = rotation;
+		self
+	}
+
+	/// Set how long a rate limited or unauthorized key is skipped (default 60s).
+	pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+		self.cooldown = cooldown;
+		self
+	}
+}
+
+/// Getters
+impl ApiKeyPool {
+	/// Returns the key rotation.
+	pub fn rotation(&self) -> KeyRotation {
+		self.rotation
+	}
+
+	/// Returns the cooldown of rate limited or unauthorized keys.
+	pub fn cooldown(&self) -> Duration {
+		self.cooldown
+	}
+
+	/// Returns the usage of each key of the adapter (in the order they were added).
+	pub fn usage(&self, adapter_kind: AdapterKind) -> Vec<ApiKeyUsage> {
+		let Some(keys) = self.keys.get(&adapter_kind) else {
+			return Vec::new();
+		};
+		let state = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+		let now = Instant::now();
+
+		keys.iter()
+			.map(|key| {
+				let key_state = state
+					.get(&adapter_kind)
+					.and_then(|adapter_state| adapter_state.keys.get(&key.name))
+					.cloned()
+					.unwrap_or_default();
+				ApiKeyUsage {
+					name: key.name.clone(),
+					requests: key_state.requests,
+					rate_limited: key_state.rate_limited,
+					unauthorized: key_state.unauthorized,
+					cooldown_remaining: key_state
+						.cooldown_until
+						.map(|until| until.saturating_duration_since(now))
+						.filter(|remaining| !remaining.is_zero()),
+				}
+			})
+			.collect()
+	}
+
+	/// Returns true if the pool has keys for the adapter.
+	pub fn has_keys(&self, adapter_kind: AdapterKind) -> bool {
+		self.keys.get(&adapter_kind).is_some_and(|keys| !keys.is_empty())
+	}
+}
+
+/// Crate Methods
+impl ApiKeyPool {
+	/// Returns the key the next request of the adapter would take, without taking it (none if the adapter has no keys).
+	pub(crate) fn peek_key(&self, adapter_kind: AdapterKind) -> Option<AuthData> {
+		let keys = self.keys.get(&adapter_kind).filter(|keys| !keys.is_empty())?;
+		let state = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+		let no_state = AdapterKeysState::default();
+		let adapter_state = state.get(&adapter_kind).unwrap_or(&no_state);
+		let idx = self.pick_idx(keys, adapter_state, Instant::now())?;
+
+		Some(keys[idx].auth_data.clone())
+	}
+
+	/// Takes the next key of the adapter for a request (none if the adapter has no keys).
+	///
+	/// Note: The request is counted by `record_request`, once sent (e.g., not when short-circuited by a middleware).
+	pub(crate) fn next_key(&self, adapter_kind: AdapterKind) -> Option<AuthData> {
+		let keys = self.keys.get(&adapter_kind).filter(|keys| !keys.is_empty())?;
+		let mut state = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+		let adapter_state = state.entry(adapter_kind).or_default();
+		let now = Instant::now();
+		let idx = self.pick_idx(keys, adapter_state, now)?;
+
+		let key = &keys[idx];
+		adapter_state.cursor = idx + 1;
+		adapter_state.keys.entry(key.name.clone()).or_default().last_used = Some(now);
+
+		Some(key.auth_data.clone())
+	}
+
+	/// Returns true if the auth is one of the keys of the adapter.
+	pub(crate) fn is_pool_key(&self, adapter_kind: AdapterKind, auth_data: &AuthData) -> bool {
+		self.find_key(adapter_kind, auth_data).is_some()
+	}
+
+	/// Counts a request sent with this auth. Auth data not from this pool is ignored.
+	pub(crate) fn record_request(&self, adapter_kind: AdapterKind, auth_data: &AuthData) {
+		let Some(key) = self.find_key(adapter_kind, auth_data) else {
+			return;
+		};
+		let mut state = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+		let key_state = state.entry(adapter_kind).or_default().keys.entry(key.name.clone()).or_default();
+		key_state.requests += 1;
+	}
+
+	/// Records the failure of a request made with this auth, and puts the key on cooldown when
+	/// rate limited or unauthorized. Auth data not from this pool is ignored.
+	pub(crate) fn report_failure(&self, adapter_kind: AdapterKind, auth_data: &AuthData, err: &Error) {
+		let Some(key) = self.find_key(adapter_kind, auth_data) else {
+			return;
+		};
+
+		let kind = err.kind();
+		let mut state = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+		let key_state = state.entry(adapter_kind).or_default().keys.entry(key.name.clone()).or_default();
+		match kind {
+			ErrorKind::RateLimited | ErrorKind::QuotaExhausted => key_state.rate_limited += 1,
+			ErrorKind::InvalidAuth => key_state.unauthorized += 1,
+			_ => return,
+		}
+		key_state.cooldown_until = Some(Instant::now() + self.cooldown);
+		tracing::debug!(%adapter_kind, key = %key.name, ?kind, "api key put on cooldown");
+	}
+}
+
+/// Private Support
+impl ApiKeyPool {
+	/// The index of the next key per the rotation, skipping the keys cooling down (unless all of them are).
+	fn pick_idx(&self, keys: &[PoolKey], adapter_state: &AdapterKeysState, now: Instant) -> Option<usize> {
+		let is_available = |key: &PoolKey| {
+			adapter_state
+				.keys
+				.get(&key.name)
+				.and_then(|key_state| key_state.cooldown_until)
+				.is_none_or(|until| until <= now)
+		};
+		let last_used = |key: &PoolKey| adapter_state.keys.get(&key.name).and_then(|key_state| key_state.last_used);
+
+		let available = (0..keys.len()).filter(|idx| is_available(&keys[*idx]));
+		match self.rotation {
+			KeyRotation::RoundRobin => {
+				let cursor = adapter_state.cursor;
+				available.min_by_key(|idx| (idx + keys.len() - cursor % keys.len()) % keys.len())
+			}
+			KeyRotation::LeastRecentlyUsed => available.min_by_key(|idx| last_used(&keys[*idx])),
+		}
+		// All cooling down, so the one available the soonest.
+		.or_else(|| {
+			(0..keys.len()).min_by_key(|idx| {
+				adapter_state
+					.keys
+					.get(&keys[*idx].name)
+					.and_then(|key_state| key_state.cooldown_until)
+			})
+		})
+	}
+
+	fn find_key(&self, adapter_kind: AdapterKind, auth_data: &AuthData) -> Option<&PoolKey> {
+		self.keys
+			.get(&adapter_kind)
+			.and_then(|keys| keys.iter().find(|key| is_same_key(&key.auth_data, auth_data)))
+	}
+}
+
+// endregion: --- ApiKeyPool
+
+// region:    --- Stream
+
+/// Reports the failure of a chat stream to the pool, when its first event is an error
+/// (e.g., a 429 status, which stream requests only get once polled).
+pub(crate) fn api_key_chat_stream(
+	stream: ChatStream,
+	api_key_pool: ApiKeyPool,
+	adapter_kind: AdapterKind,
+	auth_data: AuthData,
+) -> ChatStream {
+	let inter_stream = futures::stream::unfold((stream, Some(auth_data)), move |(stream, mut auth_data)| {
+		let api_key_pool = api_key_pool.clone();
+		async move {
+			let mut inter_stream = stream.into_inter_stream();
+			let event = inter_stream.next().await?;
+
+			if let Some(auth_data) = auth_data.take()
+				&& let Err(err) = &event
+			{
+				api_key_pool.report_failure(adapter_kind, &auth_data, err);
+			}
+
+			Some((event, (ChatStream::new(inter_stream), auth_data)))
+		}
+	});
+
+	ChatStream::from_inter_stream(inter_stream)
+}
+
+// endregion: --- Stream
+
+// region:    --- Support
+
+#[derive(Debug, Clone)]
+struct PoolKey {
+	name: String,
+	auth_data: AuthData,
+}
+
+impl PoolKey {
+	fn new(name: impl Into<String>, auth_data: AuthData) -> Self {
+		Self {
+			name: name.into(),
+			auth_data,
+		}
+	}
+}
+
+#[derive(Debug, Default)]
+struct AdapterKeysState {
+	/// The index of the next key for the round-robin.
+	cursor: usize,
+	keys: HashMap<String, KeyState>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct KeyState {
+	requests: u64,
+	rate_limited: u64,
+	unauthorized: u64,
+	last_used: Option<Instant>,
+	cooldown_until: Option<Instant>,
+}
+
+fn is_same_key(a: &AuthData, b: &AuthData) -> bool {
+	match (a, b) {
+		(AuthData::Key(a), AuthData::Key(b)) => a == b,
+		(AuthData::FromEnv(a), AuthData::FromEnv(b)) => a == b,
+		_ => false,
+	}
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::resolver::AuthResolver;
+	use crate::{ClientConfig, ModelIden, webc};
+	use reqwest::StatusCode;
+	use reqwest::header::HeaderMap;
+
+	fn key_value(auth_data: Option<AuthData>) -> Result<String> {
+		match auth_data {
+			Some(AuthData::Key(value)) => Ok(value),
+			other => Err(format!("unexpected auth data: {other:?}").into()),
+		}
+	}
+
+	/// Takes the next key, and counts it as sent.
+	fn send(pool: &ApiKeyPool, adapter_kind: AdapterKind) -> Option<AuthData> {
+		let auth_data = pool.next_key(adapter_kind)?;
+		pool.record_request(adapter_kind, &auth_data);
+		Some(auth_data)
+	}
+
+	fn status_error(status: u16) -> Result<Error> {
+		Ok(Error::WebModelCall {
+			model_iden: ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini"),
+			webc_error: webc::Error::ResponseFailedStatus {
+				status: StatusCode::from_u16(status)?,
+				body: String::new(),
+				headers: Box::new(HeaderMap::new()),
+			},
+		})
+	}
+
+	#[test]
+	fn test_api_key_pool_rotation_and_cooldown() -> Result<()> {
+		// -- Setup & Fixtures
+		let keys = HashMap::from([("a".to_string(), "sk-a".to_string()), ("b".to_string(), "sk-b".to_string())]);
+		let pool = ApiKeyPool::new()
+			.with_keys(AdapterKind::OpenAI, AuthData::from_multi(keys))
+			.with_keys(AdapterKind::OpenAI, AuthData::from_single("sk-c"));
+
+		// -- Exec & Check (round-robin)
+		let picked: Vec<String> = (0..4)
+			.map(|_| key_value(send(&pool, AdapterKind::OpenAI)))
+			.collect::<Result<_>>()?;
+		assert_eq!(picked, ["sk-a", "sk-b", "sk-c", "sk-a"]);
+		assert!(pool.next_key(AdapterKind::Anthropic).is_none());
+
+		// -- Exec & Check (cooldown)
+		pool.report_failure(AdapterKind::OpenAI, &AuthData::from_single("sk-b"), &status_error(429)?);
+		pool.report_failure(AdapterKind::OpenAI, &AuthData::from_single("sk-c"), &status_error(401)?);
+		pool.report_failure(AdapterKind::OpenAI, &AuthData::from_single("sk-a"), &status_error(400)?);
+		assert_eq!(key_value(send(&pool, AdapterKind::OpenAI))?, "sk-a");
+		assert_eq!(key_value(send(&pool, AdapterKind::OpenAI))?, "sk-a");
+
+		let usage = pool.usage(AdapterKind::OpenAI);
+		assert_eq!(usage.len(), 3);
+		assert_eq!((usage[0].name.as_str(), usage[0].requests), ("a", 4));
+		assert_eq!((usage[1].name.as_str(), usage[1].rate_limited), ("b", 1));
+		assert_eq!((usage[2].name.as_str(), usage[2].unauthorized), ("key-3", 1));
+		assert!(usage[0].cooldown_remaining.is_none());
+		assert!(usage[1].cooldown_remaining.is_some());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_api_key_pool_least_recently_used() -> Result<()> {
+		// -- Setup & Fixtures
+		let pool = ApiKeyPool::new()
+			.with_rotation(KeyRotation::LeastRecentlyUsed)
+			.with_cooldown(Duration::ZERO)
+			.with_keys(AdapterKind::Groq, AuthData::from_single("gsk-1"))
+			.with_keys(AdapterKind::Groq, AuthData::from_single("gsk-2"));
+
+		// -- Exec
+		let first = key_value(pool.next_key(AdapterKind::Groq))?;
+		let second = key_value(pool.next_key(AdapterKind::Groq))?;
+		pool.report_failure(AdapterKind::Groq, &AuthData::from_single("gsk-1"), &status_error(429)?);
+		let third = key_value(pool.next_key(AdapterKind::Groq))?;
+
+		// -- Check (a zero cooldown does not skip the key)
+		assert_eq!([first, second, third], ["gsk-1", "gsk-2", "gsk-1"]);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_api_key_pool_peek_does_not_take() -> Result<()> {
+		// -- Setup & Fixtures
+		let pool = ApiKeyPool::new()
+			.with_keys(AdapterKind::OpenAI, AuthData::from_single("sk-1"))
+			.with_keys(AdapterKind::OpenAI, AuthData::from_single("sk-2"));
+
+		// -- Exec
+		let peeked = [pool.peek_key(AdapterKind::OpenAI), pool.peek_key(AdapterKind::OpenAI)];
+		let taken = pool.next_key(AdapterKind::OpenAI);
+		let usage_before_send = pool.usage(AdapterKind::OpenAI);
+		let taken_auth = taken.clone().ok_or("Should have a key")?;
+		pool.record_request(AdapterKind::OpenAI, &taken_auth);
+
+		// -- Check
+		assert_eq!(key_value(peeked[0].clone())?, "sk-1");
+		assert_eq!(key_value(peeked[1].clone())?, "sk-1");
+		assert_eq!(key_value(taken)?, "sk-1");
+		assert_eq!(usage_before_send[0].requests, 0);
+		assert_eq!(pool.usage(AdapterKind::OpenAI)[0].requests, 1);
+		assert_eq!(key_value(pool.peek_key(AdapterKind::OpenAI))?, "sk-2");
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn test_api_key_pool_with_auth_resolver() -> Result<()> {
+		// -- Setup & Fixtures
+		let pool = ApiKeyPool::new().with_keys(AdapterKind::OpenAI, AuthData::from_single("sk-pool"));
+		let auth_resolver =
+			AuthResolver::from_resolver_fn(|_model_iden: ModelIden| Ok(Some(AuthData::from_single("sk-resolver"))));
+		// Set in both orders, the pool is used for its adapters, and the resolver for the others.
+		let configs = [
+			ClientConfig::default()
+				.with_api_key_pool(pool.clone())
+				.with_auth_resolver(auth_resolver.clone()),
+			ClientConfig::default()
+				.with_auth_resolver(auth_resolver)
+				.with_api_key_pool(pool.clone()),
+		];
+
+		for config in configs {
+			// -- Exec
+			let openai = config
+				.resolve_service_target(ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini"))
+				.await?;
+			let anthropic = config
+				.resolve_service_target(ModelIden::new(AdapterKind::Anthropic, "claude-sonnet-4-5"))
+				.await?;
+
+			// -- Check
+			assert_eq!(key_value(Some(openai.auth))?, "sk-pool");
+			assert_eq!(key_value(Some(anthropic.auth))?, "sk-resolver");
+		}
+		// Resolving does not take nor count the keys.
+		assert_eq!(pool.usage(AdapterKind::OpenAI)[0].requests, 0);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,517 @@
+//! AuditSink lets the `Client` keep a record of every request sent to a provider, and of its response.
+//! - Set with `client_config.with_audit_sink(..)` (or `ClientBuilder::with_audit_sink(..)`).
+//! - One `AuditRecord` per provider call: the final JSON payload (after the middlewares), the response body
+//!   (for a chat stream, the reassembled content), the `ModelIden`, the duration, and the usage.
+//!   Each retry (and chat stream resume) is a new provider call, with its own record.
+//! - The calls served by the `ResponseCache` or short-circuited by a middleware are not recorded (nothing was sent).
+//!
+//! `JsonlAuditSink` appends the records to a JSONL file, redacted per its `AuditRedaction`
+//! (secrets, base64 binary payloads, and regex-matched PII).
+
+use crate::adapter::inter_stream::InterStreamEvent;
+use crate::chat::{ChatStream, ChatTimings, ToolCall, Usage};
+use crate::client::{Headers, RequestKind};
+use crate::{Error, ModelIden, Result};
+use futures::StreamExt;
+use regex::Regex;
+use serde::Serialize;
+use serde_json::{Value, json};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::time::Instant;
+
+// region:    --- AuditSink
+
+/// A sink for the audit records of the provider calls.
+///
+/// Note: Called inline by the client, so an implementation doing slow I/O should hand the record off (e.g., to a channel).
+pub trait AuditSink: Send + Sync {
+	/// Called once per provider call, when its response is parsed, its stream ends, or it fails.
+	fn record(&self, record: &AuditRecord);
+}
+
+impl std::fmt::Debug for dyn AuditSink {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "AuditSink")
+	}
+}
+
+// endregion: --- AuditSink
+
+// region:    --- AuditRecord
+
+/// The record of one provider call (not redacted, see `AuditRedaction`).
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditRecord {
+	/// Unix time, in milliseconds, when the request was sent.
+	pub timestamp_ms: u64,
+
+	pub request_kind: RequestKind,
+
+	/// The resolved model identifier.
+	pub model_iden: ModelIden,
+
+	pub url: String,
+
+	/// The JSON payload sent to the provider.
+	/// For a resumed chat stream, the one of the resume request (with the prefill).
+	pub request: Value,
+
+	/// The response body, or for a chat stream, the reassembled `{content, reasoning_content, tool_calls}`.
+	/// `None` when the call failed before a response (the stream content received until the failure otherwise).
+	pub response: Option<Value>,
+
+	/// The error message, when the call failed.
+	pub error: Option<String>,
+
+	/// From the request to the parsed response, the end of the stream, or the failure.
+	pub duration: Duration,
+
+	/// The chat stream timings (see `ChatTimings`).
+	pub timings: Option<ChatTimings>,
+
+	/// The token usage (for a chat stream, only when captured with `ChatOptions.capture_usage`).
+	pub usage: Option<Usage>,
+}
+
+/// An `AuditRecord` in progress, created when the request is sent, completed with the response.
+pub(crate) struct PendingAudit {
+	record: AuditRecord,
+	started_at: Instant,
+}
+
+impl PendingAudit {
+	pub(crate) fn new(request_kind: RequestKind, model_iden: ModelIden, url: &str, request: &Value) -> Self {
+		let timestamp_ms = SystemTime::now()
+			.duration_since(UNIX_EPOCH)
+			.map(|since_epoch| since_epoch.as_millis() as u64)
+			.unwrap_or_default();
+
+		PendingAudit {
+			record: AuditRecord {
+				timestamp_ms,
+				request_kind,
+				model_iden,
+				url: url.to_string(),
+				request: request.clone(),
+				response: None,
+				error: None,
+				duration: Duration::ZERO,
+				timings: None,
+				usage: None,
+			},
+			started_at: Instant::now(),
+		}
+	}
+
+	pub(crate) fn set_response(&mut self, response: &Value) {
+		self.record.response = Some(response.clone());
+	}
+
+	/// Complete the record with the outcome of the call, and send it to the sink.
+	pub(crate) fn finish(mut self, sink: &dyn AuditSink, usage: Option<Usage>, error: Option<&Error>) {
+		self.record.duration = self.started_at.elapsed();
+		self.record.usage = usage;
+		self.record.error = error.map(|err| err.to_string());
+		sink.record(&self.record);
+	}
+}
+
+// endregion: --- AuditRecord
+
+// region:    --- AuditRedaction
+
+const REDACTED: &str = "[REDACTED]";
+const PII_REDACTED: &str = "[PII]";
+
+/// The JSON object keys (and URL query parameters) redacted by default.
+const DEFAULT_SECRET_KEYS: &[&str] = &[
+	"api_key",
+	"apikey",
+	"api-key",
+	"x-api-key",
+	"x-goog-api-key",
+	"authorization",
+	"x-amz-security-token",
+	"access_token",
+	"password",
+	"secret",
+];
+
+/// A string is taken as a base64 payload from this length (when it only has base64 characters).
+const MIN_BINARY_LEN: usize = 128;
+
+/// What `JsonlAuditSink` redacts before writing a record.
+///
+/// Default: the secret properties and URL query parameters (e.g., `api_key`, `authorization`, the `key` parameter)
+/// and the base64 binary payloads.
+#[derive(Debug, Clone)]
+pub struct AuditRedaction {
+	secrets: Vec<String>,
+	secret_keys: Vec<String>,
+	binaries: bool,
+	pii_patterns: Vec<Regex>,
+}
+
+impl Default for AuditRedaction {
+	fn default() -> Self {
+		Self {
+			secrets: Vec::new(),
+			secret_keys: DEFAULT_SECRET_KEYS.iter().map(|key| key.to_string()).collect(),
+			binaries: true,
+			pii_patterns: Vec::new(),
+		}
+	}
+}
+
+/// Constructors
+impl AuditRedaction {
+	/// No redaction at all.
+	pub fn none() -> Self {
+		Self {
+			secrets: Vec::new(),
+			secret_keys: Vec::new(),
+			binaries: false,
+			pii_patterns: Vec::new(),
+		}
+	}
+}
+
+/// Chainable Setters
+impl AuditRedaction {
+	/// Redact this secret value wherever it appears (e.g., an API key set in a custom header or the URL).
+	pub fn with_secret(mut self, secret: impl Into<String>) -> Self {
+		let secret = secret.into();
+		if !secret.is_empty() {
+			self.secrets.push(secret);
+		}
+		self
+	}
+
+	/// Redact the value of the JSON object properties (and URL query parameters) of this name (case insensitive).
+	pub fn with_secret_key(mut self, key: impl Into<String>) -> Self {
+		self.secret_keys.push(key.into().to_lowercase());
+		self
+	}
+
+	/// Replace the base64 binary payloads (data URLs and long base64 strings) by a `[BINARY n chars]` placeholder.
+	pub fn with_binaries(mut self, binaries: bool) -> Self {
+		self.binaries = binaries;
+		self
+	}
+
+	/// Replace the matches of this regex (e.g., `[\w.+-]+@[\w-]+\.[\w.]+` for the emails) by `[PII]`.
+	pub fn with_pii_pattern(mut self, pattern: &str) -> Result<Self> {
+		let regex = Regex::new(pattern).map_err(|err| Error::AuditRedactionPattern {
+			pattern: pattern.to_string(),
+			cause: err.to_string(),
+		})?;
+		self.pii_patterns.push(regex);
+		Ok(self)
+	}
+}
+
+/// Redaction
+impl AuditRedaction {
+	/// Redact the record, as a JSON value.
+	pub fn redact_record(&self, record: &AuditRecord) -> Value {
+		let mut value = serde_json::to_value(record).unwrap_or_default();
+		if let Some(Value::String(url)) = value.get_mut("url") {
+			*url = self.redact_url(url);
+		}
+		for name in ["request", "response", "error"] {
+			if let Some(value) = value.get_mut(name) {
+				self.redact_value(value);
+			}
+		}
+		value
+	}
+
+	/// Redact a JSON value in place.
+	pub fn redact_value(&self, value: &mut Value) {
+		match value {
+			Value::Object(map) => {
+				for (key, value) in map.iter_mut() {
+					if self.is_secret_key(key) && !value.is_null() {
+						*value = Value::String(REDACTED.to_string());
+					} else {
+						self.redact_value(value);
+					}
+				}
+			}
+			Value::Array(items) => items.iter_mut().for_each(|item| self.redact_value(item)),
+			Value::String(text) => {
+				if self.binaries && is_binary(text) {
+					*text = format!("[BINARY {} chars]", text.len());
+				} else {
+					*text = self.redact_text(text);
+				}
+			}
+			_ => (),
+		}
+	}
+
+	/// Redact the HTTP headers in place (the secret names, and the secret values wherever they appear).
+	pub(crate) fn redact_headers(&self, headers: &mut Headers) {
+		for (name, value) in headers.iter_mut() {
+			if self.is_secret_key(name) {
+				*value = REDACTED.to_string();
+			} else {
+				*value = self.redact_text(value);
+			}
+		}
+	}
+
+	fn redact_text(&self, text: &str) -> String {
+		let mut text = text.to_string();
+		for secret in &self.secrets {
+			text = text.replace(secret, REDACTED);
+		}
+		for pattern in &self.pii_patterns {
+			text = pattern.replace_all(&text, PII_REDACTED).into_owned();
+		}
+		text
+	}
+
+	pub(crate) fn redact_url(&self, url: &str) -> String {
+		let url = self.redact_text(url);
+		let Some((base, query)) = url.split_once('?') else {
+			return url;
+		};
+		let query = query
+			.split('&')
+			.map(|param| match param.split_once('=') {
+				// `key` is the Google APIs key parameter.
+				Some((name, _)) if name == "key" || self.is_secret_key(name) => format!("{name}={REDACTED}"),
+				_ => param.to_string(),
+			})
+			.collect::<Vec<_>>()
+			.join("&");
+		format!("{base}?{query}")
+	}
+
+	fn is_secret_key(&self, key: &str) -> bool {
+		self.secret_keys.iter().any(|secret_key| secret_key.eq_ignore_ascii_case(key))
+	}
+}
+
+/// A data URL, or a long string of base64 characters only.
+fn is_binary(text: &str) -> bool {
+	if text.starts_with("data:") && text.contains(";base64,") {
+		return true;
+	}
+	text.len() >= MIN_BINARY_LEN
+		&& text
+			.bytes()
+			.all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'+' | b'/' | b'=' | b'-' | b'_'))
+}
+
+// endregion: --- AuditRedaction
+
+// region:    --- JsonlAuditSink
+
+/// Appends the redacted audit records to a JSONL file (one JSON record per line).
+#[derive(Debug)]
+pub struct JsonlAuditSink {
+	path: PathBuf,
+	redaction: AuditRedaction,
+	// Serializes the writes, so the lines of concurrent calls do not interleave.
+	write_lock: Mutex<()>,
+}
+
+impl JsonlAuditSink {
+	/// Create a sink appending to `path` (the file and its parent directories are created on first record if missing).
+	pub fn new(path: impl Into<PathBuf>) -> Self {
+		Self {
+			path: path.into(),
+			redaction: AuditRedaction::default(),
+			write_lock: Mutex::new(()),
+		}
+	}
+
+	/// Set the redaction applied before writing (default: `AuditRedaction::default()`).
+	pub fn with_redaction(mut self, redaction: AuditRedaction) -> Self {
+		self.redaction = redaction;
+		self
+	}
+
+	/// The JSONL file path.
+	pub fn path(&self) -> &Path {
+		&self.path
+	}
+}
+
+impl AuditSink for JsonlAuditSink {
+	fn record(&self, record: &AuditRecord) {
+		let value = self.redaction.redact_record(record);
+
+		let _guard = self.write_lock.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+		let res = (|| -> std::io::Result<()> {
+			if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+				std::fs::create_dir_all(dir)?;
+			}
+			let mut file = std::fs::OpenOptions::new().create(true).append(true).open(&self.path)?;
+			writeln!(file, "{value}")
+		})();
+
+		if let Err(err) = res {
+			tracing::warn!(path = %self.path.display(), %err, "failed to write audit record");
+		}
+	}
+}
+
+// endregion: --- JsonlAuditSink
+
+// region:    --- Stream
+
+#[derive(Default)]
+struct StreamContent {
+	content: String,
+	reasoning_content: String,
+	tool_calls: Vec<ToolCall>,
+}
+
+impl StreamContent {
+	fn to_response(&self) -> Value {
+		json!({
+			"content": self.content,
+			"reasoning_content": (!self.reasoning_content.is_empty()).then_some(&self.reasoning_content),
+			"tool_calls": self.tool_calls,
+		})
+	}
+}
+
+/// The audit of the chat stream attempt that reached its end, finished by `audit_chat_stream_end`
+/// (which sees the end with the stream timings).
+pub(crate) type StreamAuditSlot = Arc<Mutex<Option<PendingAudit>>>;
+
+/// Reassemble the content of a chat stream attempt (one provider call), and record it with its error,
+/// or leave it in the `end_slot` at its end.
+pub(crate) fn audit_chat_stream(
+	stream: ChatStream,
+	audit: PendingAudit,
+	sink: Arc<dyn AuditSink>,
+	end_slot: StreamAuditSlot,
+) -> ChatStream {
+	let state = (Some(audit), StreamContent::default());
+
+	let inter_stream = futures::stream::unfold((stream, state), move |(stream, (mut audit, mut content))| {
+		let sink = sink.clone();
+		let end_slot = end_slot.clone();
+		async move {
+			let mut inter_stream = stream.into_inter_stream();
+			let event = inter_stream.next().await?;
+
+			match &event {
+				Ok(InterStreamEvent::Chunk(chunk)) => content.content.push_str(chunk),
+				Ok(InterStreamEvent::ReasoningChunk(chunk)) => content.reasoning_content.push_str(chunk),
+				Ok(InterStreamEvent::ToolCallChunk(tool_call)) => content.tool_calls.push(tool_call.clone()),
+				Ok(InterStreamEvent::End(_)) => {
+					if let Some(mut audit) = audit.take() {
+						audit.set_response(&content.to_response());
+						*end_slot.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(audit);
+					}
+				}
+				Err(err) => {
+					if let Some(mut audit) = audit.take() {
+						audit.set_response(&content.to_response());
+						audit.finish(sink.as_ref(), None, Some(err));
+					}
+				}
+				_ => (),
+			}
+
+			Some((event, (ChatStream::new(inter_stream), (audit, content))))
+		}
+	});
+
+	ChatStream::from_inter_stream(inter_stream)
+}
+
+/// Record the audit of the attempt that reached the stream end (see `audit_chat_stream`), with the end timings and usage.
+pub(crate) fn audit_chat_stream_end(
+	stream: ChatStream,
+	end_slot: StreamAuditSlot,
+	sink: Arc<dyn AuditSink>,
+) -> ChatStream {
+	let inter_stream = futures::stream::unfold(stream, move |stream| {
+		let sink = sink.clone();
+		let end_slot = end_slot.clone();
+		async move {
+			let mut inter_stream = stream.into_inter_stream();
+			let event = inter_stream.next().await?;
+
+			if let Ok(InterStreamEvent::End(end)) = &event {
+				let audit = end_slot.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).take();
+				if let Some(mut audit) = audit {
+					audit.record.timings = end.timings.clone();
+					audit.finish(sink.as_ref(), end.captured_usage.clone(), None);
+				}
+			}
+
+			Some((event, ChatStream::new(inter_stream)))
+		}
+	});
+
+	ChatStream::from_inter_stream(inter_stream)
+}
+
+// endregion: --- Stream
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::adapter::AdapterKind;
+
+	#[test]
+	fn test_audit_redaction_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let redaction = AuditRedaction::default()
+			.with_secret("sk-live-123")
+			.with_pii_pattern(r"[\w.+-]+@[\w-]+\.[\w.]+")?;
+		let image = "A".repeat(200);
+		let mut audit = PendingAudit::new(
+			RequestKind::Chat,
+			ModelIden::new(AdapterKind::Gemini, "gemini-2.5-flash"),
+			"https://example.com/v1/models?key=abc&alt=sse",
+			&json!({
+				"contents": [{"parts": [
+					{"text": "Mail john.doe@example.com, token sk-live-123"},
+					{"inline_data": {"mime_type": "image/png", "data": image}},
+					{"image_url": {"url": "data:image/png;base64,iVBORw0KGgo="}}
+				]}],
+				"api_key": "abc"
+			}),
+		);
+		audit.set_response(&json!({"text": "Sent to john.doe@example.com"}));
+
+		// -- Exec
+		let value = redaction.redact_record(&audit.record);
+
+		// -- Check
+		assert_eq!(
+			value["url"].as_str(),
+			Some("https://example.com/v1/models?key=[REDACTED]&alt=sse")
+		);
+		let parts = &value["request"]["contents"][0]["parts"];
+		assert_eq!(parts[0]["text"].as_str(), Some("Mail [PII], token [REDACTED]"));
+		assert_eq!(parts[1]["inline_data"]["data"].as_str(), Some("[BINARY 200 chars]"));
+		assert!(
+			parts[2]["image_url"]["url"]
+				.as_str()
+				.is_some_and(|url| url.starts_with("[BINARY"))
+		);
+		assert_eq!(value["request"]["api_key"].as_str(), Some("[REDACTED]"));
+		assert_eq!(value["response"]["text"].as_str(), Some("Sent to [PII]"));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -1,10 +1,15 @@
-use crate::chat::ChatOptions;
+#[cfg(feature = "mock")]
+use crate::adapter::Mock;
+use crate::chat::{CapabilityRegistry, ChatOptions};
 use crate::resolver::{
 	AuthResolver, IntoAuthResolverFn, IntoModelMapperFn, IntoServiceTargetResolverFn, ModelMapper,
 	ServiceTargetResolver,
 };
-use crate::webc::WebClient;
-use crate::{Client, ClientConfig, WebConfig};
+use crate::webc::{Cassette, WebClient};
+use crate::{
+	ApiKeyPool, AuditSink, Client, ClientConfig, MetricsHook, Middleware, RateLimiter, ResponseCache, RetryPolicy,
+	WebConfig,
+};
 use std::sync::Arc;
 
 /// Builder for `Client`.
@@ -15,6 +20,9 @@ use std::sync::Arc;
 #[derive(Debug, Default)]
 pub struct ClientBuilder {
 	web_client: Option<WebClient>,
+	cassette: Option<Cassette>,
+	#[cfg(feature = "mock")]
+	mock: Option<Mock>,
 	config: Option<ClientConfig>,
 }
 
@@ -26,6 +34,19 @@ impl ClientBuilder {
 		self
 	}
 
+	/// Record or replay the web calls with a `Cassette` (e.g., to run provider tests offline).
+	pub fn with_cassette(mut self, cassette: Cassette) -> Self {
+		self.cassette = Some(cassette);
+		self
+	}
+
+	/// Serve the `mock::` namespaced models (e.g., `mock::my-model`) with the given `Mock` script.
+	#[cfg(feature = "mock")]
+	pub fn with_mock(mut self, mock: Mock) -> Self {
+		self.mock = Some(mock);
+		self
+	}
+
 	/// Set a `ClientConfig`.
 	pub fn with_config(mut self, config: ClientConfig) -> Self {
 		self.config = Some(config);
@@ -50,6 +71,71 @@ impl ClientBuilder {
 		self
 	}
 
+	/// Set `RetryPolicy` on `ClientConfig` (creates it if absent).
+	pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		let client_config = self.config.get_or_insert_with(ClientConfig::default);
+		client_config.retry_policy = Some(retry_policy);
+		self
+	}
+
+	/// Set `RateLimiter` on `ClientConfig` (creates it if absent).
+	pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+		let client_config = self.config.get_or_insert_with(ClientConfig::default);
+		client_config.rate_limiter = Some(rate_limiter);
+		self
+	}
+
+	/// Set `ResponseCache` on `ClientConfig` (creates it if absent).
+	pub fn with_response_cache(mut self, response_cache: impl ResponseCache + 'static) -> Self {
+		let client_config = self.config.get_or_insert_with(ClientConfig::default);
+		client_config.response_cache = Some(Arc::new(response_cache));
+		self
+	}
+
+	/// Set `trace_content` on `ClientConfig` (creates it if absent).
+	pub fn with_trace_content(mut self, trace_content: bool) -> Self {
+		let client_config = self.config.get_or_insert_with(ClientConfig::default);
+		client_config.trace_content = trace_content;
+		self
+	}
+
+	/// Set `MetricsHook` on `ClientConfig` (creates it if absent).
+	pub fn with_metrics_hook(mut self, metrics_hook: impl MetricsHook + 'static) -> Self {
+		let client_config = self.config.get_or_insert_with(ClientConfig::default);
+		client_config.metrics_hook = Some(Arc::new(metrics_hook));
+		self
+	}
+
+	/// Set `AuditSink` on `ClientConfig` (creates it if absent).
+	pub fn with_audit_sink(mut self, audit_sink: impl AuditSink + 'static) -> Self {
+		let client_config = self.config.get_or_insert_with(ClientConfig::default);
+		client_config.audit_sink = Some(Arc::new(audit_sink));
+		self
+	}
+
+	/// Set `ApiKeyPool` on `ClientConfig` (creates it if absent), used before the `AuthResolver` for the adapters it has keys for.
+	pub fn with_api_key_pool(mut self, api_key_pool: ApiKeyPool) -> Self {
+		let client_config = self.config.get_or_insert_with(ClientConfig::default);
+		client_config.api_key_pool = Some(api_key_pool);
+		self
+	}
+
+	/// Set `CapabilityRegistry` on `ClientConfig` (creates it if absent).
+	pub fn with_capability_registry(mut self, capability_registry: CapabilityRegistry) -> Self {
+		let client_config = self.config.get_or_insert_with(ClientConfig::default);
+		client_config.capability_registry = Some(capability_registry);
+		self
+	}
+
+	/// Append a `Middleware` to the `ClientConfig` middleware chain (creates it if absent).
+	///
+	/// Middlewares are called in the order they are added (see [`Middleware`]).
+	pub fn with_middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+		let client_config = self.config.get_or_insert_with(ClientConfig::default);
+		client_config.middlewares.push(Arc::new(middleware));
+		self
+	}
+
 	/// Set `AuthResolver` on `ClientConfig` (creates it if absent).
 	pub fn with_auth_resolver(mut self, auth_resolver: AuthResolver) -> Self {
 		let client_config = self.config.get_or_insert_with(ClientConfig::default);
@@ -115,8 +201,21 @@ impl ClientBuilder {
 			// Use default WebClient
 			WebClient::default()
 		};
+		let web_client = match self.cassette {
+			Some(cassette) => web_client.with_cassette(cassette),
+			None => web_client,
+		};
+		#[cfg(feature = "mock")]
+		let web_client = match self.mock {
+			Some(mock) => web_client.with_mock(mock),
+			None => web_client,
+		};
 
-		let inner = super::ClientInner { web_client, config };
+		let inner = super::ClientInner {
+			web_client,
+			config,
+			live_capabilities: Default::default(),
+		};
 		Client { inner: Arc::new(inner) }
 	}
 }
@@ -0,0 +1,203 @@
+//! Per-request deadlines and cancellation for the chat calls.
+//! - `ChatOptions::with_timeout(..)` bounds `exec_chat` (retries and fallbacks included) and the start of `exec_chat_stream`.
+//! - `ChatOptions::with_stream_idle_timeout(..)` bounds the wait between two stream events.
+//! - `ChatOptions::with_cancellation_token(..)` aborts the in-flight call (or ends the stream) when cancelled.
+//!
+//! Note: Dropping a `ChatStream` also ends the underlying request.
+
+use crate::chat::ChatStream;
+use crate::{Error, ModelIden, Result};
+use futures::StreamExt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::sync::Notify;
+use tokio::time::Instant;
+
+// region:    --- CancellationToken
+
+/// A handle to cancel in-flight requests.
+///
+/// Clones share the same state, so a clone can be kept to call `cancel()` while the request runs.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+	inner: Arc<CancellationInner>,
+}
+
+#[derive(Debug, Default)]
+struct CancellationInner {
+	cancelled: AtomicBool,
+	notify: Notify,
+}
+
+impl CancellationToken {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Cancel the requests using this token (and the ones started with it afterward).
+	pub fn cancel(&self) {
+		if !self.inner.cancelled.swap(true, Ordering::SeqCst) {
+			self.inner.notify.notify_waiters();
+		}
+	}
+
+	pub fn is_cancelled(&self) -> bool {
+		self.inner.cancelled.load(Ordering::SeqCst)
+	}
+
+	/// Completes when the token is cancelled.
+	pub async fn cancelled(&self) {
+		loop {
+			// Created before the check, so a `cancel()` in between is not missed.
+			let notified = self.inner.notify.notified();
+			if self.is_cancelled() {
+				return;
+			}
+			notified.await;
+		}
+	}
+}
+
+// endregion: --- CancellationToken
+
+// region:    --- Guards
+
+/// Runs the call until it completes, the timeout elapses, or the token is cancelled.
+/// The call future is dropped (so the web request aborted) on timeout or cancellation.
+pub(crate) async fn guard_call<T, F>(
+	model_iden: impl FnOnce() -> Result<ModelIden>,
+	timeout: Option<Duration>,
+	cancellation_token: Option<&CancellationToken>,
+	call: F,
+) -> Result<T>
+where
+	F: Future<Output = Result<T>>,
+{
+	if timeout.is_none() && cancellation_token.is_none() {
+		return call.await;
+	}
+
+	let deadline = timeout.map(|timeout| Instant::now() + timeout);
+	tokio::select! {
+		biased;
+		_ = cancelled(cancellation_token) => Err(Error::Cancelled { model_iden: model_iden()? }),
+		res = call => res,
+		_ = deadline_reached(deadline) => Err(Error::RequestTimeout {
+			model_iden: model_iden()?,
+			timeout: timeout.unwrap_or_default(),
+		}),
+	}
+}
+
+/// The limits of a chat stream (see `guard_chat_stream`).
+pub(crate) struct StreamGuard {
+	pub model_iden: ModelIden,
+	/// The request timeout, counted from `started_at`, until the first event.
+	pub timeout: Option<Duration>,
+	pub started_at: Instant,
+	pub idle_timeout: Option<Duration>,
+	pub cancellation_token: Option<CancellationToken>,
+}
+
+/// Ends the stream with an error (and drops the underlying request) when the first event does not come
+/// before the request timeout, when no event comes for the idle timeout, or when the token is cancelled.
+pub(crate) fn guard_chat_stream(stream: ChatStream, guard: StreamGuard) -> ChatStream {
+	if guard.timeout.is_none() && guard.idle_timeout.is_none() && guard.cancellation_token.is_none() {
+		return stream;
+	}
+
+	struct State<S> {
+		inter_stream: Option<S>,
+		guard: StreamGuard,
+		started: bool,
+	}
+
+	let state = State {
+		inter_stream: Some(stream.into_inter_stream()),
+		guard,
+		started: false,
+	};
+
+	let inter_stream = futures::stream::unfold(state, |mut state| async move {
+		// Taken, so the request is dropped when the stream ends with a timeout or cancellation error.
+		let mut inter_stream = state.inter_stream.take()?;
+		let guard = &state.guard;
+		let deadline = match state.started {
+			false => guard.timeout.map(|timeout| guard.started_at + timeout),
+			true => guard.idle_timeout.map(|idle_timeout| Instant::now() + idle_timeout),
+		};
+
+		let err = tokio::select! {
+			biased;
+			_ = cancelled(guard.cancellation_token.as_ref()) => Error::Cancelled {
+				model_iden: guard.model_iden.clone(),
+			},
+			event = inter_stream.next() => {
+				let event = event?;
+				state.inter_stream = Some(inter_stream);
+				state.started = true;
+				return Some((event, state));
+			}
+			_ = deadline_reached(deadline) => match state.started {
+				false => Error::RequestTimeout {
+					model_iden: guard.model_iden.clone(),
+					timeout: guard.timeout.unwrap_or_default(),
+				},
+				true => Error::StreamIdleTimeout {
+					model_iden: guard.model_iden.clone(),
+					timeout: guard.idle_timeout.unwrap_or_default(),
+				},
+			},
+		};
+
+		Some((Err(err), state))
+	});
+
+	ChatStream::from_inter_stream(inter_stream)
+}
+
+// endregion: --- Guards
+
+// region:    --- Support
+
+async fn cancelled(cancellation_token: Option<&CancellationToken>) {
+	match cancellation_token {
+		Some(cancellation_token) => cancellation_token.cancelled().await,
+		None => std::future::pending().await,
+	}
+}
+
+async fn deadline_reached(deadline: Option<Instant>) {
+	match deadline {
+		Some(deadline) => tokio::time::sleep_until(deadline).await,
+		None => std::future::pending().await,
+	}
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[tokio::test]
+	async fn test_cancellation_token_cancelled() {
+		let token = CancellationToken::new();
+		let waiter = tokio::spawn({
+			let token = token.clone();
+			async move { token.cancelled().await }
+		});
+
+		assert!(!token.is_cancelled());
+		token.cancel();
+		waiter.await.expect("waiter should complete");
+		assert!(token.is_cancelled());
+		// Already cancelled, completes right away.
+		token.cancelled().await;
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,86 @@
+//! Provider batch APIs (OpenAI, Anthropic, Gemini): submit many chat requests at the batch pricing,
+//! poll the batch, and fetch the results once it is done.
+//!
+//! Note: The batch calls are plain provider API calls, the client middlewares, retry policy,
+//!       rate limits, and response cache are not applied.
+
+use crate::adapter::{AdapterDispatcher, BatchItemData, ServiceType};
+use crate::chat::{ChatBatch, ChatBatchItem, ChatBatchResult, ChatOptions, ChatOptionsSet};
+use crate::{Client, Error, Result, ServiceTarget};
+
+impl Client {
+	/// Submits the chat requests as one provider batch for the given model.
+	///
+	/// Each request is built as for `exec_chat(..)` (with the given and client `ChatOptions`).
+	/// Store the returned `ChatBatch` (it is serializable) to poll it later.
+	pub async fn submit_chat_batch<I, T>(
+		&self,
+		model: &str,
+		items: I,
+		options: Option<&ChatOptions>,
+	) -> Result<ChatBatch>
+	where
+		I: IntoIterator<Item = T>,
+		T: Into<ChatBatchItem>,
+	{
+		let options_set = ChatOptionsSet::default()
+			.with_chat_options(options)
+			.with_client_options(self.config().chat_options_for(model));
+
+		let target = self.resolve_service_target(model).await?;
+
+		let items = items
+			.into_iter()
+			.map(|item| {
+				let ChatBatchItem { custom_id, chat_req } = item.into();
+				let request = AdapterDispatcher::to_web_request_data(
+					ServiceTarget {
+						endpoint: target.endpoint.clone(),
+						auth: target.auth.clone(),
+						model: target.model.clone(),
+					},
+					ServiceType::Chat,
+					chat_req,
+					options_set.clone(),
+				)?;
+				Ok(BatchItemData { custom_id, request })
+			})
+			.collect::<Result<Vec<_>>>()?;
+
+		AdapterDispatcher::submit_chat_batch(self.web_client(), target, items).await
+	}
+
+	/// Returns the batch with its current status and request counts.
+	pub async fn poll_batch(&self, batch: &ChatBatch) -> Result<ChatBatch> {
+		let target = self.batch_service_target(batch).await?;
+		AdapterDispatcher::poll_batch(self.web_client(), target, &batch.id).await
+	}
+
+	/// Fetches the results of a done batch (see `BatchStatus::is_done()`), one per request that the provider processed.
+	///
+	/// The `ChatOptions` are the ones used to build the responses (e.g., `capture_raw_body`).
+	pub async fn fetch_batch_results(
+		&self,
+		batch: &ChatBatch,
+		options: Option<&ChatOptions>,
+	) -> Result<Vec<ChatBatchResult>> {
+		if !batch.status.is_done() {
+			return Err(Error::BatchNotCompleted {
+				model_iden: batch.model_iden.clone(),
+				batch_id: batch.id.clone(),
+				status: batch.status,
+			});
+		}
+
+		let options_set = ChatOptionsSet::default()
+			.with_chat_options(options)
+			.with_client_options(self.config().chat_options());
+		let target = self.batch_service_target(batch).await?;
+
+		AdapterDispatcher::fetch_batch_results(self.web_client(), target, batch, options_set).await
+	}
+
+	async fn batch_service_target(&self, batch: &ChatBatch) -> Result<ServiceTarget> {
+		self.config().resolve_service_target(batch.model_iden.clone()).await
+	}
+}
@@ -0,0 +1,71 @@
+//! Model capabilities of the client: the `CapabilityRegistry` ones (built-in data and overrides),
+//! completed with the metadata of the provider models endpoints once loaded.
+
+use crate::adapter::AdapterKind;
+use crate::chat::{CapabilityRegistry, ModelCapabilities};
+use crate::{Client, Error, ModelIden, Result};
+
+impl Client {
+	/// Returns the capabilities of the model (after the `ModelMapper`), each one `None` when unknown.
+	///
+	/// The `CapabilityRegistry` ones (see `ClientConfig::with_capability_registry`) take precedence,
+	/// and the provider metadata loaded with `load_model_capabilities(..)` fills the unknown ones.
+	pub fn model_capabilities(&self, model: &str) -> Result<ModelCapabilities> {
+		let model_iden = self.default_model(model)?;
+		let model_iden = match self.config().model_mapper() {
+			Some(model_mapper) => {
+				model_mapper
+					.map_model(model_iden.clone())
+					.map_err(|resolver_error| Error::Resolver {
+						model_iden,
+						resolver_error,
+					})?
+			}
+			None => model_iden,
+		};
+
+		Ok(self.capabilities_of(&model_iden))
+	}
+
+	/// Loads the capabilities given by the provider models endpoint of the adapter (see `list_models`),
+	/// and returns the number of models with some.
+	///
+	/// Note: Only the context window is given by some providers (e.g., Gemini, Groq, Together, Cohere).
+	pub async fn load_model_capabilities(&self, adapter_kind: AdapterKind) -> Result<usize> {
+		let models = self.list_models(adapter_kind).await?;
+
+		let mut live_capabilities = self
+			.live_capabilities()
+			.write()
+			.unwrap_or_else(|poisoned| poisoned.into_inner());
+		let mut count = 0;
+		for model in &models {
+			let capabilities = ModelCapabilities::from(model);
+			if capabilities != ModelCapabilities::default() {
+				live_capabilities.insert(ModelIden::new(adapter_kind, model.id.as_str()), capabilities);
+				count += 1;
+			}
+		}
+
+		Ok(count)
+	}
+
+	/// The capabilities of a resolved model.
+	pub(crate) fn capabilities_of(&self, model_iden: &ModelIden) -> ModelCapabilities {
+		let (model_name, _) = model_iden.model_name.as_model_name_and_namespace();
+		let mut capabilities = self
+			.live_capabilities()
+			.read()
+			.unwrap_or_else(|poisoned| poisoned.into_inner())
+			.get(&ModelIden::new(model_iden.adapter_kind, model_name))
+			.copied()
+			.unwrap_or_default();
+
+		let registry = self.config().capability_registry().unwrap_or(CapabilityRegistry::builtin());
+		if let Some(registered) = registry.get(model_iden) {
+			capabilities.merge(&registered);
+		}
+
+		capabilities
+	}
+}
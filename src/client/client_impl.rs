@@ -1,31 +1,78 @@
 use crate::adapter::{AdapterDispatcher, AdapterKind, ServiceType, WebRequestData};
 use crate::chat::{
-	ChatOptions, ChatOptionsSet, ChatRequest, ChatResponse, ChatStreamResponse, ImagenGenerateImagesRequest,
-	ImagenGenerateImagesResponse, VeoGenerateVideosRequest, VeoGenerateVideosResponse, VeoOperationStatusResponse,
+	ChatMessage, ChatOptions, ChatOptionsSet, ChatRequest, ChatResponse, ChatStream, ChatStreamResponse,
+	ImagenGenerateImagesRequest, ImagenGenerateImagesResponse, Usage, VeoGenerateVideosRequest,
+	VeoGenerateVideosResponse, VeoOperationStatusResponse,
 };
+use crate::client::api_key_pool::api_key_chat_stream;
+use crate::client::audit::{PendingAudit, StreamAuditSlot, audit_chat_stream, audit_chat_stream_end};
+use crate::client::cancellation::{StreamGuard, guard_call, guard_chat_stream};
+use crate::client::metrics::{self, StreamTiming, time_chat_stream};
+use crate::client::rate_limiter::estimate_payload_tokens;
+use crate::client::request_validation::validate_chat_request;
+use crate::client::resume_policy::{ResumeCapture, resume_chat_stream, supports_prefill};
+use crate::client::retry_policy::retry_chat_stream;
+use crate::client::telemetry::{self, GenAiOperation, trace_chat_stream};
+use crate::client::{CacheKey, CachedResponse, MiddlewareContext, RequestKind, RequestPreview, StreamMiddlewares};
 use crate::embed::{EmbedOptions, EmbedOptionsSet, EmbedRequest, EmbedResponse};
 use crate::resolver::AuthData;
-use crate::{Client, Error, ModelIden, Result, ServiceTarget};
+use crate::webc::{self, WebResponse, WebStreamRequest};
+use crate::{Client, Error, FallbackAttempt, Headers, ModelIden, ModelInfo, Result, RetryPolicy, ServiceTarget};
+use serde_json::Value;
+use std::sync::Arc;
+use tokio::time::Instant;
+use tracing::{Instrument, Span};
 
 /// High-level client APIs.
 impl Client {
-	/// Lists model names for the given adapter.
+	/// Lists the model names for the given adapter: the ids of `Client::list_models`, or the adapter static list
+	/// when the listing fails for any reason (e.g., no API key, offline), so it works without a key or network.
 	///
 	/// Notes:
 	///
-	/// - Non-Ollama adapters use a static list.
+	/// - For most adapters, names also drive AdapterKind detection (see [`AdapterKind`]).
 	///
-	/// - Ollama queries the default host (http://localhost:11434/v1/).
+	/// - The provider lists may have non-chat models (e.g., embeddings), the static fallback lists do not.
 	///
-	/// - May evolve to accept a custom endpoint.
+	/// - Returns the listing error only when the adapter has no static list (e.g., Ollama).
+	pub async fn all_model_names(&self, adapter_kind: AdapterKind) -> Result<Vec<String>> {
+		match self.list_models(adapter_kind).await {
+			Ok(models) => Ok(models.into_iter().map(|model| model.id).collect()),
+			Err(err) => {
+				let names = AdapterDispatcher::all_model_names(adapter_kind).await?;
+				if names.is_empty() {
+					return Err(err);
+				}
+				tracing::debug!(%adapter_kind, error = %err, "model listing failed, using the static model names");
+				Ok(names)
+			}
+		}
+	}
+
+	/// Lists the models of the provider models endpoint for the given adapter (a live call, see `all_model_names`
+	/// for the names with an offline fallback).
 	///
-	/// - For most adapters, names also drive AdapterKind detection (see [`AdapterKind`]).
+	/// The endpoint and auth are resolved as for a call (`AuthResolver` and `ServiceTargetResolver`), for a
+	/// `ModelIden` of this adapter with an empty model name, and the request is sent with the client `WebClient`.
 	///
-	/// - Adapters should filter non-chat models until more skills are supported.
-	///   Future: `model_names(adapter_kind, Option<&[Skill]>)`.
-	pub async fn all_model_names(&self, adapter_kind: AdapterKind) -> Result<Vec<String>> {
-		let models = AdapterDispatcher::all_model_names(adapter_kind).await?;
-		Ok(models)
+	/// When the provider has no models endpoint (`Error::AdapterNotSupported`, or a `404` status), the adapter
+	/// static list of the common models is returned instead (with the ids only), and the error when there is none
+	/// (e.g., Ollama). The other errors (e.g., auth, network, resolver) are returned.
+	pub async fn list_models(&self, adapter_kind: AdapterKind) -> Result<Vec<ModelInfo>> {
+		let target = self.config().resolve_service_target(ModelIden::new(adapter_kind, "")).await?;
+
+		match AdapterDispatcher::list_models(self.web_client(), target).await {
+			Ok(models) => Ok(models),
+			Err(err) if !is_model_listing_unavailable(&err) => Err(err),
+			Err(err) => {
+				let names = AdapterDispatcher::all_model_names(adapter_kind).await?;
+				if names.is_empty() {
+					return Err(err);
+				}
+				tracing::debug!(%adapter_kind, error = %err, "model listing failed, using the static model names");
+				Ok(names.into_iter().map(ModelInfo::new).collect())
+			}
+		}
 	}
 
 	/// Builds a ModelIden by inferring AdapterKind from the model name.
@@ -50,53 +97,130 @@ impl Client {
 		self.config().resolve_service_target(model).await
 	}
 
-	/// Sends a chat request and returns the full response.
-	pub async fn exec_chat(
+	/// Builds the web request `exec_chat` would send for this model, without sending it (dry run).
+	///
+	/// The preview has the resolved model, the URL, the headers (auth redacted), and the provider JSON payload.
+	/// Middlewares are not applied (see `RequestPreview`).
+	pub async fn preview_chat_request(
 		&self,
 		model: &str,
-		chat_req: ChatRequest,
-		// options not implemented yet
+		chat_req: &ChatRequest,
 		options: Option<&ChatOptions>,
-	) -> Result<ChatResponse> {
+	) -> Result<RequestPreview> {
 		let options_set = ChatOptionsSet::default()
 			.with_chat_options(options)
-			.with_client_options(self.config().chat_options());
+			.with_client_options(self.config().chat_options_for(model));
 
-		let model = self.default_model(model)?;
-		let target = self.config().resolve_service_target(model).await?;
+		let target = self.resolve_service_target(model).await?;
 		let model = target.model.clone();
 		let auth_data = target.auth.clone();
 
-		let WebRequestData {
-			mut url,
-			mut headers,
-			payload,
-		} = AdapterDispatcher::to_web_request_data(target, ServiceType::Chat, chat_req, options_set.clone())?;
+		let mut web_request_data =
+			AdapterDispatcher::to_web_request_data(target, ServiceType::Chat, chat_req.clone(), options_set)?;
+		apply_request_override(&auth_data, &mut web_request_data);
+		AdapterDispatcher::sign_web_request(&model, &auth_data, &mut web_request_data)?;
 
-		if let AuthData::RequestOverride {
-			url: override_url,
-			headers: override_headers,
-		} = auth_data
-		{
-			url = override_url;
-			headers = override_headers;
-		};
+		Ok(RequestPreview::new(model, web_request_data, &auth_data))
+	}
 
-		let web_res =
-			self.web_client()
-				.do_post(&url, &headers, payload)
-				.await
-				.map_err(|webc_error| Error::WebModelCall {
-					model_iden: model.clone(),
-					webc_error,
-				})?;
+	/// Builds the web request `exec_embed` would send for this model, without sending it (dry run).
+	pub async fn preview_embed_request(
+		&self,
+		model: &str,
+		embed_req: &EmbedRequest,
+		options: Option<&EmbedOptions>,
+	) -> Result<RequestPreview> {
+		let options_set = EmbedOptionsSet::new()
+			.with_request_options(options)
+			.with_client_options(self.config().embed_options_for(model));
 
-		let chat_res = AdapterDispatcher::to_chat_response(model, web_res, options_set)?;
+		let target = self.resolve_service_target(model).await?;
+		let model = target.model.clone();
+		let auth_data = target.auth.clone();
 
-		Ok(chat_res)
+		let web_request_data = AdapterDispatcher::to_embed_request_data(target, embed_req.clone(), options_set)?;
+
+		Ok(RequestPreview::new(model, web_request_data, &auth_data))
+	}
+
+	/// Sends a chat request and returns the full response.
+	///
+	/// When `ChatOptions.fallback_models` is set, the next model is tried when the current one
+	/// fails with a retryable error (see `RetryPolicy::is_retryable`). The failed attempts are
+	/// reported in `ChatResponse.fallback_attempts`.
+	///
+	/// The `ChatOptions` timeout and cancellation token bound the whole call (retries and fallbacks included).
+	pub async fn exec_chat(
+		&self,
+		model: &str,
+		chat_req: ChatRequest,
+		// options not implemented yet
+		options: Option<&ChatOptions>,
+	) -> Result<ChatResponse> {
+		let options_set = ChatOptionsSet::default()
+			.with_chat_options(options)
+			.with_client_options(self.config().chat_options_for(model));
+
+		// Taken first, as the call below consumes the options set.
+		let started_at = Instant::now();
+		let timeout = options_set.timeout();
+		let cancellation_token = options_set.cancellation_token().cloned();
+
+		let trace_content = self.config().trace_content();
+		let span = self.gen_ai_span(GenAiOperation::Chat, model);
+		telemetry::record_chat_request(&span, &chat_req, &options_set, trace_content);
+
+		let exec = async {
+			let fallback_models = options_set.fallback_models();
+			if fallback_models.is_empty() {
+				return self.exec_chat_single(model, chat_req, options_set).await;
+			}
+
+			let mut fallback_attempts = Vec::new();
+			// Collected, so no `String::as_str` map is held across the awaits below (it would make the future not `Send`).
+			let models: Vec<&str> = std::iter::once(model)
+				.chain(fallback_models.iter().map(String::as_str))
+				.collect();
+			let mut models = models.into_iter().peekable();
+			while let Some(model) = models.next() {
+				// Each model with its own client options (e.g., its alias ones).
+				let model_options_set = options_set.clone().with_client_options(self.config().chat_options_for(model));
+				match self.exec_chat_single(model, chat_req.clone(), model_options_set).await {
+					Ok(mut chat_res) => {
+						chat_res.fallback_attempts = fallback_attempts;
+						return Ok(chat_res);
+					}
+					Err(err) if models.peek().is_some() && self.is_fallback_error(options_set.retry_policy(), &err) => {
+						let attempt = FallbackAttempt::new(self.failed_model_iden(model, &err)?, &err);
+						tracing::debug!(model = %attempt.model_iden, error = %attempt.error, "chat failed, trying next fallback model");
+						fallback_attempts.push(attempt);
+					}
+					Err(err) => return Err(err),
+				}
+			}
+
+			// Unreachable: the model list always has at least one entry and the last one always returns.
+			Err(Error::Internal("No model to execute the chat request".to_string()))
+		};
+
+		let mut res = guard_call(|| self.mapped_model(model), timeout, cancellation_token.as_ref(), exec)
+			.instrument(span.clone())
+			.await;
+		metrics::record_chat_metrics(
+			self.config().metrics_hook().map(AsRef::as_ref),
+			|| self.mapped_model(model).ok(),
+			started_at,
+			&mut res,
+		);
+		telemetry::record_chat_result(&span, &res, trace_content);
+
+		res
 	}
 
 	/// Streams a chat response.
+	///
+	/// The `ChatOptions` timeout bounds the call until the first stream event, the stream idle timeout
+	/// the wait between events, and the cancellation token both (the stream then ends with the error).
 	pub async fn exec_chat_stream(
 		&self,
 		model: &str,
@@ -105,42 +229,130 @@ impl Client {
 	) -> Result<ChatStreamResponse> {
 		let options_set = ChatOptionsSet::default()
 			.with_chat_options(options)
-			.with_client_options(self.config().chat_options());
-
-		let model = self.default_model(model)?;
-		let target = self.config().resolve_service_target(model).await?;
-		let model = target.model.clone();
-		let auth_data = target.auth.clone();
-
-		let WebRequestData {
-			mut url,
-			mut headers,
-			payload,
-		} = AdapterDispatcher::to_web_request_data(target, ServiceType::ChatStream, chat_req, options_set.clone())?;
-
-		// TODO: Need to check this.
-		//       This was part of the 429c5cee2241dbef9f33699b9c91202233c22816 commit
-		//       But now it is missing in the the exec_chat(..) above, which is probably an issue.
-		if let AuthData::RequestOverride {
-			url: override_url,
-			headers: override_headers,
-		} = auth_data
-		{
-			url = override_url;
-			headers = override_headers;
+			.with_client_options(self.config().chat_options_for(model));
+
+		// The timeout bounds the call and the stream until its first event.
+		let started_at = Instant::now();
+		let timeout = options_set.timeout();
+		let idle_timeout = options_set.stream_idle_timeout();
+		let cancellation_token = options_set.cancellation_token().cloned();
+
+		let trace_content = self.config().trace_content();
+		let span = self.gen_ai_span(GenAiOperation::Chat, model);
+		telemetry::record_chat_request(&span, &chat_req, &options_set, trace_content);
+
+		let exec = async {
+			let model = self.default_model(model)?;
+			let target = self.config().resolve_service_target(model).await?;
+			let model = target.model.clone();
+
+			if options_set.validate_request() == Some(true) {
+				validate_chat_request(&model, &self.capabilities_of(&model), &chat_req, &options_set)?;
+			}
+
+			// -- Middlewares (the request hooks run for each request sent, the stream event hooks on the returned stream)
+			let ctx = MiddlewareContext::new(model.clone(), RequestKind::ChatStream);
+			let stream_middlewares = (!self.config().middlewares().is_empty())
+				.then(|| StreamMiddlewares::new(ctx.clone(), self.config().middlewares().to_vec()));
+
+			// -- Response cache (a cached chat response is replayed as a stream, streams do not populate the cache)
+			if let Some(response_cache) = self.config().response_cache()
+				&& let Some(CachedResponse::Chat(mut chat_res)) =
+					response_cache.get(&CacheKey::for_chat(&model, &chat_req, &options_set))
+			{
+				chat_res.cached = true;
+				telemetry::record_chat_result(&span, &Ok(chat_res.clone()), trace_content);
+				let stream = ChatStream::from_chat_response(chat_res, &options_set);
+				let mut stream = self.time_chat_stream(stream, model.clone(), started_at, true);
+				if let Some(stream_middlewares) = stream_middlewares {
+					stream = stream.with_middlewares(stream_middlewares);
+				}
+				return Ok(ChatStreamResponse {
+					stream,
+					model_iden: model,
+				});
+			}
+
+			// -- Send (the retries and resumes re-issue the request through the same sender)
+			let audit_end_slot = self.config().audit_sink().map(|_| StreamAuditSlot::default());
+			let sender = Arc::new(ChatStreamSender {
+				client: self.clone(),
+				target,
+				chat_req,
+				chat_options: options_set.chat_options().cloned(),
+				client_chat_options: options_set.client_options().cloned(),
+				audit_end_slot: audit_end_slot.clone(),
+			});
+			let mut stream = sender.send("").await?;
+
+			if let Some(retry_policy) = options_set.retry_policy().or(self.config().retry_policy()).cloned() {
+				let sender = sender.clone();
+				stream = retry_chat_stream(stream, retry_policy, move || {
+					let sender = sender.clone();
+					async move { sender.send("").await }
+				});
+			}
+
+			// Re-issue the request with a prefill if the stream drops.
+			if let Some(resume_policy) = options_set
+				.resume_policy()
+				.filter(|_| supports_prefill(model.adapter_kind))
+				.cloned()
+			{
+				let capture = ResumeCapture {
+					content: options_set.capture_content().unwrap_or_default(),
+					reasoning_content: options_set.capture_reasoning_content().unwrap_or_default(),
+				};
+				stream = resume_chat_stream(stream, model.clone(), resume_policy, capture, move |prefill: &str| {
+					let sender = sender.clone();
+					let prefill = prefill.to_string();
+					async move { sender.send(&prefill).await }
+				});
+			}
+
+			stream = guard_chat_stream(
+				stream,
+				StreamGuard {
+					model_iden: model.clone(),
+					timeout,
+					started_at,
+					idle_timeout,
+					cancellation_token: cancellation_token.clone(),
+				},
+			);
+
+			stream = self.time_chat_stream(stream, model.clone(), started_at, false);
+			if let (Some(audit_end_slot), Some(audit_sink)) = (audit_end_slot, self.config().audit_sink()) {
+				stream = audit_chat_stream_end(stream, audit_end_slot, audit_sink.clone());
+			}
+
+			span.record("gen_ai.response.model", &*model.model_name);
+			stream = trace_chat_stream(stream, span.clone(), trace_content);
+
+			if let Some(stream_middlewares) = stream_middlewares {
+				stream = stream.with_middlewares(stream_middlewares);
+			}
+
+			Ok(ChatStreamResponse {
+				stream,
+				model_iden: model,
+			})
 		};
 
-		let reqwest_builder = self
-			.web_client()
-			.new_req_builder(&url, &headers, payload)
-			.map_err(|webc_error| Error::WebModelCall {
-				model_iden: model.clone(),
-				webc_error,
-			})?;
-
-		let res = AdapterDispatcher::to_chat_stream(model, reqwest_builder, options_set)?;
-
-		Ok(res)
+		let res = guard_call(|| self.mapped_model(model), timeout, cancellation_token.as_ref(), exec)
+			.instrument(span.clone())
+			.await;
+		if let Err(err) = &res {
+			metrics::record_chat_stream_error(
+				self.config().metrics_hook().map(AsRef::as_ref),
+				|| self.mapped_model(model).ok(),
+				started_at,
+				err,
+			);
+			telemetry::record_error(&span, err);
+		}
+
+		res
 	}
 
 	/// Creates embeddings for a single input string.
@@ -166,6 +378,8 @@ impl Client {
 	}
 
 	/// Sends an embedding request and returns the response.
+	///
+	/// Like `exec_chat`, falls back to `EmbedOptions.fallback_models` on retryable errors.
 	pub async fn exec_embed(
 		&self,
 		model: &str,
@@ -174,27 +388,47 @@ impl Client {
 	) -> Result<EmbedResponse> {
 		let options_set = EmbedOptionsSet::new()
 			.with_request_options(options)
-			.with_client_options(self.config().embed_options());
-
-		let model = self.default_model(model)?;
-		let target = self.config().resolve_service_target(model).await?;
-		let model = target.model.clone();
-
-		let WebRequestData { headers, payload, url } =
-			AdapterDispatcher::to_embed_request_data(target, embed_req, options_set.clone())?;
-
-		let web_res =
-			self.web_client()
-				.do_post(&url, &headers, payload)
-				.await
-				.map_err(|webc_error| Error::WebModelCall {
-					model_iden: model.clone(),
-					webc_error,
-				})?;
+			.with_client_options(self.config().embed_options_for(model));
+
+		let span = self.gen_ai_span(GenAiOperation::Embeddings, model);
+
+		let exec = async {
+			let fallback_models = options_set.fallback_models();
+			if fallback_models.is_empty() {
+				return self.exec_embed_single(model, embed_req, options_set).await;
+			}
+
+			let mut fallback_attempts = Vec::new();
+			// Collected, so no `String::as_str` map is held across the awaits below (it would make the future not `Send`).
+			let models: Vec<&str> = std::iter::once(model)
+				.chain(fallback_models.iter().map(String::as_str))
+				.collect();
+			let mut models = models.into_iter().peekable();
+			while let Some(model) = models.next() {
+				// Each model with its own client options (e.g., its alias ones).
+				let model_options_set = options_set.clone().with_client_options(self.config().embed_options_for(model));
+				match self.exec_embed_single(model, embed_req.clone(), model_options_set).await {
+					Ok(mut embed_res) => {
+						embed_res.fallback_attempts = fallback_attempts;
+						return Ok(embed_res);
+					}
+					Err(err) if models.peek().is_some() && self.is_fallback_error(None, &err) => {
+						let attempt = FallbackAttempt::new(self.failed_model_iden(model, &err)?, &err);
+						tracing::debug!(model = %attempt.model_iden, error = %attempt.error, "embed failed, trying next fallback model");
+						fallback_attempts.push(attempt);
+					}
+					Err(err) => return Err(err),
+				}
+			}
+
+			// Unreachable: the model list always has at least one entry and the last one always returns.
+			Err(Error::Internal("No model to execute the embed request".to_string()))
+		};
 
-		let res = AdapterDispatcher::to_embed_response(model, web_res, options_set)?;
+		let res = exec.instrument(span.clone()).await;
+		telemetry::record_embed_result(&span, &res);
 
-		Ok(res)
+		res
 	}
 
 	/// Executes an Imagen 3 image generation request.
@@ -206,25 +440,25 @@ impl Client {
 		let model_iden = self.default_model(model)?; // This will set AdapterKind to Gemini if model starts with "imagen-"
 		let target = self.config().resolve_service_target(model_iden.clone()).await?;
 
-		// Extract model_iden before moving target
+		// The resolved model, for the response
 		let target_model = target.model.clone();
 
-		let web_request_data = AdapterDispatcher::to_imagen_generation_request_data(target, request)?;
-
-		let web_res = self
-			.web_client()
-			.do_post(
-				&web_request_data.url,
-				&web_request_data.headers,
-				web_request_data.payload,
+		let build_request =
+			|target: ServiceTarget| AdapterDispatcher::to_imagen_generation_request_data(target, request.clone());
+
+		let mut audit = None;
+		let res = self
+			.exec_web_request(
+				RequestKind::ImagenGenerateImages,
+				&target,
+				build_request,
+				None,
+				&mut audit,
 			)
 			.await
-			.map_err(|webc_error| Error::WebModelCall {
-				model_iden: target_model.clone(),
-				webc_error,
-			})?;
-
-		let response = AdapterDispatcher::to_imagen_generation_response(target_model, web_res)?;
+			.and_then(|web_res| AdapterDispatcher::to_imagen_generation_response(target_model, web_res));
+		self.finish_audit(audit, None, res.as_ref().err());
+		let response = res?;
 
 		Ok(response)
 	}
@@ -238,25 +472,19 @@ impl Client {
 		let model_iden = self.default_model(model)?;
 		let target = self.config().resolve_service_target(model_iden.clone()).await?;
 
-		// Extract model_iden before moving target
+		// The resolved model, for the response
 		let target_model = target.model.clone();
 
-		let web_request_data = AdapterDispatcher::to_veo_generation_request_data(target, request)?;
+		let build_request =
+			|target: ServiceTarget| AdapterDispatcher::to_veo_generation_request_data(target, request.clone());
 
-		let web_res = self
-			.web_client()
-			.do_post(
-				&web_request_data.url,
-				&web_request_data.headers,
-				web_request_data.payload,
-			)
+		let mut audit = None;
+		let res = self
+			.exec_web_request(RequestKind::VeoGenerateVideos, &target, build_request, None, &mut audit)
 			.await
-			.map_err(|webc_error| Error::WebModelCall {
-				model_iden: target_model.clone(),
-				webc_error,
-			})?;
-
-		let response = AdapterDispatcher::to_veo_generation_response(target_model, web_res)?;
+			.and_then(|web_res| AdapterDispatcher::to_veo_generation_response(target_model, web_res));
+		self.finish_audit(audit, None, res.as_ref().err());
+		let response = res?;
 
 		Ok(response)
 	}
@@ -270,23 +498,463 @@ impl Client {
 		let model_iden = self.default_model(model)?;
 		let target = self.config().resolve_service_target(model_iden.clone()).await?;
 
-		// Extract model_iden before moving target
+		// The resolved model, for the response
 		let target_model = target.model.clone();
 
-		let web_request_data = AdapterDispatcher::get_veo_operation_status_request_data(target, &operation_name)?;
+		let build_request =
+			|target: ServiceTarget| AdapterDispatcher::get_veo_operation_status_request_data(target, &operation_name);
+
+		let mut audit = None;
+		let res = self
+			.exec_web_request(
+				RequestKind::VeoOperationStatus,
+				&target,
+				build_request,
+				None,
+				&mut audit,
+			)
+			.await
+			.and_then(|web_res| AdapterDispatcher::to_veo_operation_status_response(target_model, web_res));
+		self.finish_audit(audit, None, res.as_ref().err());
+		let response = res?;
+
+		Ok(response)
+	}
+}
+
+// region:    --- Support
+
+/// Whether the model listing error means the provider has no models endpoint (so the static list applies).
+fn is_model_listing_unavailable(err: &Error) -> bool {
+	match err {
+		Error::AdapterNotSupported { .. } => true,
+		Error::WebAdapterCall {
+			webc_error: webc::Error::ResponseFailedStatus { status, .. },
+			..
+		} => *status == reqwest::StatusCode::NOT_FOUND,
+		_ => false,
+	}
+}
+
+/// Sends the request to the `RequestOverride` url and headers, if the auth is one.
+fn apply_request_override(auth_data: &AuthData, web_request_data: &mut WebRequestData) {
+	if let AuthData::RequestOverride { url, headers } = auth_data {
+		web_request_data.url = url.clone();
+		web_request_data.headers = headers.clone();
+	}
+}
+
+/// Sends the requests of a chat stream: the first one, and the ones re-issued by its retries and resumes.
+///
+/// Each request goes through the middlewares `on_request`, the `RateLimiter`, the request signing,
+/// and the `AuditSink` (one record per request).
+struct ChatStreamSender {
+	client: Client,
+	target: ServiceTarget,
+	chat_req: ChatRequest,
+	// The stream outlives the call, so the sender needs its own copy of the options.
+	chat_options: Option<ChatOptions>,
+	client_chat_options: Option<ChatOptions>,
+	audit_end_slot: Option<StreamAuditSlot>,
+}
 
-		let headers_vec: Vec<(String, String)> = web_request_data.headers.into_iter().collect();
-		let web_res = self
+impl ChatStreamSender {
+	/// Sends the request, with the `prefill` as a last assistant message (if not empty), and returns its stream.
+	async fn send(&self, prefill: &str) -> Result<ChatStream> {
+		let client = &self.client;
+		let chat_req = match prefill.is_empty() {
+			true => self.chat_req.clone(),
+			false => self.chat_req.clone().append_message(ChatMessage::assistant(prefill)),
+		};
+		let options_set = ChatOptionsSet::default()
+			.with_chat_options(self.chat_options.as_ref())
+			.with_client_options(self.client_chat_options.as_ref());
+		let target = client.request_target(&self.target);
+		let model = target.model.clone();
+		let auth_data = target.auth.clone();
+
+		let mut web_request_data =
+			AdapterDispatcher::to_web_request_data(target, ServiceType::ChatStream, chat_req, options_set.clone())?;
+		apply_request_override(&auth_data, &mut web_request_data);
+
+		let ctx = MiddlewareContext::new(model.clone(), RequestKind::ChatStream);
+		if let Some(mut web_res) = client.middlewares_on_request(&ctx, &mut web_request_data)? {
+			// Short-circuited, so replay the response as a stream.
+			client.middlewares_on_response(&ctx, &mut web_res)?;
+			let chat_res = AdapterDispatcher::to_chat_response(model, web_res, options_set.clone())?;
+			return Ok(ChatStream::from_chat_response(chat_res, &options_set));
+		}
+		// The RateLimiter wait can be long, so the key is counted and the request signed after it.
+		client.rate_limit_acquire(&model, &web_request_data.payload).await;
+		client.record_api_key_request(model.adapter_kind, &auth_data);
+		AdapterDispatcher::sign_web_request(&model, &auth_data, &mut web_request_data)?;
+
+		let WebRequestData { url, headers, payload } = web_request_data;
+		let audit = self
+			.audit_end_slot
+			.as_ref()
+			.map(|_| PendingAudit::new(RequestKind::ChatStream, model.clone(), &url, &payload));
+		let stream_request = client
 			.web_client()
-			.do_get(&web_request_data.url, &headers_vec)
-			.await
+			.new_stream_request(&url, &headers, payload)
 			.map_err(|webc_error| Error::WebModelCall {
-				model_iden: target_model.clone(),
+				model_iden: model.clone(),
 				webc_error,
 			})?;
+		let stream_request = client.rate_limit_adapt_stream(&model, stream_request);
+		let mut stream = AdapterDispatcher::to_chat_stream(model.clone(), stream_request, options_set)?.stream;
+
+		if let Some(api_key_pool) = client.config().api_key_pool() {
+			stream = api_key_chat_stream(stream, api_key_pool.clone(), model.adapter_kind, auth_data);
+		}
+		if let (Some(audit), Some(audit_end_slot), Some(audit_sink)) =
+			(audit, self.audit_end_slot.clone(), client.config().audit_sink())
+		{
+			stream = audit_chat_stream(stream, audit, audit_sink.clone(), audit_end_slot);
+		}
 
-		let response = AdapterDispatcher::to_veo_operation_status_response(target_model, web_res)?;
+		Ok(stream)
+	}
+}
 
-		Ok(response)
+impl Client {
+	/// Executes the chat request on a single model (with retries, no fallback).
+	async fn exec_chat_single(
+		&self,
+		model: &str,
+		chat_req: ChatRequest,
+		options_set: ChatOptionsSet<'_, '_>,
+	) -> Result<ChatResponse> {
+		let model = self.default_model(model)?;
+		let target = self.config().resolve_service_target(model).await?;
+		let model = target.model.clone();
+
+		if options_set.validate_request() == Some(true) {
+			validate_chat_request(&model, &self.capabilities_of(&model), &chat_req, &options_set)?;
+		}
+
+		// -- Response cache (keyed by this model, so a fallback response is cached under the model that answered)
+		let response_cache = self.config().response_cache();
+		let cache_key = response_cache.map(|_| CacheKey::for_chat(&model, &chat_req, &options_set));
+		if let (Some(response_cache), Some(cache_key)) = (response_cache, &cache_key)
+			&& let Some(CachedResponse::Chat(mut chat_res)) = response_cache.get(cache_key)
+		{
+			chat_res.cached = true;
+			return Ok(chat_res);
+		}
+
+		let build_request = |target: ServiceTarget| {
+			let auth_data = target.auth.clone();
+			let mut web_request_data = AdapterDispatcher::to_web_request_data(
+				target,
+				ServiceType::Chat,
+				chat_req.clone(),
+				options_set.clone(),
+			)?;
+			apply_request_override(&auth_data, &mut web_request_data);
+			Ok(web_request_data)
+		};
+
+		let retry_policy = options_set.retry_policy().or(self.config().retry_policy());
+		let mut audit = None;
+		let res = self
+			.exec_web_request(RequestKind::Chat, &target, build_request, retry_policy, &mut audit)
+			.await
+			.and_then(|web_res| AdapterDispatcher::to_chat_response(model, web_res, options_set));
+		self.finish_audit(
+			audit,
+			res.as_ref().ok().map(|chat_res| chat_res.usage.clone()),
+			res.as_ref().err(),
+		);
+		let chat_res = res?;
+
+		if let (Some(response_cache), Some(cache_key)) = (response_cache, &cache_key) {
+			response_cache.put(cache_key, &CachedResponse::Chat(chat_res.clone()));
+		}
+
+		Ok(chat_res)
+	}
+
+	/// Executes the embed request on a single model (with retries, no fallback).
+	async fn exec_embed_single(
+		&self,
+		model: &str,
+		embed_req: EmbedRequest,
+		options_set: EmbedOptionsSet<'_, '_>,
+	) -> Result<EmbedResponse> {
+		let model = self.default_model(model)?;
+		let target = self.config().resolve_service_target(model).await?;
+		let model = target.model.clone();
+
+		// -- Response cache (keyed by this model, so a fallback response is cached under the model that answered)
+		let response_cache = self.config().response_cache();
+		let cache_key = response_cache.map(|_| CacheKey::for_embed(&model, &embed_req, &options_set));
+		if let (Some(response_cache), Some(cache_key)) = (response_cache, &cache_key)
+			&& let Some(CachedResponse::Embed(mut embed_res)) = response_cache.get(cache_key)
+		{
+			embed_res.cached = true;
+			return Ok(embed_res);
+		}
+
+		let build_request = |target: ServiceTarget| {
+			AdapterDispatcher::to_embed_request_data(target, embed_req.clone(), options_set.clone())
+		};
+
+		let mut audit = None;
+		let res = self
+			.exec_web_request(
+				RequestKind::Embed,
+				&target,
+				build_request,
+				self.config().retry_policy(),
+				&mut audit,
+			)
+			.await
+			.and_then(|web_res| AdapterDispatcher::to_embed_response(model, web_res, options_set));
+		self.finish_audit(
+			audit,
+			res.as_ref().ok().map(|embed_res| embed_res.usage.clone()),
+			res.as_ref().err(),
+		);
+		let res = res?;
+
+		if let (Some(response_cache), Some(cache_key)) = (response_cache, &cache_key) {
+			response_cache.put(cache_key, &CachedResponse::Embed(res.clone()));
+		}
+
+		Ok(res)
+	}
+
+	/// Returns true if the error should move the call to the next fallback model.
+	///
+	/// Uses the effective `RetryPolicy` classification (or the default one if none is set).
+	fn is_fallback_error(&self, retry_policy: Option<&RetryPolicy>, err: &Error) -> bool {
+		match retry_policy.or(self.config().retry_policy()) {
+			Some(retry_policy) => retry_policy.is_retryable(err),
+			None => RetryPolicy::default().is_retryable(err),
+		}
+	}
+
+	/// The (mapped) model of a failed web call, or the default model for the name.
+	fn failed_model_iden(&self, model: &str, err: &Error) -> Result<ModelIden> {
+		match err {
+			Error::WebModelCall { model_iden, .. } => Ok(model_iden.clone()),
+			_ => self.default_model(model),
+		}
+	}
+
+	/// The default model for the name, mapped by the `ModelMapper` (e.g., a config alias), as the call resolves it.
+	///
+	/// Used to report a call (span, metrics, timeout errors) before or without its resolved `ServiceTarget`.
+	fn mapped_model(&self, model: &str) -> Result<ModelIden> {
+		let model = self.default_model(model)?;
+		match self.config().model_mapper() {
+			Some(model_mapper) => model_mapper.map_model(model.clone()).map_err(|resolver_error| Error::Resolver {
+				model_iden: model,
+				resolver_error,
+			}),
+			None => Ok(model),
+		}
+	}
+
+	/// The `gen_ai` tracing span of a call (see `telemetry`), with the system of the mapped model.
+	fn gen_ai_span(&self, operation: GenAiOperation, model: &str) -> Span {
+		let adapter_kind = self.mapped_model(model).ok().map(|model_iden| model_iden.adapter_kind);
+		telemetry::gen_ai_span(operation, model, adapter_kind)
+	}
+
+	/// Sends the audit record of a provider call to the `AuditSink` (see `audit`).
+	fn finish_audit(&self, audit: Option<PendingAudit>, usage: Option<Usage>, error: Option<&Error>) {
+		if let (Some(audit), Some(audit_sink)) = (audit, self.config().audit_sink()) {
+			audit.finish(audit_sink.as_ref(), usage, error);
+		}
+	}
+
+	/// Times the stream and reports it to the `MetricsHook` (see `metrics`).
+	fn time_chat_stream(
+		&self,
+		stream: ChatStream,
+		model_iden: ModelIden,
+		started_at: Instant,
+		cached: bool,
+	) -> ChatStream {
+		let timing = StreamTiming {
+			model_iden,
+			started_at,
+			cached,
+			hook: self.config().metrics_hook().cloned(),
+		};
+		time_chat_stream(stream, timing)
+	}
+}
+
+impl Client {
+	/// Sends the web request through the middleware chain.
+	///
+	/// The request is built by `build_request` for each attempt, which goes through the middlewares `on_request`,
+	/// the `RateLimiter`, and the request signing (see `AdapterDispatcher::sign_web_request`).
+	/// POST requests are retried per the `RetryPolicy` (if any); the Veo operation status is a GET.
+	/// When an `AuditSink` is set, the failed attempts are recorded, and `audit` gets the last request sent
+	/// (and its response), to be finished by the caller.
+	async fn exec_web_request(
+		&self,
+		request_kind: RequestKind,
+		target: &ServiceTarget,
+		build_request: impl Fn(ServiceTarget) -> Result<WebRequestData>,
+		retry_policy: Option<&RetryPolicy>,
+		audit: &mut Option<PendingAudit>,
+	) -> Result<WebResponse> {
+		let model = &target.model;
+		let ctx = MiddlewareContext::new(model.clone(), request_kind);
+		let retry_policy = retry_policy.filter(|_| request_kind != RequestKind::VeoOperationStatus);
+
+		let mut attempt = 1;
+		let mut web_res = loop {
+			let request_target = self.request_target(target);
+			let auth_data = request_target.auth.clone();
+			let mut web_request_data = build_request(request_target)?;
+			if let Some(web_res) = self.middlewares_on_request(&ctx, &mut web_request_data)? {
+				break web_res;
+			}
+			// The RateLimiter wait can be long, so the key is counted and the request signed after it.
+			self.rate_limit_acquire(model, &web_request_data.payload).await;
+			self.record_api_key_request(model.adapter_kind, &auth_data);
+			AdapterDispatcher::sign_web_request(model, &auth_data, &mut web_request_data)?;
+
+			let WebRequestData { url, headers, payload } = web_request_data;
+			if self.config().audit_sink().is_some() {
+				*audit = Some(PendingAudit::new(request_kind, model.clone(), &url, &payload));
+			}
+			let err = match self.send_web_request(request_kind, model, &url, headers, payload).await {
+				Ok(web_res) => break web_res,
+				Err(err) => err,
+			};
+			self.report_api_key_failure(model.adapter_kind, &auth_data, &err);
+
+			let Some(delay) = retry_policy.and_then(|retry_policy| retry_policy.retry_delay(attempt, &err)) else {
+				return Err(err);
+			};
+			self.finish_audit(audit.take(), None, Some(&err));
+			tracing::debug!(model = %model, attempt, ?delay, "web call failed, retrying");
+			tokio::time::sleep(delay).await;
+			attempt += 1;
+		};
+
+		self.middlewares_on_response(&ctx, &mut web_res)?;
+		if let Some(audit) = audit.as_mut() {
+			audit.set_response(&web_res.body);
+		}
+
+		Ok(web_res)
+	}
+
+	/// The target of a request to send, with the next ApiKeyPool key (if the target has a pool key).
+	///
+	/// Called for each request, so the retries and resumes take a key too.
+	fn request_target(&self, target: &ServiceTarget) -> ServiceTarget {
+		let mut target = target.clone();
+		let adapter_kind = target.model.adapter_kind;
+		if let Some(api_key_pool) = self.config().api_key_pool()
+			&& api_key_pool.is_pool_key(adapter_kind, &target.auth)
+			&& let Some(auth_data) = api_key_pool.next_key(adapter_kind)
+		{
+			target.auth = auth_data;
+		}
+		target
+	}
+
+	/// Counts a request sent with this key in the ApiKeyPool usage, if any.
+	fn record_api_key_request(&self, adapter_kind: AdapterKind, auth_data: &AuthData) {
+		if let Some(api_key_pool) = self.config().api_key_pool() {
+			api_key_pool.record_request(adapter_kind, auth_data);
+		}
+	}
+
+	/// Reports a failed request to the ApiKeyPool, if any (for the key cooldowns).
+	fn report_api_key_failure(&self, adapter_kind: AdapterKind, auth_data: &AuthData, err: &Error) {
+		if let Some(api_key_pool) = self.config().api_key_pool() {
+			api_key_pool.report_failure(adapter_kind, auth_data, err);
+		}
+	}
+
+	/// Runs the `on_request` hooks in order; stops at the first short-circuit response.
+	fn middlewares_on_request(
+		&self,
+		ctx: &MiddlewareContext,
+		web_request_data: &mut WebRequestData,
+	) -> Result<Option<WebResponse>> {
+		for middleware in self.config().middlewares() {
+			if let Some(web_res) = middleware.on_request(ctx, web_request_data)? {
+				return Ok(Some(web_res));
+			}
+		}
+		Ok(None)
+	}
+
+	/// Runs the `on_response` hooks in reverse order.
+	fn middlewares_on_response(&self, ctx: &MiddlewareContext, web_res: &mut WebResponse) -> Result<()> {
+		for middleware in self.config().middlewares().iter().rev() {
+			middleware.on_response(ctx, web_res)?;
+		}
+		Ok(())
+	}
+
+	/// Performs a single request (a GET for the Veo operation status, a POST otherwise).
+	///
+	/// Note: The `RateLimiter` capacity is acquired by the caller, before the request is signed.
+	async fn send_web_request(
+		&self,
+		request_kind: RequestKind,
+		model: &ModelIden,
+		url: &str,
+		headers: Headers,
+		payload: Value,
+	) -> Result<WebResponse> {
+		let res = match request_kind {
+			RequestKind::VeoOperationStatus => {
+				let headers: Vec<(String, String)> = headers.into_iter().collect();
+				self.web_client().do_get(url, &headers).await
+			}
+			_ => self.web_client().do_post(url, &headers, payload).await,
+		};
+		self.rate_limit_adapt(model, &res);
+
+		res.map_err(|webc_error| Error::WebModelCall {
+			model_iden: model.clone(),
+			webc_error,
+		})
+	}
+
+	/// Waits for the `RateLimiter` capacity (if any) for this model and payload.
+	async fn rate_limit_acquire(&self, model: &ModelIden, payload: &Value) {
+		if let Some(rate_limiter) = self.config().rate_limiter() {
+			rate_limiter.acquire(model, estimate_payload_tokens(payload)).await;
+		}
+	}
+
+	/// Lets the `RateLimiter` (if any) adapt from the response (or failed status) headers.
+	fn rate_limit_adapt(&self, model: &ModelIden, res: &webc::Result<WebResponse>) {
+		let Some(rate_limiter) = self.config().rate_limiter() else {
+			return;
+		};
+		let headers = match res {
+			Ok(web_res) => &web_res.headers,
+			Err(webc::Error::ResponseFailedStatus { headers, .. }) => headers.as_ref(),
+			Err(_) => return,
+		};
+		rate_limiter.adapt_from_headers(model, headers);
+	}
+
+	/// Lets the adaptive `RateLimiter` (if any) adapt from the stream response headers, once received.
+	fn rate_limit_adapt_stream(&self, model: &ModelIden, stream_request: WebStreamRequest) -> WebStreamRequest {
+		match self.config().rate_limiter().filter(|rate_limiter| rate_limiter.is_adaptive()) {
+			Some(rate_limiter) => {
+				let (rate_limiter, model) = (rate_limiter.clone(), model.clone());
+				stream_request.with_on_response(move |headers| rate_limiter.adapt_from_headers(&model, headers))
+			}
+			None => stream_request,
+		}
 	}
 }
+
+// endregion: --- Support
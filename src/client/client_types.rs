@@ -1,6 +1,8 @@
+use crate::chat::ModelCapabilities;
 use crate::webc::WebClient;
-use crate::{ClientBuilder, ClientConfig};
-use std::sync::Arc;
+use crate::{ClientBuilder, ClientConfig, ModelIden};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
 
 /// Client for sending AI requests to supported providers.
 ///
@@ -45,6 +47,10 @@ impl Client {
 	pub(crate) fn config(&self) -> &ClientConfig {
 		&self.inner.config
 	}
+
+	pub(crate) fn live_capabilities(&self) -> &RwLock<HashMap<ModelIden, ModelCapabilities>> {
+		&self.inner.live_capabilities
+	}
 }
 
 // endregion: --- Client Getters
@@ -56,6 +62,9 @@ pub(super) struct ClientInner {
 	pub(super) web_client: WebClient,
 
 	pub(super) config: ClientConfig,
+
+	/// The capabilities given by the provider models endpoints (see `Client::load_model_capabilities`).
+	pub(super) live_capabilities: RwLock<HashMap<ModelIden, ModelCapabilities>>,
 }
 
 // endregion: --- ClientInner
@@ -1,9 +1,14 @@
 use crate::adapter::AdapterDispatcher;
-use crate::chat::ChatOptions;
+use crate::chat::{CapabilityRegistry, ChatOptions};
 use crate::client::ServiceTarget;
 use crate::embed::EmbedOptions;
 use crate::resolver::{AuthResolver, ModelMapper, ServiceTargetResolver};
-use crate::{Error, ModelIden, Result, WebConfig};
+use crate::{
+	ApiKeyPool, AuditSink, Error, MetricsHook, Middleware, ModelAlias, ModelIden, RateLimiter, ResponseCache, Result,
+	RetryPolicy, WebConfig,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
 
 /// Configuration for building and customizing a `Client`.
 #[derive(Debug, Default, Clone)]
@@ -14,6 +19,16 @@ pub struct ClientConfig {
 	pub(super) web_config: Option<WebConfig>,
 	pub(super) chat_options: Option<ChatOptions>,
 	pub(super) embed_options: Option<EmbedOptions>,
+	pub(super) retry_policy: Option<RetryPolicy>,
+	pub(super) middlewares: Vec<Arc<dyn Middleware>>,
+	pub(super) rate_limiter: Option<RateLimiter>,
+	pub(super) response_cache: Option<Arc<dyn ResponseCache>>,
+	pub(super) trace_content: bool,
+	pub(super) metrics_hook: Option<Arc<dyn MetricsHook>>,
+	pub(super) audit_sink: Option<Arc<dyn AuditSink>>,
+	pub(super) capability_registry: Option<CapabilityRegistry>,
+	pub(super) model_aliases: HashMap<String, ModelAlias>,
+	pub(super) api_key_pool: Option<ApiKeyPool>,
 }
 
 /// Chainable setters related to the ClientConfig.
@@ -21,6 +36,7 @@ impl ClientConfig {
 	/// Sets the AuthResolver.
 	///
 	/// Called before `service_target_resolver`; if set, it will receive this value.
+	/// Not called for the adapters with keys in the `ApiKeyPool` (if any), which takes precedence.
 	pub fn with_auth_resolver(mut self, auth_resolver: AuthResolver) -> Self {
 		self.auth_resolver = Some(auth_resolver);
 		self
@@ -54,6 +70,65 @@ impl ClientConfig {
 		self
 	}
 
+	/// Sets the default RetryPolicy for chat, chat stream, and embed requests.
+	///
+	/// `ChatOptions.retry_policy`, when set, takes precedence for chat requests.
+	pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.retry_policy = Some(retry_policy);
+		self
+	}
+
+	/// Sets the client-side RateLimiter, enforced before each web call.
+	pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+		self.rate_limiter = Some(rate_limiter);
+		self
+	}
+
+	/// Sets the ApiKeyPool, which takes precedence over the `AuthResolver` for the adapters it has keys for.
+	///
+	/// Each request sent takes the next key of the pool, and its failure is reported to the pool,
+	/// to put rate limited or unauthorized keys on cooldown.
+	pub fn with_api_key_pool(mut self, api_key_pool: ApiKeyPool) -> Self {
+		self.api_key_pool = Some(api_key_pool);
+		self
+	}
+
+	/// Sets the ResponseCache used for chat and embed requests.
+	pub fn with_response_cache(mut self, response_cache: impl ResponseCache + 'static) -> Self {
+		self.response_cache = Some(Arc::new(response_cache));
+		self
+	}
+
+	/// Appends a Middleware to the middleware chain (called in the order they are added).
+	pub fn with_middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+		self.middlewares.push(Arc::new(middleware));
+		self
+	}
+
+	/// Records the prompt and completion content as events of the `gen_ai` tracing spans (off by default).
+	pub fn with_trace_content(mut self, trace_content: bool) -> Self {
+		self.trace_content = trace_content;
+		self
+	}
+
+	/// Sets the MetricsHook receiving the timings (and usage) of each chat call.
+	pub fn with_metrics_hook(mut self, metrics_hook: impl MetricsHook + 'static) -> Self {
+		self.metrics_hook = Some(Arc::new(metrics_hook));
+		self
+	}
+
+	/// Sets the AuditSink receiving the record (payload, response, usage) of each provider call.
+	pub fn with_audit_sink(mut self, audit_sink: impl AuditSink + 'static) -> Self {
+		self.audit_sink = Some(Arc::new(audit_sink));
+		self
+	}
+
+	/// Sets the CapabilityRegistry of the models (default: `CapabilityRegistry::default()`, the built-in data).
+	pub fn with_capability_registry(mut self, capability_registry: CapabilityRegistry) -> Self {
+		self.capability_registry = Some(capability_registry);
+		self
+	}
+
 	/// Sets the HTTP client configuration (reqwest).
 	pub fn with_web_config(mut self, web_config: WebConfig) -> Self {
 		self.web_config = Some(web_config);
@@ -92,6 +167,70 @@ impl ClientConfig {
 	pub fn embed_options(&self) -> Option<&EmbedOptions> {
 		self.embed_options.as_ref()
 	}
+
+	/// Returns the default RetryPolicy, if set.
+	pub fn retry_policy(&self) -> Option<&RetryPolicy> {
+		self.retry_policy.as_ref()
+	}
+
+	/// Returns the RateLimiter, if set.
+	pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+		self.rate_limiter.as_ref()
+	}
+
+	/// Returns the ApiKeyPool, if set.
+	pub fn api_key_pool(&self) -> Option<&ApiKeyPool> {
+		self.api_key_pool.as_ref()
+	}
+
+	/// Returns the ResponseCache, if set.
+	pub fn response_cache(&self) -> Option<&dyn ResponseCache> {
+		self.response_cache.as_deref()
+	}
+
+	/// Returns the middleware chain, in call order.
+	pub fn middlewares(&self) -> &[Arc<dyn Middleware>] {
+		&self.middlewares
+	}
+
+	/// Returns true if the prompt and completion content are recorded on the tracing spans.
+	pub fn trace_content(&self) -> bool {
+		self.trace_content
+	}
+
+	/// Returns the MetricsHook, if set.
+	pub fn metrics_hook(&self) -> Option<&Arc<dyn MetricsHook>> {
+		self.metrics_hook.as_ref()
+	}
+
+	/// Returns the AuditSink, if set.
+	pub fn audit_sink(&self) -> Option<&Arc<dyn AuditSink>> {
+		self.audit_sink.as_ref()
+	}
+
+	/// Returns the CapabilityRegistry, if set.
+	pub fn capability_registry(&self) -> Option<&CapabilityRegistry> {
+		self.capability_registry.as_ref()
+	}
+
+	/// Returns the model alias (from `ClientConfig::from_toml` / `from_json`), if any.
+	pub fn model_alias(&self, name: &str) -> Option<&ModelAlias> {
+		self.model_aliases.get(name)
+	}
+
+	/// Returns the default ChatOptions for the model name (the alias ones, if any).
+	pub(crate) fn chat_options_for(&self, model: &str) -> Option<&ChatOptions> {
+		self.model_alias(model)
+			.and_then(|alias| alias.chat_options.as_ref())
+			.or(self.chat_options())
+	}
+
+	/// Returns the default EmbedOptions for the model name (the alias ones, if any).
+	pub(crate) fn embed_options_for(&self, model: &str) -> Option<&EmbedOptions> {
+		self.model_alias(model)
+			.and_then(|alias| alias.embed_options.as_ref())
+			.or(self.embed_options())
+	}
 }
 
 /// Resolvers
@@ -113,8 +252,13 @@ impl ClientConfig {
 			resolver_error,
 		})?;
 
-		// -- Get the auth
-		let auth = if let Some(auth) = self.auth_resolver() {
+		// -- Get the auth (the next ApiKeyPool key, not taken until a request is sent, for the adapters it has keys for)
+		let pool_key = self
+			.api_key_pool()
+			.and_then(|api_key_pool| api_key_pool.peek_key(model.adapter_kind));
+		let auth = if let Some(pool_key) = pool_key {
+			pool_key
+		} else if let Some(auth) = self.auth_resolver() {
 			// resolve async which may be async
 			auth.resolve(model.clone())
 				.await
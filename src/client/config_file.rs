@@ -0,0 +1,294 @@
+//! Declarative `ClientConfig`, loaded from a TOML or JSON document with `ClientConfig::from_toml` / `from_json`.
+//!
+//! ```toml
+//! [adapters.groq]
+//! endpoint = "https://my-gateway.example.com/groq/v1/" # with the trailing `/`
+//! api_key_env = "MY_GROQ_API_KEY"                      # AuthData::FromEnv
+//!
+//! [chat_options]
+//! temperature = 0.2
+//!
+//! [embed_options]
+//! dimensions = 512
+//!
+//! [aliases]
+//! fast = "groq::llama-3.3-70b-versatile"
+//! smart = { model = "claude-sonnet-4-5", chat_options = { max_tokens = 8000 } }
+//! ```
+//!
+//! - The adapter endpoints and API key env names are set with a `ServiceTargetResolver` and an `AuthResolver`.
+//! - The aliases are resolved by a `ModelMapper`, so `client.exec_chat("fast", ..)` uses the aliased model.
+//! - The alias options are merged over the `[chat_options]` / `[embed_options]` ones (per property),
+//!   and replace them as the client default options for that alias.
+//!
+//! Note: The `ModelMapper`, `AuthResolver`, and `ServiceTargetResolver` are set only when needed,
+//!       and setting another one afterwards replaces the one from the document.
+
+use crate::adapter::AdapterKind;
+use crate::chat::ChatOptions;
+use crate::embed::EmbedOptions;
+use crate::resolver::{AuthData, AuthResolver, Endpoint, ModelMapper, ServiceTargetResolver};
+use crate::{ClientConfig, Error, ModelIden, Result, ServiceTarget};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+// region:    --- ModelAlias
+
+/// A named model alias of a `ClientConfig` document, with its own default options.
+#[derive(Debug, Clone)]
+pub struct ModelAlias {
+	/// The aliased model (e.g., `groq::llama-3.3-70b-versatile`).
+	pub model: ModelIden,
+
+	/// The client default ChatOptions for this alias, if the alias or the document has some.
+	pub chat_options: Option<ChatOptions>,
+
+	/// The client default EmbedOptions for this alias, if the alias or the document has some.
+	pub embed_options: Option<EmbedOptions>,
+}
+
+// endregion: --- ModelAlias
+
+/// Constructors
+impl ClientConfig {
+	/// Creates a ClientConfig from a TOML document (see the `config_file` module for the format).
+	pub fn from_toml(content: &str) -> Result<Self> {
+		let config_file: ConfigFile =
+			toml::from_str(content).map_err(|err| Error::ClientConfigFile { cause: err.to_string() })?;
+		config_file.into_client_config()
+	}
+
+	/// Creates a ClientConfig from a JSON document (same format as `from_toml`).
+	pub fn from_json(content: &str) -> Result<Self> {
+		let config_file: ConfigFile =
+			serde_json::from_str(content).map_err(|err| Error::ClientConfigFile { cause: err.to_string() })?;
+		config_file.into_client_config()
+	}
+}
+
+// region:    --- Config File
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+	/// By adapter lower name (e.g., `openai`, `groq`).
+	#[serde(default)]
+	adapters: HashMap<String, AdapterSection>,
+	chat_options: Option<Value>,
+	embed_options: Option<Value>,
+	#[serde(default)]
+	aliases: HashMap<String, AliasSection>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AdapterSection {
+	endpoint: Option<String>,
+	api_key_env: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum AliasSection {
+	Model(String),
+	Table(AliasTable),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AliasTable {
+	model: String,
+	chat_options: Option<Value>,
+	embed_options: Option<Value>,
+}
+
+impl ConfigFile {
+	fn into_client_config(self) -> Result<ClientConfig> {
+		let ConfigFile {
+			adapters,
+			chat_options,
+			embed_options,
+			aliases,
+		} = self;
+
+		let mut config = ClientConfig::default();
+
+		// -- Adapters
+		let mut endpoints: HashMap<AdapterKind, Endpoint> = HashMap::new();
+		let mut api_key_envs: HashMap<AdapterKind, String> = HashMap::new();
+		for (name, section) in adapters {
+			let adapter_kind = AdapterKind::from_lower_str(&name).ok_or_else(|| Error::ClientConfigFile {
+				cause: format!("Unknown adapter '{name}' in [adapters]"),
+			})?;
+			if let Some(endpoint) = section.endpoint {
+				endpoints.insert(adapter_kind, Endpoint::from_owned(endpoint));
+			}
+			if let Some(api_key_env) = section.api_key_env {
+				api_key_envs.insert(adapter_kind, api_key_env);
+			}
+		}
+
+		if !api_key_envs.is_empty() {
+			let api_key_envs = Arc::new(api_key_envs);
+			config = config.with_auth_resolver(AuthResolver::from_resolver_fn(move |model_iden: ModelIden| {
+				Ok(api_key_envs.get(&model_iden.adapter_kind).map(AuthData::from_env))
+			}));
+		}
+
+		if !endpoints.is_empty() {
+			let endpoints = Arc::new(endpoints);
+			config = config.with_service_target_resolver(ServiceTargetResolver::from_resolver_fn(
+				move |mut service_target: ServiceTarget| {
+					if let Some(endpoint) = endpoints.get(&service_target.model.adapter_kind) {
+						service_target.endpoint = endpoint.clone();
+					}
+					Ok(service_target)
+				},
+			));
+		}
+
+		// -- Default options
+		if let Some(chat_options) = &chat_options {
+			config = config.with_chat_options(from_options_value("chat_options", chat_options.clone())?);
+		}
+		if let Some(embed_options) = &embed_options {
+			config = config.with_embed_options(from_options_value("embed_options", embed_options.clone())?);
+		}
+
+		// -- Aliases
+		let mut model_aliases = HashMap::new();
+		for (name, section) in aliases {
+			let alias = match section {
+				AliasSection::Model(model) => AliasTable {
+					model,
+					chat_options: None,
+					embed_options: None,
+				},
+				AliasSection::Table(table) => table,
+			};
+			let model = ModelIden::new(AdapterKind::from_model(&alias.model)?, alias.model);
+			let chat_options = merge_options(chat_options.as_ref(), alias.chat_options)
+				.map(|options| from_options_value(&format!("aliases.{name}.chat_options"), options))
+				.transpose()?;
+			let embed_options = merge_options(embed_options.as_ref(), alias.embed_options)
+				.map(|options| from_options_value(&format!("aliases.{name}.embed_options"), options))
+				.transpose()?;
+			model_aliases.insert(
+				name,
+				ModelAlias {
+					model,
+					chat_options,
+					embed_options,
+				},
+			);
+		}
+
+		if !model_aliases.is_empty() {
+			let alias_models: Arc<HashMap<String, ModelIden>> = Arc::new(
+				model_aliases
+					.iter()
+					.map(|(name, alias)| (name.clone(), alias.model.clone()))
+					.collect(),
+			);
+			config = config.with_model_mapper(ModelMapper::from_mapper_fn(move |model_iden: ModelIden| {
+				Ok(alias_models.get(&*model_iden.model_name).cloned().unwrap_or(model_iden))
+			}));
+		}
+		config.model_aliases = model_aliases;
+
+		Ok(config)
+	}
+}
+
+// endregion: --- Config File
+
+// region:    --- Support
+
+/// The alias options over the document ones (top-level properties), if any.
+fn merge_options(base: Option<&Value>, overlay: Option<Value>) -> Option<Value> {
+	match (base, overlay) {
+		(Some(Value::Object(base)), Some(Value::Object(overlay))) => {
+			let mut merged = base.clone();
+			merged.extend(overlay);
+			Some(Value::Object(merged))
+		}
+		(_, Some(overlay)) => Some(overlay),
+		(base, None) => base.cloned(),
+	}
+}
+
+fn from_options_value<T: DeserializeOwned>(section: &str, value: Value) -> Result<T> {
+	serde_json::from_value(value).map_err(|err| Error::ClientConfigFile {
+		cause: format!("Invalid [{section}]: {err}"),
+	})
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+
+	#[tokio::test]
+	async fn test_client_config_from_toml_aliases() -> Result<()> {
+		// -- Setup & Fixtures
+		let content = r#"
+			[adapters.groq]
+			endpoint = "https://gateway.example.com/groq/v1/"
+			api_key_env = "MY_GROQ_API_KEY"
+
+			[chat_options]
+			temperature = 0.2
+			max_tokens = 1000
+
+			[aliases]
+			fast = "groq::llama-3.3-70b-versatile"
+			smart = { model = "claude-sonnet-4-5", chat_options = { max_tokens = 8000 } }
+		"#;
+
+		// -- Exec
+		let config = ClientConfig::from_toml(content)?;
+		let fast = config
+			.resolve_service_target(ModelIden::new(AdapterKind::Ollama, "fast"))
+			.await?;
+		let smart = config.model_alias("smart").ok_or("should have 'smart'")?;
+
+		// -- Check
+		assert_eq!(fast.model.adapter_kind, AdapterKind::Groq);
+		assert_eq!(&*fast.model.model_name, "groq::llama-3.3-70b-versatile");
+		assert_eq!(fast.endpoint.base_url(), "https://gateway.example.com/groq/v1/");
+		assert!(matches!(fast.auth, AuthData::FromEnv(ref name) if name == "MY_GROQ_API_KEY"));
+		assert_eq!(smart.model.adapter_kind, AdapterKind::Anthropic);
+		let smart_options = smart.chat_options.as_ref().ok_or("should have chat options")?;
+		assert_eq!(smart_options.temperature, Some(0.2));
+		assert_eq!(smart_options.max_tokens, Some(8000));
+		assert_eq!(config.chat_options_for("fast").and_then(|o| o.max_tokens), Some(1000));
+		assert_eq!(config.chat_options_for("smart").and_then(|o| o.max_tokens), Some(8000));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_client_config_from_json_invalid() -> Result<()> {
+		// -- Exec
+		let unknown_adapter = ClientConfig::from_json(r#"{"adapters": {"nope": {"api_key_env": "NOPE"}}}"#);
+		let unknown_property = ClientConfig::from_json(r#"{"chat_option": {"temperature": 0.2}}"#);
+		let invalid_options = ClientConfig::from_json(r#"{"aliases": {"a": {"model": "gpt-4o", "chat_options": 1}}}"#);
+
+		// -- Check
+		assert!(matches!(unknown_adapter, Err(Error::ClientConfigFile { .. })));
+		assert!(matches!(unknown_property, Err(Error::ClientConfigFile { .. })));
+		assert!(matches!(invalid_options, Err(Error::ClientConfigFile { .. })));
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,271 @@
+//! Concurrent execution of many independent chat or embed requests.
+//! - `Client::exec_chat_many(..)` / `Client::exec_embed_many(..)` return the results in the job order.
+//! - `Client::exec_chat_many_stream(..)` / `Client::exec_embed_many_stream(..)` yield `(job_index, result)` as the jobs complete.
+//!
+//! Each job goes through the regular `exec_chat` / `exec_embed` call, so the configured rate limits, retries,
+//! fallbacks, and response cache apply per job. A failed job does not abort the others.
+
+use crate::chat::{ChatOptions, ChatRequest, ChatResponse};
+use crate::embed::{EmbedOptions, EmbedRequest, EmbedResponse};
+use crate::{Client, Result};
+use futures::{Stream, StreamExt};
+use std::sync::Arc;
+
+// region:    --- Jobs
+
+/// One chat request of `Client::exec_chat_many(..)`.
+#[derive(Debug, Clone)]
+pub struct ChatJob {
+	pub model: String,
+	pub chat_req: ChatRequest,
+	pub options: Option<ChatOptions>,
+}
+
+impl ChatJob {
+	pub fn new(model: impl Into<String>, chat_req: ChatRequest) -> Self {
+		Self {
+			model: model.into(),
+			chat_req,
+			options: None,
+		}
+	}
+
+	pub fn with_options(mut self, options: ChatOptions) -> Self {
+		self.options = Some(options);
+		self
+	}
+}
+
+impl<S: Into<String>> From<(S, ChatRequest)> for ChatJob {
+	fn from((model, chat_req): (S, ChatRequest)) -> Self {
+		Self::new(model, chat_req)
+	}
+}
+
+impl<S: Into<String>> From<(S, ChatRequest, Option<ChatOptions>)> for ChatJob {
+	fn from((model, chat_req, options): (S, ChatRequest, Option<ChatOptions>)) -> Self {
+		Self {
+			model: model.into(),
+			chat_req,
+			options,
+		}
+	}
+}
+
+/// One embed request of `Client::exec_embed_many(..)`.
+#[derive(Debug, Clone)]
+pub struct EmbedJob {
+	pub model: String,
+	pub embed_req: EmbedRequest,
+	pub options: Option<EmbedOptions>,
+}
+
+impl EmbedJob {
+	pub fn new(model: impl Into<String>, embed_req: EmbedRequest) -> Self {
+		Self {
+			model: model.into(),
+			embed_req,
+			options: None,
+		}
+	}
+
+	pub fn with_options(mut self, options: EmbedOptions) -> Self {
+		self.options = Some(options);
+		self
+	}
+}
+
+impl<S: Into<String>> From<(S, EmbedRequest)> for EmbedJob {
+	fn from((model, embed_req): (S, EmbedRequest)) -> Self {
+		Self::new(model, embed_req)
+	}
+}
+
+impl<S: Into<String>> From<(S, EmbedRequest, Option<EmbedOptions>)> for EmbedJob {
+	fn from((model, embed_req, options): (S, EmbedRequest, Option<EmbedOptions>)) -> Self {
+		Self {
+			model: model.into(),
+			embed_req,
+			options,
+		}
+	}
+}
+
+// endregion: --- Jobs
+
+// region:    --- ExecManyOptions
+
+/// Progress of a `exec_..._many` call, given to the `on_progress` callback after each job completes.
+#[derive(Debug, Clone)]
+pub struct ExecProgress {
+	/// Index (in the job order) of the job that just completed.
+	pub index: usize,
+
+	/// Number of completed jobs (including the failed ones).
+	pub completed: usize,
+
+	/// Number of failed jobs.
+	pub failed: usize,
+
+	/// Total number of jobs.
+	pub total: usize,
+}
+
+type ProgressFn = Arc<dyn Fn(&ExecProgress) + Send + Sync>;
+
+/// Options of the `Client::exec_..._many` calls.
+#[derive(Clone, Default)]
+pub struct ExecManyOptions {
+	/// Maximum number of jobs in flight (default `DEFAULT_CONCURRENCY`).
+	pub concurrency: Option<usize>,
+
+	/// Called after each job completes (in completion order).
+	pub on_progress: Option<ProgressFn>,
+}
+
+impl ExecManyOptions {
+	pub const DEFAULT_CONCURRENCY: usize = 8;
+
+	/// Sets the maximum number of jobs in flight (`0` is treated as `1`).
+	pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+		self.concurrency = Some(concurrency);
+		self
+	}
+
+	/// Sets the callback called after each job completes.
+	pub fn with_on_progress(mut self, on_progress: impl Fn(&ExecProgress) + Send + Sync + 'static) -> Self {
+		self.on_progress = Some(Arc::new(on_progress));
+		self
+	}
+
+	fn concurrency(&self) -> usize {
+		self.concurrency.unwrap_or(Self::DEFAULT_CONCURRENCY).max(1)
+	}
+}
+
+impl std::fmt::Debug for ExecManyOptions {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("ExecManyOptions")
+			.field("concurrency", &self.concurrency)
+			.field("on_progress", &self.on_progress.as_ref().map(|_| "Fn"))
+			.finish()
+	}
+}
+
+// endregion: --- ExecManyOptions
+
+// region:    --- Client Exec Many
+
+impl Client {
+	/// Executes the chat jobs with bounded concurrency and returns their results in the job order.
+	pub async fn exec_chat_many<I, J>(&self, jobs: I, options: Option<&ExecManyOptions>) -> Vec<Result<ChatResponse>>
+	where
+		I: IntoIterator<Item = J>,
+		J: Into<ChatJob>,
+	{
+		collect_in_order(self.exec_chat_many_stream(jobs, options)).await
+	}
+
+	/// Executes the chat jobs with bounded concurrency and yields `(job_index, result)` as they complete.
+	pub fn exec_chat_many_stream<I, J>(
+		&self,
+		jobs: I,
+		options: Option<&ExecManyOptions>,
+	) -> impl Stream<Item = (usize, Result<ChatResponse>)> + Send + 'static
+	where
+		I: IntoIterator<Item = J>,
+		J: Into<ChatJob>,
+	{
+		let client = self.clone();
+		let jobs: Vec<ChatJob> = jobs.into_iter().map(Into::into).collect();
+		exec_many(jobs, options.cloned().unwrap_or_default(), move |job| {
+			exec_chat_job(client.clone(), job)
+		})
+	}
+
+	/// Executes the embed jobs with bounded concurrency and returns their results in the job order.
+	pub async fn exec_embed_many<I, J>(&self, jobs: I, options: Option<&ExecManyOptions>) -> Vec<Result<EmbedResponse>>
+	where
+		I: IntoIterator<Item = J>,
+		J: Into<EmbedJob>,
+	{
+		collect_in_order(self.exec_embed_many_stream(jobs, options)).await
+	}
+
+	/// Executes the embed jobs with bounded concurrency and yields `(job_index, result)` as they complete.
+	pub fn exec_embed_many_stream<I, J>(
+		&self,
+		jobs: I,
+		options: Option<&ExecManyOptions>,
+	) -> impl Stream<Item = (usize, Result<EmbedResponse>)> + Send + 'static
+	where
+		I: IntoIterator<Item = J>,
+		J: Into<EmbedJob>,
+	{
+		let client = self.clone();
+		let jobs: Vec<EmbedJob> = jobs.into_iter().map(Into::into).collect();
+		exec_many(jobs, options.cloned().unwrap_or_default(), move |job| {
+			exec_embed_job(client.clone(), job)
+		})
+	}
+}
+
+// endregion: --- Client Exec Many
+
+// region:    --- Support
+
+async fn exec_chat_job(client: Client, job: ChatJob) -> Result<ChatResponse> {
+	client.exec_chat(&job.model, job.chat_req, job.options.as_ref()).await
+}
+
+async fn exec_embed_job(client: Client, job: EmbedJob) -> Result<EmbedResponse> {
+	client.exec_embed(&job.model, job.embed_req, job.options.as_ref()).await
+}
+
+fn exec_many<J, T, F, Fut>(
+	jobs: Vec<J>,
+	options: ExecManyOptions,
+	mut exec_job: F,
+) -> impl Stream<Item = (usize, Result<T>)> + Send + 'static
+where
+	J: Send + 'static,
+	T: Send + 'static,
+	F: FnMut(J) -> Fut + Send + 'static,
+	Fut: Future<Output = Result<T>> + Send + 'static,
+{
+	let total = jobs.len();
+	let concurrency = options.concurrency();
+	let on_progress = options.on_progress;
+	let mut completed = 0;
+	let mut failed = 0;
+
+	futures::stream::iter(jobs.into_iter().enumerate())
+		.map(move |(index, job)| {
+			let fut = exec_job(job);
+			async move { (index, fut.await) }
+		})
+		.buffer_unordered(concurrency)
+		.map(move |(index, res)| {
+			completed += 1;
+			if res.is_err() {
+				failed += 1;
+			}
+			if let Some(on_progress) = on_progress.as_ref() {
+				on_progress(&ExecProgress {
+					index,
+					completed,
+					failed,
+					total,
+				});
+			}
+			(index, res)
+		})
+}
+
+async fn collect_in_order<T>(stream: impl Stream<Item = (usize, Result<T>)>) -> Vec<Result<T>> {
+	let mut results: Vec<(usize, Result<T>)> = stream.collect().await;
+	results.sort_by_key(|(index, _)| *index);
+	results.into_iter().map(|(_, res)| res).collect()
+}
+
+// endregion: --- Support
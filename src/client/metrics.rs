@@ -0,0 +1,203 @@
+//! Latency and throughput metrics of the chat calls.
+//!
+//! Every `exec_chat` and `exec_chat_stream` call is timed from its start (retries, fallbacks, and resumes included),
+//! and the `ChatTimings` are set on `ChatResponse.timings` and on the stream `StreamEnd.timings`.
+//!
+//! A `MetricsHook` (see `ClientConfig::with_metrics_hook(..)`) receives them once per call,
+//! e.g., to forward them to Prometheus or StatsD.
+
+use crate::adapter::inter_stream::InterStreamEvent;
+use crate::chat::{ChatResponse, ChatStream, ChatTimings, Usage};
+use crate::{Error, ModelIden};
+use futures::StreamExt;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::Instant;
+
+// region:    --- MetricsHook
+
+/// A sink for the metrics of the chat calls.
+pub trait MetricsHook: Send + Sync {
+	/// Called once per chat call: when the response is returned, when the stream ends, or when the call fails.
+	fn on_chat_metrics(&self, metrics: &ChatMetrics);
+}
+
+impl std::fmt::Debug for dyn MetricsHook {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "MetricsHook")
+	}
+}
+
+// endregion: --- MetricsHook
+
+// region:    --- ChatMetrics
+
+/// The metrics of one chat call.
+#[derive(Debug, Clone)]
+pub struct ChatMetrics {
+	/// The resolved model identifier of the call (the one which answered when fallback models are set).
+	pub model_iden: ModelIden,
+
+	/// True for `exec_chat_stream`.
+	pub stream: bool,
+
+	/// True when served from the client `ResponseCache`.
+	pub cached: bool,
+
+	/// The call timings (for a failed call, the total is the time until the error).
+	pub timings: ChatTimings,
+
+	/// The token usage (for a stream, only when captured with `ChatOptions.capture_usage`).
+	pub usage: Option<Usage>,
+
+	/// The error message, when the call failed.
+	pub error: Option<String>,
+}
+
+// endregion: --- ChatMetrics
+
+// region:    --- Support
+
+/// Set the timings of a non-streaming call on its response and report them to the hook.
+pub(crate) fn record_chat_metrics(
+	hook: Option<&dyn MetricsHook>,
+	model_iden: impl FnOnce() -> Option<ModelIden>,
+	started_at: Instant,
+	res: &mut crate::Result<ChatResponse>,
+) {
+	let total = started_at.elapsed();
+	match res {
+		Ok(chat_res) => {
+			let timings = ChatTimings::new(total, None, None, chat_res.usage.completion_tokens);
+			if let Some(hook) = hook {
+				hook.on_chat_metrics(&ChatMetrics {
+					model_iden: chat_res.model_iden.clone(),
+					stream: false,
+					cached: chat_res.cached,
+					timings: timings.clone(),
+					usage: Some(chat_res.usage.clone()),
+					error: None,
+				});
+			}
+			chat_res.timings = Some(timings);
+		}
+		Err(err) => {
+			if let Some(hook) = hook
+				&& let Some(model_iden) = model_iden()
+			{
+				hook.on_chat_metrics(&error_metrics(model_iden, false, total, err));
+			}
+		}
+	}
+}
+
+/// Report a failed stream call (the stream was not created) to the hook.
+pub(crate) fn record_chat_stream_error(
+	hook: Option<&dyn MetricsHook>,
+	model_iden: impl FnOnce() -> Option<ModelIden>,
+	started_at: Instant,
+	err: &Error,
+) {
+	if let Some(hook) = hook
+		&& let Some(model_iden) = model_iden()
+	{
+		hook.on_chat_metrics(&error_metrics(model_iden, true, started_at.elapsed(), err));
+	}
+}
+
+fn error_metrics(model_iden: ModelIden, stream: bool, total: Duration, err: &Error) -> ChatMetrics {
+	ChatMetrics {
+		model_iden,
+		stream,
+		cached: false,
+		timings: ChatTimings::new(total, None, None, None),
+		usage: None,
+		error: Some(err.to_string()),
+	}
+}
+
+// endregion: --- Support
+
+// region:    --- Stream
+
+/// What `time_chat_stream` needs about the call.
+pub(crate) struct StreamTiming {
+	pub model_iden: ModelIden,
+	pub started_at: Instant,
+	pub cached: bool,
+	pub hook: Option<Arc<dyn MetricsHook>>,
+}
+
+struct TimingState {
+	timing: StreamTiming,
+	first_token: Option<Duration>,
+	first_reasoning_token: Option<Duration>,
+	// Once the end or an error has been reported.
+	done: bool,
+}
+
+/// Record the first token times of the stream, and set the timings on its end (reported to the hook with an error too).
+pub(crate) fn time_chat_stream(stream: ChatStream, timing: StreamTiming) -> ChatStream {
+	let state = TimingState {
+		timing,
+		first_token: None,
+		first_reasoning_token: None,
+		done: false,
+	};
+
+	let inter_stream = futures::stream::unfold((stream, state), |(stream, mut state)| async move {
+		let mut inter_stream = stream.into_inter_stream();
+		let mut event = inter_stream.next().await?;
+		let elapsed = state.timing.started_at.elapsed();
+
+		match &mut event {
+			Ok(InterStreamEvent::Chunk(_)) => {
+				state.first_token.get_or_insert(elapsed);
+			}
+			Ok(InterStreamEvent::ReasoningChunk(_)) => {
+				state.first_reasoning_token.get_or_insert(elapsed);
+			}
+			Ok(InterStreamEvent::End(end)) => {
+				let completion_tokens = end.captured_usage.as_ref().and_then(|usage| usage.completion_tokens);
+				let timings = ChatTimings::new(
+					elapsed,
+					state.first_token,
+					state.first_reasoning_token,
+					completion_tokens,
+				);
+				if let Some(hook) = state.timing.hook.as_deref()
+					&& !state.done
+				{
+					hook.on_chat_metrics(&ChatMetrics {
+						model_iden: state.timing.model_iden.clone(),
+						stream: true,
+						cached: state.timing.cached,
+						timings: timings.clone(),
+						usage: end.captured_usage.clone(),
+						error: None,
+					});
+				}
+				end.timings = Some(timings);
+				state.done = true;
+			}
+			Err(err) => {
+				if let Some(hook) = state.timing.hook.as_deref()
+					&& !state.done
+				{
+					let mut metrics = error_metrics(state.timing.model_iden.clone(), true, elapsed, err);
+					metrics.timings.time_to_first_token = state.first_token;
+					metrics.timings.time_to_first_reasoning_token = state.first_reasoning_token;
+					hook.on_chat_metrics(&metrics);
+				}
+				state.done = true;
+			}
+			_ => (),
+		}
+
+		Some((event, (ChatStream::new(inter_stream), state)))
+	});
+
+	ChatStream::from_inter_stream(inter_stream)
+}
+
+// endregion: --- Stream
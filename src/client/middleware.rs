@@ -0,0 +1,112 @@
+//! Middleware lets users observe and alter the web calls made by the `Client`.
+//!
+//! Middlewares are registered in order with `ClientBuilder::with_middleware(..)` and are called:
+//! - `on_request`: in registration order, with the `WebRequestData` (url, headers, payload) before it is sent
+//!   (for each request, so again for the retries and the chat stream resumes).
+//!   The requests signed over their full content (e.g., Bedrock SigV4) are signed after, so the changes are signed too.
+//!   Returning `Some(WebResponse)` short-circuits the call (remaining `on_request` and the HTTP call are skipped).
+//! - `on_response`: in reverse registration order, with the `WebResponse` (sent or short-circuited).
+//! - `on_stream_event`: in registration order, for each `ChatStreamEvent` of a chat stream.
+//!
+//! Applies to chat, chat stream, embed, Imagen, and Veo calls.
+
+use crate::adapter::WebRequestData;
+use crate::chat::ChatStreamEvent;
+use crate::webc::WebResponse;
+use crate::{ModelIden, Result};
+use serde::Serialize;
+use std::sync::Arc;
+
+// region:    --- Middleware
+
+/// A hook around the web calls of the `Client`.
+///
+/// All methods have a default pass-through implementation, so only the needed ones must be implemented.
+pub trait Middleware: Send + Sync {
+	/// Called before the request is sent.
+	///
+	/// Return `Ok(Some(web_response))` to short-circuit the call (e.g., serve from a cache).
+	/// For chat streams, a short-circuited response is parsed as a chat response and replayed as a stream.
+	fn on_request(&self, ctx: &MiddlewareContext, request: &mut WebRequestData) -> Result<Option<WebResponse>> {
+		let _ = (ctx, request);
+		Ok(None)
+	}
+
+	/// Called with the response of a non-streaming call, before it is parsed by the adapter.
+	fn on_response(&self, ctx: &MiddlewareContext, response: &mut WebResponse) -> Result<()> {
+		let _ = (ctx, response);
+		Ok(())
+	}
+
+	/// Called for each event of a chat stream, before it is returned to the caller.
+	fn on_stream_event(&self, ctx: &MiddlewareContext, event: &mut ChatStreamEvent) -> Result<()> {
+		let _ = (ctx, event);
+		Ok(())
+	}
+}
+
+impl std::fmt::Debug for dyn Middleware {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Middleware")
+	}
+}
+
+// endregion: --- Middleware
+
+// region:    --- MiddlewareContext
+
+/// Information about the call a middleware is invoked for.
+#[derive(Debug, Clone)]
+pub struct MiddlewareContext {
+	/// The resolved model identifier of the call.
+	pub model_iden: ModelIden,
+
+	/// The kind of call.
+	pub request_kind: RequestKind,
+}
+
+impl MiddlewareContext {
+	pub(crate) fn new(model_iden: ModelIden, request_kind: RequestKind) -> Self {
+		Self {
+			model_iden,
+			request_kind,
+		}
+	}
+}
+
+/// The kind of `Client` call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum RequestKind {
+	Chat,
+	ChatStream,
+	Embed,
+	ImagenGenerateImages,
+	VeoGenerateVideos,
+	VeoOperationStatus,
+}
+
+// endregion: --- MiddlewareContext
+
+// region:    --- Stream Middlewares
+
+/// The middleware chain attached to a `ChatStream`.
+#[derive(Debug, Clone)]
+pub(crate) struct StreamMiddlewares {
+	ctx: MiddlewareContext,
+	middlewares: Vec<Arc<dyn Middleware>>,
+}
+
+impl StreamMiddlewares {
+	pub(crate) fn new(ctx: MiddlewareContext, middlewares: Vec<Arc<dyn Middleware>>) -> Self {
+		Self { ctx, middlewares }
+	}
+
+	pub(crate) fn on_stream_event(&self, event: &mut ChatStreamEvent) -> Result<()> {
+		for middleware in self.middlewares.iter() {
+			middleware.on_stream_event(&self.ctx, event)?;
+		}
+		Ok(())
+	}
+}
+
+// endregion: --- Stream Middlewares
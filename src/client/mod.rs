@@ -5,18 +5,46 @@
 
 // region:    --- Modules
 
+mod api_key_pool;
+mod audit;
 mod builder;
+mod cancellation;
+mod client_batch;
+mod client_capabilities;
 mod client_impl;
 mod client_types;
 mod config;
+mod config_file;
+mod exec_many;
 mod headers;
+mod metrics;
+mod middleware;
+mod rate_limiter;
+mod request_preview;
+mod request_validation;
+mod response_cache;
+mod resume_policy;
+mod retry_policy;
 mod service_target;
+mod telemetry;
 mod web_config;
 
+pub use api_key_pool::*;
+pub use audit::*;
 pub use builder::*;
+pub use cancellation::*;
 pub use client_types::*;
 pub use config::*;
+pub use config_file::*;
+pub use exec_many::*;
 pub use headers::*;
+pub use metrics::*;
+pub use middleware::*;
+pub use rate_limiter::*;
+pub use request_preview::*;
+pub use response_cache::*;
+pub use resume_policy::*;
+pub use retry_policy::*;
 pub use service_target::*;
 pub use web_config::*;
 
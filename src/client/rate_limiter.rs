@@ -0,0 +1,491 @@
+//! RateLimiter is a client-side token-bucket limiter, enforced by the `Client` before a call is dispatched.
+//! - Limits can be set per `AdapterKind` and per `ModelIden` (requests per minute and estimated tokens per minute).
+//! - When both apply to a call, both must have capacity.
+//! - Calls wait asynchronously for capacity rather than failing.
+//! - When adaptive, the buckets are lowered from the `x-ratelimit-remaining-*` (OpenAI, Groq) and
+//!   `anthropic-ratelimit-*-remaining` (Anthropic) response headers, of the chat streams too. A model without
+//!   a limit gets a bucket from these headers (with the `*-limit-*` ones as the per minute limits), so it is
+//!   also throttled.
+//! - Groq `x-ratelimit-*-requests` headers are per day, so only its token (per minute) headers are used.
+//!
+//! Note: Tokens are estimated from the request payload size (about 4 characters per token),
+//!       as the actual count is only known once the response is received.
+
+use crate::ModelIden;
+use crate::adapter::AdapterKind;
+use crate::client::retry_policy::parse_reset_duration;
+use reqwest::header::HeaderMap;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// region:    --- RateLimit
+
+/// The limits of one rate limiter bucket.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RateLimit {
+	/// Maximum requests per minute.
+	pub requests_per_minute: Option<u32>,
+
+	/// Maximum (estimated) tokens per minute.
+	pub tokens_per_minute: Option<u32>,
+}
+
+/// Constructors
+impl RateLimit {
+	/// Create a limit with a requests-per-minute value.
+	pub fn from_rpm(requests_per_minute: u32) -> Self {
+		Self {
+			requests_per_minute: Some(requests_per_minute),
+			tokens_per_minute: None,
+		}
+	}
+}
+
+/// Chainable Setters
+impl RateLimit {
+	/// Set the maximum requests per minute.
+	pub fn with_rpm(mut self, requests_per_minute: u32) -> Self {
+		self.requests_per_minute = Some(requests_per_minute);
+		self
+	}
+
+	/// Set the maximum (estimated) tokens per minute.
+	pub fn with_tpm(mut self, tokens_per_minute: u32) -> Self {
+		self.tokens_per_minute = Some(tokens_per_minute);
+		self
+	}
+}
+
+// endregion: --- RateLimit
+
+// region:    --- RateLimiter
+
+/// Client-side rate limiter (see module documentation).
+///
+/// Clones share the same bucket state.
+#[derive(Debug, Clone, Default)]
+pub struct RateLimiter {
+	adapter_limits: HashMap<AdapterKind, RateLimit>,
+	model_limits: HashMap<ModelIden, RateLimit>,
+	adaptive: bool,
+	state: Arc<Mutex<RateLimiterState>>,
+}
+
+/// Chainable Setters
+impl RateLimiter {
+	/// Create a new RateLimiter without any limit.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Set the limit for all the models of an adapter.
+	pub fn with_adapter_limit(mut self, adapter_kind: AdapterKind, limit: RateLimit) -> Self {
+		self.adapter_limits.insert(adapter_kind, limit);
+		self
+	}
+
+	/// Set the limit for a given model (matched on the resolved `ModelIden`).
+	pub fn with_model_limit(mut self, model_iden: ModelIden, limit: RateLimit) -> Self {
+		self.model_limits.insert(model_iden, limit);
+		self
+	}
+
+	/// Enable or disable the adaptation from the provider rate-limit response headers.
+	pub fn with_adaptive(mut self, adaptive: bool) -> Self {
+		self.adaptive = adaptive;
+		self
+	}
+}
+
+/// Getters
+impl RateLimiter {
+	/// Returns the limit set for the adapter, if any.
+	pub fn adapter_limit(&self, adapter_kind: AdapterKind) -> Option<&RateLimit> {
+		self.adapter_limits.get(&adapter_kind)
+	}
+
+	/// Returns the limit set for the model, if any.
+	pub fn model_limit(&self, model_iden: &ModelIden) -> Option<&RateLimit> {
+		self.model_limits.get(model_iden)
+	}
+
+	/// Returns true if the limiter adapts from the response headers.
+	pub fn is_adaptive(&self) -> bool {
+		self.adaptive
+	}
+}
+
+/// Crate Methods
+impl RateLimiter {
+	/// Wait until the adapter and model buckets have capacity for one request of `estimated_tokens`,
+	/// then consume it.
+	pub(crate) async fn acquire(&self, model_iden: &ModelIden, estimated_tokens: u32) {
+		loop {
+			let wait = {
+				let mut state = self.lock_state();
+				let now = Instant::now();
+				let keys = self.bucket_keys(&state, model_iden);
+
+				let wait = keys
+					.iter()
+					.filter_map(|key| self.bucket(&mut state, key, now).map(|b| b.wait_time(estimated_tokens, now)))
+					.max()
+					.unwrap_or_default();
+
+				if wait.is_zero() {
+					for key in keys.iter() {
+						if let Some(bucket) = self.bucket(&mut state, key, now) {
+							bucket.consume(estimated_tokens);
+						}
+					}
+				}
+				wait
+			};
+
+			if wait.is_zero() {
+				return;
+			}
+			tracing::debug!(model = %model_iden, ?wait, "rate limited, waiting");
+			tokio::time::sleep(wait).await;
+		}
+	}
+
+	/// Lower the model bucket from the provider rate-limit headers (when adaptive).
+	///
+	/// Provider limits are per model, so a model without a limit gets one from the headers.
+	pub(crate) fn adapt_from_headers(&self, model_iden: &ModelIden, headers: &HeaderMap) {
+		if !self.adaptive {
+			return;
+		}
+		let Some(remaining) = RemainingLimits::from_headers(model_iden.adapter_kind, headers) else {
+			return;
+		};
+
+		let mut state = self.lock_state();
+		let now = Instant::now();
+		if !self.model_limits.contains_key(model_iden) && !state.header_limits.contains_key(model_iden) {
+			tracing::debug!(model = %model_iden, "rate limit bucket created from the response headers");
+			state.header_limits.insert(model_iden.clone(), remaining.limit());
+		}
+		let key = BucketKey::Model(model_iden.clone());
+		if let Some(bucket) = self.bucket(&mut state, &key, now) {
+			bucket.adapt(&remaining, now);
+		}
+	}
+}
+
+/// Private Support
+impl RateLimiter {
+	fn lock_state(&self) -> std::sync::MutexGuard<'_, RateLimiterState> {
+		// The state is only plain numbers, so a poisoned lock is still usable.
+		self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+	}
+
+	/// The keys of the buckets that apply to this model, from the least to the most specific.
+	fn bucket_keys(&self, state: &RateLimiterState, model_iden: &ModelIden) -> Vec<BucketKey> {
+		let mut keys = Vec::new();
+		if self.adapter_limits.contains_key(&model_iden.adapter_kind) {
+			keys.push(BucketKey::Adapter(model_iden.adapter_kind));
+		}
+		if self.model_limits.contains_key(model_iden) || state.header_limits.contains_key(model_iden) {
+			keys.push(BucketKey::Model(model_iden.clone()));
+		}
+		keys
+	}
+
+	fn bucket<'a>(&self, state: &'a mut RateLimiterState, key: &BucketKey, now: Instant) -> Option<&'a mut Bucket> {
+		let limit = match key {
+			BucketKey::Adapter(adapter_kind) => self.adapter_limits.get(adapter_kind).copied()?,
+			BucketKey::Model(model_iden) => self
+				.model_limits
+				.get(model_iden)
+				.or_else(|| state.header_limits.get(model_iden))
+				.copied()?,
+		};
+		let bucket = state.buckets.entry(key.clone()).or_insert_with(|| Bucket::new(&limit, now));
+		bucket.refill(now);
+		Some(bucket)
+	}
+}
+
+// endregion: --- RateLimiter
+
+// region:    --- Bucket
+
+#[derive(Debug, Default)]
+struct RateLimiterState {
+	buckets: HashMap<BucketKey, Bucket>,
+	/// The limits of the models without a set limit, from the first rate-limit response headers.
+	header_limits: HashMap<ModelIden, RateLimit>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum BucketKey {
+	Adapter(AdapterKind),
+	Model(ModelIden),
+}
+
+/// Request and token buckets for one key.
+#[derive(Debug)]
+struct Bucket {
+	requests: Option<TokenBucket>,
+	tokens: Option<TokenBucket>,
+	/// Set when the provider reported an exhausted limit with a reset time.
+	blocked_until: Option<Instant>,
+}
+
+impl Bucket {
+	fn new(limit: &RateLimit, now: Instant) -> Self {
+		Self {
+			requests: limit.requests_per_minute.map(|rpm| TokenBucket::per_minute(rpm, now)),
+			tokens: limit.tokens_per_minute.map(|tpm| TokenBucket::per_minute(tpm, now)),
+			blocked_until: None,
+		}
+	}
+
+	fn refill(&mut self, now: Instant) {
+		if let Some(requests) = self.requests.as_mut() {
+			requests.refill(now);
+		}
+		if let Some(tokens) = self.tokens.as_mut() {
+			tokens.refill(now);
+		}
+		if self.blocked_until.is_some_and(|until| until <= now) {
+			self.blocked_until = None;
+		}
+	}
+
+	fn wait_time(&self, estimated_tokens: u32, now: Instant) -> Duration {
+		let blocked = self
+			.blocked_until
+			.map(|until| until.saturating_duration_since(now))
+			.unwrap_or_default();
+		let requests = self.requests.as_ref().map(|b| b.wait_time(1.)).unwrap_or_default();
+		let tokens = self
+			.tokens
+			.as_ref()
+			.map(|b| b.wait_time(estimated_tokens as f64))
+			.unwrap_or_default();
+		blocked.max(requests).max(tokens)
+	}
+
+	fn consume(&mut self, estimated_tokens: u32) {
+		if let Some(requests) = self.requests.as_mut() {
+			requests.consume(1.);
+		}
+		if let Some(tokens) = self.tokens.as_mut() {
+			tokens.consume(estimated_tokens as f64);
+		}
+	}
+
+	fn adapt(&mut self, remaining: &RemainingLimits, now: Instant) {
+		if let (Some(bucket), Some(value)) = (self.requests.as_mut(), remaining.requests) {
+			bucket.available = bucket.available.min(value);
+		}
+		if let (Some(bucket), Some(value)) = (self.tokens.as_mut(), remaining.tokens) {
+			bucket.available = bucket.available.min(value);
+		}
+
+		let exhausted = remaining.requests.is_some_and(|v| v < 1.) || remaining.tokens.is_some_and(|v| v < 1.);
+		if exhausted && let Some(reset) = remaining.reset {
+			self.blocked_until = Some(now + reset);
+		}
+	}
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+	capacity: f64,
+	available: f64,
+	refill_per_sec: f64,
+	last_refill: Instant,
+}
+
+impl TokenBucket {
+	fn per_minute(per_minute: u32, now: Instant) -> Self {
+		let capacity = per_minute as f64;
+		Self {
+			capacity,
+			available: capacity,
+			refill_per_sec: capacity / 60.,
+			last_refill: now,
+		}
+	}
+
+	fn refill(&mut self, now: Instant) {
+		let elapsed = now.saturating_duration_since(self.last_refill).as_secs_f64();
+		self.available = (self.available + elapsed * self.refill_per_sec).min(self.capacity);
+		self.last_refill = now;
+	}
+
+	fn wait_time(&self, amount: f64) -> Duration {
+		// A request larger than the bucket only waits for a full bucket.
+		let amount = amount.min(self.capacity);
+		if self.available >= amount || self.refill_per_sec <= 0. {
+			return Duration::ZERO;
+		}
+		Duration::from_secs_f64((amount - self.available) / self.refill_per_sec)
+	}
+
+	fn consume(&mut self, amount: f64) {
+		self.available -= amount.min(self.capacity);
+	}
+}
+
+// endregion: --- Bucket
+
+// region:    --- Support
+
+/// The remaining limits reported by the provider.
+#[derive(Debug, Default)]
+struct RemainingLimits {
+	requests: Option<f64>,
+	tokens: Option<f64>,
+	reset: Option<Duration>,
+	limit_requests: Option<f64>,
+	limit_tokens: Option<f64>,
+}
+
+impl RemainingLimits {
+	fn from_headers(adapter_kind: AdapterKind, headers: &HeaderMap) -> Option<Self> {
+		// Groq request headers are per day (RPD), so they do not fit the per minute request bucket.
+		let per_minute_requests = adapter_kind != AdapterKind::Groq;
+		let header_num = |names: &[&str]| {
+			names
+				.iter()
+				.find_map(|name| headers.get(*name).and_then(|v| v.to_str().ok()))
+				.and_then(|v| v.trim().parse::<f64>().ok())
+		};
+		let requests_num = |names: &[&str]| if per_minute_requests { header_num(names) } else { None };
+
+		let requests = requests_num(&["x-ratelimit-remaining-requests", "anthropic-ratelimit-requests-remaining"]);
+		let tokens = header_num(&["x-ratelimit-remaining-tokens", "anthropic-ratelimit-tokens-remaining"]);
+		if requests.is_none() && tokens.is_none() {
+			return None;
+		}
+
+		// Anthropic reset headers are RFC 3339 timestamps, so only the OpenAI/Groq durations are used.
+		let reset_names: &[&str] = if per_minute_requests {
+			&["x-ratelimit-reset-requests", "x-ratelimit-reset-tokens"]
+		} else {
+			&["x-ratelimit-reset-tokens"]
+		};
+		let reset = reset_names
+			.iter()
+			.filter_map(|name| headers.get(*name).and_then(|v| v.to_str().ok()))
+			.filter_map(parse_reset_duration)
+			.max();
+
+		let limit_requests = requests_num(&["x-ratelimit-limit-requests", "anthropic-ratelimit-requests-limit"]);
+		let limit_tokens = header_num(&["x-ratelimit-limit-tokens", "anthropic-ratelimit-tokens-limit"]);
+
+		Some(Self {
+			requests,
+			tokens,
+			reset,
+			limit_requests,
+			limit_tokens,
+		})
+	}
+
+	/// The per minute limit (the remaining values, at least 1, when the provider does not send the limits).
+	fn limit(&self) -> RateLimit {
+		let per_minute = |limit: Option<f64>, remaining: Option<f64>| {
+			limit.or(remaining).map(|value| value.clamp(1., u32::MAX as f64) as u32)
+		};
+		RateLimit {
+			requests_per_minute: per_minute(self.limit_requests, self.requests),
+			tokens_per_minute: per_minute(self.limit_tokens, self.tokens),
+		}
+	}
+}
+
+/// Rough token estimate of a JSON payload (about 4 characters per token).
+pub(crate) fn estimate_payload_tokens(payload: &serde_json::Value) -> u32 {
+	let len = payload.to_string().len();
+	(len / 4).min(u32::MAX as usize) as u32
+}
+
+// endregion: --- Support
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use reqwest::header::HeaderValue;
+
+	#[test]
+	fn test_bucket_wait_and_adapt() {
+		let now = Instant::now();
+		let mut bucket = Bucket::new(&RateLimit::from_rpm(60).with_tpm(600), now);
+
+		// Full bucket, no wait.
+		assert_eq!(bucket.wait_time(100, now), Duration::ZERO);
+		bucket.consume(100);
+
+		// 500 tokens left, 600 needed (capped at capacity), refill is 10 tokens/sec.
+		assert_eq!(bucket.wait_time(1000, now), Duration::from_secs(10));
+
+		// Provider reports no more requests, with a reset in 2s.
+		let mut headers = HeaderMap::new();
+		headers.insert("x-ratelimit-remaining-requests", HeaderValue::from_static("0"));
+		headers.insert("x-ratelimit-reset-requests", HeaderValue::from_static("2s"));
+		let remaining = RemainingLimits::from_headers(AdapterKind::OpenAI, &headers).unwrap();
+		bucket.adapt(&remaining, now);
+		assert_eq!(bucket.wait_time(1, now), Duration::from_secs(2));
+	}
+
+	#[test]
+	fn test_adapt_from_headers_without_limit() {
+		let limiter = RateLimiter::new().with_adaptive(true);
+		let model_iden = ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini");
+		let other_iden = ModelIden::new(AdapterKind::OpenAI, "gpt-4o");
+
+		// Provider reports 3 of 100 requests left, then none with a reset in 2s.
+		let mut headers = HeaderMap::new();
+		headers.insert("x-ratelimit-limit-requests", HeaderValue::from_static("100"));
+		headers.insert("x-ratelimit-remaining-requests", HeaderValue::from_static("3"));
+		limiter.adapt_from_headers(&model_iden, &headers);
+		headers.insert("x-ratelimit-remaining-requests", HeaderValue::from_static("0"));
+		headers.insert("x-ratelimit-reset-requests", HeaderValue::from_static("2s"));
+		limiter.adapt_from_headers(&model_iden, &headers);
+
+		let mut state = limiter.lock_state();
+		let now = Instant::now();
+		assert_eq!(
+			state.header_limits.get(&model_iden).and_then(|limit| limit.requests_per_minute),
+			Some(100)
+		);
+		let keys = limiter.bucket_keys(&state, &model_iden);
+		assert_eq!(keys, vec![BucketKey::Model(model_iden.clone())]);
+		let wait = limiter.bucket(&mut state, &keys[0], now).map(|bucket| bucket.wait_time(1, now));
+		assert!(wait.is_some_and(|wait| wait > Duration::from_millis(1500)));
+		// The other models are not limited.
+		assert!(limiter.bucket_keys(&state, &other_iden).is_empty());
+	}
+
+	#[test]
+	fn test_adapt_from_headers_groq_tokens_only() {
+		let limiter = RateLimiter::new().with_adaptive(true);
+		let model_iden = ModelIden::new(AdapterKind::Groq, "llama-3.1-8b-instant");
+
+		// Groq reports its requests per day (none of 14400 left), and its tokens per minute.
+		let mut headers = HeaderMap::new();
+		headers.insert("x-ratelimit-limit-requests", HeaderValue::from_static("14400"));
+		headers.insert("x-ratelimit-remaining-requests", HeaderValue::from_static("0"));
+		headers.insert("x-ratelimit-reset-requests", HeaderValue::from_static("2m59.56s"));
+		headers.insert("x-ratelimit-limit-tokens", HeaderValue::from_static("6000"));
+		headers.insert("x-ratelimit-remaining-tokens", HeaderValue::from_static("5990"));
+		headers.insert("x-ratelimit-reset-tokens", HeaderValue::from_static("100ms"));
+		limiter.adapt_from_headers(&model_iden, &headers);
+
+		// Only a token bucket, and the per day requests do not block.
+		let mut state = limiter.lock_state();
+		let now = Instant::now();
+		let limit = state.header_limits.get(&model_iden).copied().unwrap_or_default();
+		assert_eq!((limit.requests_per_minute, limit.tokens_per_minute), (None, Some(6000)));
+		let key = BucketKey::Model(model_iden.clone());
+		let wait = limiter.bucket(&mut state, &key, now).map(|bucket| bucket.wait_time(1, now));
+		assert_eq!(wait, Some(Duration::ZERO));
+	}
+}
@@ -0,0 +1,152 @@
+//! Request preview: the web request a call would send, built without sending it.
+//!
+//! `Client::preview_chat_request(..)` and `Client::preview_embed_request(..)` run the same resolution as the
+//! `exec_...` calls (model mapping, auth resolution, `ServiceTargetResolver`, and the adapter request building),
+//! so the preview shows the provider URL, headers, and payload, e.g., to debug a provider quirk or
+//! to snapshot test the adapter payloads.
+//!
+//! Note: The middlewares are not applied (they may have side effects), and the API keys are redacted.
+
+use crate::ModelIden;
+use crate::adapter::WebRequestData;
+use crate::client::{AuditRedaction, Headers};
+use crate::resolver::AuthData;
+use serde::Serialize;
+use serde_json::Value;
+
+/// The web request a call would send (see `Client::preview_chat_request`).
+#[derive(Debug, Clone, Serialize)]
+pub struct RequestPreview {
+	/// The resolved model identifier (after the `ModelMapper` and `ServiceTargetResolver`).
+	pub model_iden: ModelIden,
+
+	/// The full URL (the API key query parameter redacted).
+	pub url: String,
+
+	/// The HTTP headers (the auth headers redacted).
+	pub headers: Headers,
+
+	/// The JSON payload, in the provider format.
+	pub payload: Value,
+}
+
+impl RequestPreview {
+	/// Create the preview of the web request, redacting the key of `auth` (all the key values of `AuthData::MultiKeys`)
+	/// wherever it appears in the URL or headers.
+	pub(crate) fn new(model_iden: ModelIden, web_request_data: WebRequestData, auth: &AuthData) -> Self {
+		let WebRequestData {
+			url,
+			mut headers,
+			payload,
+		} = web_request_data;
+
+		let mut redaction = AuditRedaction::default();
+		match auth {
+			AuthData::MultiKeys(keys) => {
+				for value in keys.values() {
+					redaction = redaction.with_secret(value.clone());
+				}
+			}
+			_ => {
+				if let Ok(api_key) = auth.single_key_value() {
+					redaction = redaction.with_secret(api_key);
+				}
+			}
+		}
+		redaction.redact_headers(&mut headers);
+
+		Self {
+			model_iden,
+			url: redaction.redact_url(&url),
+			headers,
+			payload,
+		}
+	}
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use crate::Client;
+	use crate::chat::{ChatMessage, ChatOptions, ChatRequest};
+	use crate::resolver::AuthData;
+	use std::collections::HashMap;
+
+	#[tokio::test]
+	async fn test_preview_chat_request_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let client = Client::builder()
+			.with_auth_resolver_fn(|_| Ok(Some(AuthData::from_single("sk-test-123"))))
+			.build();
+		let chat_req = ChatRequest::new(vec![ChatMessage::user("Why is the sky blue?")]);
+		let options = ChatOptions::default().with_temperature(0.2);
+
+		// -- Exec
+		let openai = client.preview_chat_request("gpt-4o-mini", &chat_req, Some(&options)).await?;
+		let gemini = client.preview_chat_request("gemini-2.5-flash", &chat_req, None).await?;
+
+		// -- Check
+		assert_eq!(openai.url, "https://api.openai.com/v1/chat/completions");
+		let authorization = openai
+			.headers
+			.iter()
+			.find(|(name, _)| name.eq_ignore_ascii_case("authorization"));
+		assert_eq!(authorization.map(|(_, value)| value.as_str()), Some("[REDACTED]"));
+		assert_eq!(openai.payload["model"], "gpt-4o-mini");
+		assert_eq!(openai.payload["temperature"], 0.2);
+		assert_eq!(openai.payload["messages"][0]["content"], "Why is the sky blue?");
+		let serialized = serde_json::to_string(&gemini)?;
+		assert!(
+			!serialized.contains("sk-test-123"),
+			"Should not contain the key in:\n{serialized}"
+		);
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn test_preview_chat_request_bedrock_multi_keys_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let keys = HashMap::from([
+			("AWS_ACCESS_KEY_ID".to_string(), "AKIDPREVIEW".to_string()),
+			("AWS_SECRET_ACCESS_KEY".to_string(), "preview-secret".to_string()),
+			("AWS_SESSION_TOKEN".to_string(), "preview-session-token".to_string()),
+			("AWS_REGION".to_string(), "eu-west-3".to_string()),
+		]);
+		let client = Client::builder()
+			.with_auth_resolver_fn(move |_| Ok(Some(AuthData::from_multi(keys.clone()))))
+			.build();
+		let chat_req = ChatRequest::new(vec![ChatMessage::user("Why is the sky blue?")]);
+
+		// -- Exec
+		let preview = client
+			.preview_chat_request("bedrock::amazon.nova-lite-v1:0", &chat_req, None)
+			.await?;
+
+		// -- Check (signed, with the signature and session token redacted)
+		let header = |name: &str| {
+			preview
+				.headers
+				.iter()
+				.find(|(n, _)| n.as_str() == name)
+				.map(|(_, v)| v.as_str())
+		};
+		assert_eq!(header("authorization"), Some("[REDACTED]"));
+		assert_eq!(header("x-amz-security-token"), Some("[REDACTED]"));
+		assert!(header("x-amz-date").is_some());
+		let serialized = serde_json::to_string(&preview)?;
+		for secret in ["AKIDPREVIEW", "preview-secret", "preview-session-token"] {
+			assert!(
+				!serialized.contains(secret),
+				"Should not contain '{secret}' in:\n{serialized}"
+			);
+		}
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
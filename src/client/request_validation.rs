@@ -0,0 +1,164 @@
+//! Pre-flight validation of a chat request against the model capabilities.
+//!
+//! - Enabled per request or per client with `ChatOptions::with_validate_request(true)`,
+//!   or called directly with `Client::validate_chat_request(..)`.
+//! - Only the capabilities known to be unsupported (`Some(false)`) are reported, unknown ones pass.
+//! - All the problems are reported at once with `Error::RequestValidation`.
+//!
+//! Note: The prompt tokens are estimated from the text sizes (about 4 characters per token),
+//!       without the binary parts, so only a clear context window overflow is reported.
+
+use crate::chat::{ChatOptions, ChatOptionsSet, ChatRequest, ChatResponseFormat, ContentPart, ModelCapabilities};
+use crate::{Client, Error, ModelIden, Result};
+use serde::Serialize;
+
+impl Client {
+	/// Validates the chat request against the capabilities of the model (see `Client::model_capabilities`),
+	/// without sending it.
+	pub fn validate_chat_request(
+		&self,
+		model: &str,
+		chat_req: &ChatRequest,
+		options: Option<&ChatOptions>,
+	) -> Result<()> {
+		let options_set = ChatOptionsSet::default()
+			.with_chat_options(options)
+			.with_client_options(self.config().chat_options_for(model));
+		let capabilities = self.model_capabilities(model)?;
+		let model_iden = self.default_model(model)?;
+
+		validate_chat_request(&model_iden, &capabilities, chat_req, &options_set)
+	}
+}
+
+// region:    --- Support
+
+pub(crate) fn validate_chat_request(
+	model_iden: &ModelIden,
+	capabilities: &ModelCapabilities,
+	chat_req: &ChatRequest,
+	options_set: &ChatOptionsSet<'_, '_>,
+) -> Result<()> {
+	let mut problems = Vec::new();
+
+	// -- Content parts
+	let parts = || chat_req.messages.iter().flat_map(|msg| msg.content.parts());
+	if capabilities.image_input == Some(false) && parts().any(ContentPart::is_image) {
+		problems.push("image content parts are not supported".to_string());
+	}
+	if capabilities.pdf_input == Some(false) && parts().any(ContentPart::is_pdf) {
+		problems.push("PDF content parts are not supported".to_string());
+	}
+
+	// -- Tools & response format
+	if capabilities.tools == Some(false) && chat_req.tools.as_ref().is_some_and(|tools| !tools.is_empty()) {
+		problems.push("tools (function calling) are not supported".to_string());
+	}
+	if capabilities.json_schema == Some(false)
+		&& matches!(options_set.response_format(), Some(ChatResponseFormat::JsonSpec(_)))
+	{
+		problems.push("structured output (ChatResponseFormat::JsonSpec) is not supported".to_string());
+	}
+
+	// -- Context window
+	if let Some(context_window) = capabilities.context_window {
+		let estimated_tokens = estimate_prompt_tokens(chat_req);
+		if estimated_tokens > context_window {
+			problems.push(format!(
+				"estimated prompt of {estimated_tokens} tokens exceeds the context window of {context_window} tokens"
+			));
+		}
+	}
+
+	if problems.is_empty() {
+		Ok(())
+	} else {
+		Err(Error::RequestValidation {
+			model_iden: model_iden.clone(),
+			problems,
+		})
+	}
+}
+
+/// Rough token estimate of the system, texts, tool calls and responses, and tool definitions.
+fn estimate_prompt_tokens(chat_req: &ChatRequest) -> u32 {
+	let mut len = chat_req.system.as_ref().map(String::len).unwrap_or_default();
+	for part in chat_req.messages.iter().flat_map(|msg| msg.content.parts()) {
+		len += match part {
+			ContentPart::Text(text) => text.len(),
+			ContentPart::ToolCall(tool_call) => json_len(tool_call),
+			ContentPart::ToolResponse(tool_response) => json_len(tool_response),
+			ContentPart::Binary(_) | ContentPart::ThoughtSignature(_) => 0,
+		};
+	}
+	if let Some(tools) = &chat_req.tools {
+		len += tools.iter().map(json_len).sum::<usize>();
+	}
+
+	(len / 4).min(u32::MAX as usize) as u32
+}
+
+fn json_len(value: &impl Serialize) -> usize {
+	serde_json::to_string(value).map(|json| json.len()).unwrap_or_default()
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::adapter::AdapterKind;
+	use crate::chat::{ChatMessage, JsonSpec, MessageContent, Tool};
+	use serde_json::json;
+
+	#[test]
+	fn test_validate_chat_request_problems() -> Result<()> {
+		// -- Setup & Fixtures
+		let model_iden = ModelIden::new(AdapterKind::OpenAI, "text-only-model");
+		let capabilities = ModelCapabilities::default()
+			.with_image_input(false)
+			.with_pdf_input(true)
+			.with_tools(false)
+			.with_json_schema(false)
+			.with_context_window(10);
+		let content = MessageContent::from_text("Describe this image in great detail, please.")
+			.append(ContentPart::from_binary_url(
+				"image/png",
+				"https://example.com/a.png",
+				None,
+			))
+			.append(ContentPart::from_binary_url(
+				"application/pdf",
+				"https://example.com/a.pdf",
+				None,
+			));
+		let chat_req = ChatRequest::new(vec![ChatMessage::user(content)]).with_tools([Tool::new("get_weather")]);
+		let options = ChatOptions::default().with_response_format(JsonSpec::new("answer", json!({"type": "object"})));
+		let options_set = ChatOptionsSet::default().with_chat_options(Some(&options));
+
+		// -- Exec
+		let err = validate_chat_request(&model_iden, &capabilities, &chat_req, &options_set)
+			.err()
+			.ok_or("should have failed")?;
+		let unknown_res = validate_chat_request(&model_iden, &ModelCapabilities::default(), &chat_req, &options_set);
+
+		// -- Check
+		let Error::RequestValidation { problems, .. } = err else {
+			return Err(format!("unexpected error: {err}").into());
+		};
+		assert_eq!(problems.len(), 4, "problems: {problems:?}");
+		assert!(problems[0].starts_with("image"));
+		assert!(problems[1].starts_with("tools"));
+		assert!(problems[2].starts_with("structured output"));
+		assert!(problems[3].contains("context window of 10"));
+		assert!(unknown_res.is_ok());
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
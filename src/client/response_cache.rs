@@ -0,0 +1,327 @@
+//! ResponseCache lets the `Client` serve identical chat and embed requests without calling the provider again.
+//! - Set with `client_config.with_response_cache(..)` (or `ClientBuilder::with_response_cache(..)`).
+//! - Keyed by a stable hash of the resolved `ModelIden`, the request, and the effective options.
+//!   The call-control options (retries, fallbacks, timeouts, ...) are not serialized, so they are not part of it.
+//! - With fallback models, a response is cached under the model that answered.
+//! - Cache hits return a response with `cached == true`.
+//! - Chat streams replay a cached chat response as a synthetic `ChatStream` (streams do not populate the cache).
+//!
+//! Two implementations are provided: `InMemoryResponseCache` (LRU) and `DiskResponseCache` (one JSON file per key).
+
+use crate::ModelIden;
+use crate::chat::{ChatOptionsSet, ChatRequest, ChatResponse};
+use crate::embed::{EmbedOptionsSet, EmbedRequest, EmbedResponse};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+// region:    --- ResponseCache
+
+/// A cache of chat and embed responses.
+///
+/// Implementations must be thread-safe. Failures (e.g., I/O) should be handled internally;
+/// a failed `get` is a miss, and a failed `put` is ignored.
+pub trait ResponseCache: Send + Sync {
+	/// Returns the cached response for the key, if any.
+	fn get(&self, key: &CacheKey) -> Option<CachedResponse>;
+
+	/// Stores the response for the key.
+	fn put(&self, key: &CacheKey, response: &CachedResponse);
+}
+
+impl std::fmt::Debug for dyn ResponseCache {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "ResponseCache")
+	}
+}
+
+/// A cached response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum CachedResponse {
+	Chat(ChatResponse),
+	Embed(EmbedResponse),
+}
+
+// endregion: --- ResponseCache
+
+// region:    --- CacheKey
+
+/// Stable key of a cached response (hex-encoded hash).
+///
+/// The hash is computed with FNV-1a over the canonical JSON (sorted keys) of the key source,
+/// so it is stable across runs and Rust versions (required for `DiskResponseCache`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CacheKey(String);
+
+impl CacheKey {
+	/// Key of a chat request, with the options cascaded as in `ChatOptionsSet` (request over client).
+	pub(crate) fn for_chat(
+		model_iden: &ModelIden,
+		chat_req: &ChatRequest,
+		options_set: &ChatOptionsSet<'_, '_>,
+	) -> Self {
+		let mut options = effective_options(options_set.chat_options(), options_set.client_options());
+		// `stop_sequences` is not optional, so it follows the `stop_sequences()` resolution
+		// (e.g., the same key without options as with default ones).
+		options.insert("stop_sequences".to_string(), json!(options_set.stop_sequences()));
+
+		Self::from_source(json!({
+			"kind": "chat",
+			"model_iden": model_iden,
+			"request": chat_req,
+			"options": options,
+		}))
+	}
+
+	/// Key of an embed request, with the options cascaded as in `EmbedOptionsSet` (request over client).
+	pub(crate) fn for_embed(
+		model_iden: &ModelIden,
+		embed_req: &EmbedRequest,
+		options_set: &EmbedOptionsSet<'_, '_>,
+	) -> Self {
+		let options = effective_options(options_set.request_options(), options_set.client_options());
+
+		Self::from_source(json!({
+			"kind": "embed",
+			"model_iden": model_iden,
+			"request": embed_req,
+			"options": options,
+		}))
+	}
+
+	/// The hex-encoded hash.
+	pub fn as_str(&self) -> &str {
+		&self.0
+	}
+
+	fn from_source(source: Value) -> Self {
+		// NOTE: serde_json `Value` objects are sorted by key (no `preserve_order` feature), so the string is canonical.
+		let hash = fnv1a_64(source.to_string().as_bytes());
+		Self(format!("{hash:016x}"))
+	}
+}
+
+impl std::fmt::Display for CacheKey {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(&self.0)
+	}
+}
+
+// endregion: --- CacheKey
+
+// region:    --- InMemoryResponseCache
+
+/// In-memory LRU response cache.
+#[derive(Debug)]
+pub struct InMemoryResponseCache {
+	capacity: usize,
+	inner: Mutex<LruInner>,
+}
+
+#[derive(Debug, Default)]
+struct LruInner {
+	entries: HashMap<CacheKey, CachedResponse>,
+	// Least recently used first.
+	order: VecDeque<CacheKey>,
+}
+
+impl InMemoryResponseCache {
+	/// Create a cache holding at most `capacity` responses.
+	pub fn new(capacity: usize) -> Self {
+		Self {
+			capacity: capacity.max(1),
+			inner: Mutex::new(LruInner::default()),
+		}
+	}
+
+	/// Number of cached responses.
+	pub fn len(&self) -> usize {
+		self.lock_inner().entries.len()
+	}
+
+	/// Returns true if the cache is empty.
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	fn lock_inner(&self) -> std::sync::MutexGuard<'_, LruInner> {
+		self.inner.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+	}
+}
+
+impl ResponseCache for InMemoryResponseCache {
+	fn get(&self, key: &CacheKey) -> Option<CachedResponse> {
+		let mut inner = self.lock_inner();
+		let response = inner.entries.get(key)?.clone();
+		inner.touch(key);
+		Some(response)
+	}
+
+	fn put(&self, key: &CacheKey, response: &CachedResponse) {
+		let mut inner = self.lock_inner();
+		inner.entries.insert(key.clone(), response.clone());
+		inner.touch(key);
+		while inner.entries.len() > self.capacity {
+			let Some(lru_key) = inner.order.pop_front() else {
+				break;
+			};
+			inner.entries.remove(&lru_key);
+		}
+	}
+}
+
+impl LruInner {
+	fn touch(&mut self, key: &CacheKey) {
+		if let Some(idx) = self.order.iter().position(|k| k == key) {
+			self.order.remove(idx);
+		}
+		self.order.push_back(key.clone());
+	}
+}
+
+// endregion: --- InMemoryResponseCache
+
+// region:    --- DiskResponseCache
+
+/// On-disk response cache, storing one `<key>.json` file per response in a directory.
+#[derive(Debug, Clone)]
+pub struct DiskResponseCache {
+	dir: PathBuf,
+}
+
+impl DiskResponseCache {
+	/// Create a disk cache in `dir` (created on first `put` if missing).
+	pub fn new(dir: impl Into<PathBuf>) -> Self {
+		Self { dir: dir.into() }
+	}
+
+	/// The cache directory.
+	pub fn dir(&self) -> &Path {
+		&self.dir
+	}
+
+	fn entry_path(&self, key: &CacheKey) -> PathBuf {
+		self.dir.join(format!("{key}.json"))
+	}
+}
+
+impl ResponseCache for DiskResponseCache {
+	fn get(&self, key: &CacheKey) -> Option<CachedResponse> {
+		let content = std::fs::read_to_string(self.entry_path(key)).ok()?;
+		match serde_json::from_str(&content) {
+			Ok(response) => Some(response),
+			Err(err) => {
+				tracing::warn!(%key, %err, "invalid response cache entry, ignored");
+				None
+			}
+		}
+	}
+
+	fn put(&self, key: &CacheKey, response: &CachedResponse) {
+		let res = (|| -> std::io::Result<()> {
+			std::fs::create_dir_all(&self.dir)?;
+			let content = serde_json::to_string(response)?;
+			// Write then rename, so a concurrent `get` never reads a partial file.
+			let tmp_path = self.dir.join(format!("{key}.json.tmp"));
+			std::fs::write(&tmp_path, content)?;
+			std::fs::rename(tmp_path, self.entry_path(key))
+		})();
+
+		if let Err(err) = res {
+			tracing::warn!(%key, %err, "failed to write response cache entry");
+		}
+	}
+}
+
+// endregion: --- DiskResponseCache
+
+// region:    --- Support
+
+/// Serialize the options, with the non-null request level values over the client level ones.
+fn effective_options<T: Serialize>(
+	request_options: Option<&T>,
+	client_options: Option<&T>,
+) -> serde_json::Map<String, Value> {
+	let to_map = |options: Option<&T>| match options.map(serde_json::to_value) {
+		Some(Ok(Value::Object(map))) => map,
+		_ => serde_json::Map::new(),
+	};
+
+	let mut options = to_map(client_options);
+	for (name, value) in to_map(request_options) {
+		if !value.is_null() {
+			options.insert(name, value);
+		}
+	}
+	options.retain(|_, value| !value.is_null());
+	options
+}
+
+fn fnv1a_64(bytes: &[u8]) -> u64 {
+	const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+	const PRIME: u64 = 0x100000001b3;
+
+	bytes
+		.iter()
+		.fold(OFFSET_BASIS, |hash, byte| (hash ^ *byte as u64).wrapping_mul(PRIME))
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::adapter::AdapterKind;
+	use crate::chat::{ChatMessage, ChatOptions};
+
+	#[test]
+	fn test_cache_key_and_lru() {
+		let model_iden = ModelIden::new(AdapterKind::OpenAI, "gpt-4o-mini");
+		let chat_req = ChatRequest::new(vec![ChatMessage::user("Hello")]);
+		let client_options = ChatOptions::default().with_temperature(0.5);
+		let request_options = ChatOptions::default()
+			.with_fallback_models(["gpt-4o"])
+			.with_timeout(std::time::Duration::from_secs(30))
+			.with_validate_request(true);
+
+		// -- Key is stable, and ignores non-semantic options
+		let base_set = ChatOptionsSet::default().with_client_options(Some(&client_options));
+		let with_fallback_set = base_set.clone().with_chat_options(Some(&request_options));
+		let key = CacheKey::for_chat(&model_iden, &chat_req, &base_set);
+		assert_eq!(key, CacheKey::for_chat(&model_iden, &chat_req, &base_set));
+		assert_eq!(key, CacheKey::for_chat(&model_iden, &chat_req, &with_fallback_set));
+		assert_eq!(key.as_str().len(), 16);
+		let default_options = ChatOptions::default();
+		let default_set = ChatOptionsSet::default().with_chat_options(Some(&default_options));
+		assert_eq!(
+			CacheKey::for_chat(&model_iden, &chat_req, &ChatOptionsSet::default()),
+			CacheKey::for_chat(&model_iden, &chat_req, &default_set)
+		);
+
+		// -- Key changes with the effective options
+		let other_options = ChatOptions::default().with_temperature(0.9);
+		let other_set = base_set.clone().with_chat_options(Some(&other_options));
+		let other_key = CacheKey::for_chat(&model_iden, &chat_req, &other_set);
+		assert_ne!(key, other_key);
+
+		// -- LRU eviction
+		let cache = InMemoryResponseCache::new(1);
+		let response = CachedResponse::Embed(EmbedResponse::new(
+			vec![],
+			model_iden.clone(),
+			model_iden,
+			Default::default(),
+		));
+		cache.put(&key, &response);
+		cache.put(&other_key, &response);
+		assert_eq!(cache.len(), 1);
+		assert!(cache.get(&key).is_none());
+		assert!(cache.get(&other_key).is_some());
+	}
+}
+
+// endregion: --- Tests
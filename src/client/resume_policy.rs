@@ -0,0 +1,250 @@
+//! ResumePolicy makes a chat stream survive a dropped or idle connection.
+//! - It can be set per chat request via `ChatOptions::with_resume_policy(..)`,
+//! - or as a client default with the client `ChatOptions`.
+//!
+//! When the stream drops mid-response, the request is re-issued with the text received so far as an
+//! assistant prefill, and the new response continues the same `ChatStream` (after a `ChatStreamEvent::Resumed`).
+//!
+//! Note: Only applied for the adapters supporting an assistant prefill (Anthropic, Gemini),
+//!       and never once a tool call has been streamed.
+
+use crate::adapter::AdapterKind;
+use crate::adapter::inter_stream::InterStreamEvent;
+use crate::chat::ChatStream;
+use crate::{Error, ModelIden, Result};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+// region:    --- ResumePolicy
+
+/// Resume policy for chat streams.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResumePolicy {
+	/// Maximum number of resumes for one stream.
+	pub max_resumes: u32,
+
+	/// Once the stream has started, the wait between two events after which the connection is considered dropped.
+	/// Note: Keep `ChatOptions.stream_idle_timeout` (if any) above it, as it ends the stream with an error.
+	pub idle_timeout: Option<Duration>,
+}
+
+impl Default for ResumePolicy {
+	fn default() -> Self {
+		Self {
+			max_resumes: 2,
+			idle_timeout: Some(Duration::from_secs(60)),
+		}
+	}
+}
+
+/// Constructors
+impl ResumePolicy {
+	/// Create a default policy with the given maximum number of resumes.
+	pub fn new(max_resumes: u32) -> Self {
+		Self {
+			max_resumes,
+			..Default::default()
+		}
+	}
+}
+
+/// Chainable Setters
+impl ResumePolicy {
+	/// Set the maximum number of resumes.
+	pub fn with_max_resumes(mut self, max_resumes: u32) -> Self {
+		self.max_resumes = max_resumes;
+		self
+	}
+
+	/// Set the idle timeout (`None` to only resume on connection errors).
+	pub fn with_idle_timeout(mut self, idle_timeout: impl Into<Option<Duration>>) -> Self {
+		self.idle_timeout = idle_timeout.into();
+		self
+	}
+}
+
+// endregion: --- ResumePolicy
+
+// region:    --- Stream Resume
+
+/// The captured content to rebuild on the `End` event of a resumed stream.
+pub(crate) struct ResumeCapture {
+	pub content: bool,
+	pub reasoning_content: bool,
+}
+
+/// Wrap a `ChatStream` so that it is resumed when it drops or goes idle after its first event.
+///
+/// `new_stream` is called with the prefill (the text streamed so far, trailing whitespace trimmed) to re-issue the request.
+/// The `Start` of the new stream is skipped, and on `End` the captured text and reasoning cover all the attempts
+/// (the usage is the one of the last attempt).
+pub(crate) fn resume_chat_stream<F, Fut>(
+	stream: ChatStream,
+	model_iden: ModelIden,
+	resume_policy: ResumePolicy,
+	capture: ResumeCapture,
+	new_stream: F,
+) -> ChatStream
+where
+	F: FnMut(&str) -> Fut + Send + 'static,
+	Fut: Future<Output = Result<ChatStream>> + Send,
+{
+	struct State<F> {
+		stream: Option<ChatStream>,
+		new_stream: F,
+		model_iden: ModelIden,
+		resume_policy: ResumePolicy,
+		capture: ResumeCapture,
+		resumes: u32,
+		started: bool,
+		ended: bool,
+		tool_call_streamed: bool,
+		/// The prefill trailing whitespace was trimmed, so the next chunk leading whitespace is too.
+		trim_next_chunk: bool,
+		text: String,
+		reasoning: String,
+	}
+
+	let state = State {
+		stream: Some(stream),
+		new_stream,
+		model_iden,
+		resume_policy,
+		capture,
+		resumes: 0,
+		started: false,
+		ended: false,
+		tool_call_streamed: false,
+		trim_next_chunk: false,
+		text: String::new(),
+		reasoning: String::new(),
+	};
+
+	let inter_stream = futures::stream::unfold(state, |mut state| async move {
+		let mut inter_stream = state.stream.take()?.into_inter_stream();
+		loop {
+			let next = match state.resume_policy.idle_timeout.filter(|_| state.started) {
+				Some(idle_timeout) => tokio::time::timeout(idle_timeout, inter_stream.next()).await.map_err(|_| {
+					Error::StreamIdleTimeout {
+						model_iden: state.model_iden.clone(),
+						timeout: idle_timeout,
+					}
+				}),
+				None => Ok(inter_stream.next().await),
+			};
+
+			let drop_err = match next {
+				Ok(Some(Ok(event))) => {
+					let event = match event {
+						InterStreamEvent::Start if state.resumes > 0 => continue,
+						InterStreamEvent::Chunk(mut content) => {
+							if state.trim_next_chunk {
+								content = content.trim_start().to_string();
+								if content.is_empty() {
+									continue;
+								}
+								state.trim_next_chunk = false;
+							}
+							state.text.push_str(&content);
+							InterStreamEvent::Chunk(content)
+						}
+						InterStreamEvent::ReasoningChunk(content) => {
+							state.reasoning.push_str(&content);
+							InterStreamEvent::ReasoningChunk(content)
+						}
+						InterStreamEvent::ToolCallChunk(tool_call) => {
+							state.tool_call_streamed = true;
+							InterStreamEvent::ToolCallChunk(tool_call)
+						}
+						InterStreamEvent::End(mut end) => {
+							state.ended = true;
+							if state.resumes > 0 {
+								if state.capture.content && !state.text.is_empty() {
+									end.captured_text_content = Some(state.text.clone());
+								}
+								if state.capture.reasoning_content && !state.reasoning.is_empty() {
+									end.captured_reasoning_content = Some(state.reasoning.clone());
+								}
+							}
+							InterStreamEvent::End(end)
+						}
+						event => event,
+					};
+					state.started = true;
+					state.stream = Some(ChatStream::new(inter_stream));
+					return Some((Ok(event), state));
+				}
+				Ok(Some(Err(err))) if is_stream_drop(&err) => err,
+				Ok(Some(Err(err))) => {
+					state.stream = Some(ChatStream::new(inter_stream));
+					return Some((Err(err), state));
+				}
+				Ok(None) if state.ended || !state.started => return None,
+				Ok(None) => Error::WebStream {
+					model_iden: state.model_iden.clone(),
+					cause: "Stream ended before its end event".to_string(),
+				},
+				Err(idle_err) => idle_err,
+			};
+
+			// -- Resume, if still possible (otherwise, end the stream with the error)
+			let resumable = state.started && !state.ended && !state.tool_call_streamed;
+			if !resumable || state.resumes >= state.resume_policy.max_resumes {
+				return Some((Err(drop_err), state));
+			}
+
+			state.resumes += 1;
+			tracing::debug!(resume = state.resumes, error = %drop_err, "chat stream dropped, resuming with prefill");
+			let prefill = state.text.trim_end();
+			state.trim_next_chunk = prefill.len() < state.text.len();
+			match (state.new_stream)(prefill).await {
+				Ok(stream) => {
+					state.stream = Some(stream);
+					let event = InterStreamEvent::Resumed {
+						attempt: state.resumes,
+						cause: drop_err.to_string(),
+					};
+					return Some((Ok(event), state));
+				}
+				// Could not re-issue the request, end the stream with the original error.
+				Err(err) => {
+					tracing::warn!(error = %err, "chat stream resume request could not be sent");
+					return Some((Err(drop_err), state));
+				}
+			}
+		}
+	});
+
+	ChatStream::from_inter_stream(inter_stream)
+}
+
+// endregion: --- Stream Resume
+
+// region:    --- Support
+
+/// Returns true if the adapter continues an assistant message given as the last message of the request.
+pub(crate) fn supports_prefill(adapter_kind: AdapterKind) -> bool {
+	match adapter_kind {
+		AdapterKind::Anthropic | AdapterKind::Gemini => true,
+		#[cfg(feature = "mock")]
+		AdapterKind::Mock => true,
+		_ => false,
+	}
+}
+
+/// Returns true for the errors of a connection lost while streaming.
+fn is_stream_drop(error: &Error) -> bool {
+	match error {
+		Error::ReqwestEventSource(err) => matches!(
+			err.as_ref(),
+			reqwest_eventsource::Error::Transport(_) | reqwest_eventsource::Error::StreamEnded
+		),
+		Error::WebStream { .. } => true,
+		// A transient provider error event (e.g., overloaded) ends the stream as a drop would.
+		Error::ChatResponse { .. } => error.is_retryable(),
+		_ => false,
+	}
+}
+
+// endregion: --- Support
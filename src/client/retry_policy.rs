@@ -0,0 +1,529 @@
+//! RetryPolicy controls how failed web calls are retried by the `Client`.
+//! - It can be set on the client config `client_config.with_retry_policy(..)`,
+//! - or per chat request via `ChatOptions::with_retry_policy(..)` (takes precedence for chat calls).
+//!
+//! Note: Streams are only retried when the failure happens before any event has been yielded.
+
+use crate::adapter::inter_stream::InterStreamEvent;
+use crate::chat::ChatStream;
+use crate::{Error, ErrorKind, Result, support, webc};
+use futures::StreamExt;
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+use std::hash::{BuildHasher, RandomState};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// region:    --- RetryPolicy
+
+/// Retry policy for web calls (chat, chat stream, embed).
+///
+/// Delays follow an exponential backoff (`initial_backoff * backoff_multiplier^(attempt - 1)`),
+/// capped at `max_backoff`, and optionally jittered.
+/// When the provider returns a `Retry-After`, `retry-after-ms`, or `x-ratelimit-reset-*` header,
+/// that delay is used instead (still capped at `max_backoff`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetryPolicy {
+	/// Total number of attempts, including the first one (`1` means no retry).
+	pub max_attempts: u32,
+
+	/// Delay before the first retry.
+	pub initial_backoff: Duration,
+
+	/// Upper bound for any single delay (computed or from headers).
+	pub max_backoff: Duration,
+
+	/// Multiplier applied to the delay after each attempt.
+	pub backoff_multiplier: f64,
+
+	/// When true, the computed backoff is randomized between 50% and 100% of its value.
+	pub jitter: bool,
+
+	/// HTTP status codes considered retryable.
+	pub retryable_statuses: Vec<u16>,
+
+	/// Retry on connection errors (connect failures, timeouts, connection resets).
+	pub retry_connection_errors: bool,
+
+	/// Honor `Retry-After` / `retry-after-ms` / `x-ratelimit-reset-*` response headers.
+	pub respect_retry_after: bool,
+}
+
+impl Default for RetryPolicy {
+	fn default() -> Self {
+		Self {
+			max_attempts: 3,
+			initial_backoff: Duration::from_millis(500),
+			max_backoff: Duration::from_secs(30),
+			backoff_multiplier: 2.0,
+			jitter: true,
+			// 529 is used by Anthropic for "overloaded"
+			retryable_statuses: vec![429, 500, 502, 503, 504, 529],
+			retry_connection_errors: true,
+			respect_retry_after: true,
+		}
+	}
+}
+
+/// Constructors
+impl RetryPolicy {
+	/// Create a default policy with the given total number of attempts.
+	pub fn new(max_attempts: u32) -> Self {
+		Self {
+			max_attempts,
+			..Default::default()
+		}
+	}
+
+	/// Create a policy that never retries.
+	pub fn none() -> Self {
+		Self::new(1)
+	}
+}
+
+/// Chainable Setters
+impl RetryPolicy {
+	/// Set the total number of attempts (including the first one).
+	pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+		self.max_attempts = max_attempts;
+		self
+	}
+
+	/// Set the delay before the first retry.
+	pub fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+		self.initial_backoff = initial_backoff;
+		self
+	}
+
+	/// Set the maximum delay between two attempts.
+	pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+		self.max_backoff = max_backoff;
+		self
+	}
+
+	/// Set the backoff multiplier.
+	pub fn with_backoff_multiplier(mut self, backoff_multiplier: f64) -> Self {
+		self.backoff_multiplier = backoff_multiplier;
+		self
+	}
+
+	/// Enable or disable jitter on the computed backoff.
+	pub fn with_jitter(mut self, jitter: bool) -> Self {
+		self.jitter = jitter;
+		self
+	}
+
+	/// Replace the set of retryable HTTP status codes.
+	pub fn with_retryable_statuses(mut self, statuses: impl Into<Vec<u16>>) -> Self {
+		self.retryable_statuses = statuses.into();
+		self
+	}
+
+	/// Enable or disable retrying on connection errors.
+	pub fn with_retry_connection_errors(mut self, value: bool) -> Self {
+		self.retry_connection_errors = value;
+		self
+	}
+
+	/// Enable or disable honoring the rate-limit reset headers.
+	pub fn with_respect_retry_after(mut self, value: bool) -> Self {
+		self.respect_retry_after = value;
+		self
+	}
+}
+
+/// Getters
+impl RetryPolicy {
+	/// Returns true if the given status is in the retryable set.
+	pub fn is_retryable_status(&self, status: StatusCode) -> bool {
+		self.retryable_statuses.contains(&status.as_u16())
+	}
+
+	/// Returns true if the error is a retryable status or connection error for this policy,
+	/// regardless of the number of attempts.
+	///
+	/// Note: A retryable status is not retried when the provider error tells it will fail again
+	///       (e.g., a `429` for an exhausted quota, see `Error::kind`).
+	pub fn is_retryable(&self, error: &Error) -> bool {
+		match classify_error(error) {
+			Some(RetryCause::Status(status, _)) => self.is_retryable_status(status) && !is_permanent(error),
+			Some(RetryCause::Connection) => self.retry_connection_errors,
+			None => false,
+		}
+	}
+
+	/// Returns the delay to wait before the next attempt, or `None` if the error should not be retried.
+	///
+	/// `attempt` is the 1-based number of the attempt that just failed.
+	pub fn retry_delay(&self, attempt: u32, error: &Error) -> Option<Duration> {
+		if attempt >= self.max_attempts {
+			return None;
+		}
+
+		let header_delay = match classify_error(error)? {
+			RetryCause::Status(status, headers) => {
+				if !self.is_retryable_status(status) || is_permanent(error) {
+					return None;
+				}
+				headers.filter(|_| self.respect_retry_after).and_then(delay_from_headers)
+			}
+			RetryCause::Connection => {
+				if !self.retry_connection_errors {
+					return None;
+				}
+				None
+			}
+		};
+
+		let delay = header_delay.unwrap_or_else(|| self.backoff(attempt));
+
+		Some(delay.min(self.max_backoff))
+	}
+
+	/// Computed (exponential, optionally jittered) backoff for the given failed attempt.
+	fn backoff(&self, attempt: u32) -> Duration {
+		let exp = attempt.saturating_sub(1).min(32) as i32;
+		let secs = self.initial_backoff.as_secs_f64() * self.backoff_multiplier.max(1.0).powi(exp);
+		let secs = secs.min(self.max_backoff.as_secs_f64());
+
+		let secs = if self.jitter {
+			// Random factor in [0.5, 1.0], no need for a full rand dependency here.
+			let rnd = RandomState::new().hash_one(attempt) as f64 / u64::MAX as f64;
+			secs * (0.5 + rnd * 0.5)
+		} else {
+			secs
+		};
+
+		Duration::from_secs_f64(secs)
+	}
+}
+
+// endregion: --- RetryPolicy
+
+// region:    --- Stream Retry
+
+/// Wrap a `ChatStream` so that failures happening before the first event are retried.
+///
+/// `new_stream` is called to re-issue the request for each retry (as the first attempt, see `Client::exec_chat_stream`).
+/// Once an event has been yielded, errors are passed through as-is.
+pub(crate) fn retry_chat_stream<F, Fut>(stream: ChatStream, retry_policy: RetryPolicy, new_stream: F) -> ChatStream
+where
+	F: FnMut() -> Fut + Send + 'static,
+	Fut: Future<Output = Result<ChatStream>> + Send,
+{
+	struct State<F> {
+		stream: Option<ChatStream>,
+		new_stream: F,
+		retry_policy: RetryPolicy,
+		attempt: u32,
+		started: bool,
+	}
+
+	let state = State {
+		stream: Some(stream),
+		new_stream,
+		retry_policy,
+		attempt: 1,
+		started: false,
+	};
+
+	let inter_stream = futures::stream::unfold(state, |mut state| async move {
+		let mut inter_stream = state.stream.take()?.into_inter_stream();
+		loop {
+			match inter_stream.next().await {
+				Some(Ok(event)) => {
+					state.started = true;
+					state.stream = Some(ChatStream::new(inter_stream));
+					return Some((Ok::<InterStreamEvent, Error>(event), state));
+				}
+				Some(Err(err)) => {
+					if !state.started
+						&& let Some(delay) = state.retry_policy.retry_delay(state.attempt, &err)
+					{
+						tracing::debug!(
+							attempt = state.attempt,
+							?delay,
+							"chat stream failed before first event, retrying"
+						);
+						tokio::time::sleep(delay).await;
+						state.attempt += 1;
+						match (state.new_stream)().await {
+							Ok(stream) => {
+								inter_stream = stream.into_inter_stream();
+								continue;
+							}
+							// Could not re-issue the request, end the stream with this error.
+							Err(err) => return Some((Err(err), state)),
+						}
+					}
+					state.stream = Some(ChatStream::new(inter_stream));
+					state.started = true;
+					return Some((Err(err), state));
+				}
+				None => return None,
+			}
+		}
+	});
+
+	ChatStream::from_inter_stream(inter_stream)
+}
+
+// endregion: --- Stream Retry
+
+// region:    --- Support
+
+enum RetryCause<'a> {
+	Status(StatusCode, Option<&'a HeaderMap>),
+	Connection,
+}
+
+/// Extract the retry relevant information from a genai error.
+/// Returns `None` when the error is not a web/transport error (e.g., parsing, auth resolution).
+fn classify_error(error: &Error) -> Option<RetryCause<'_>> {
+	match error {
+		Error::WebModelCall { webc_error, .. } | Error::WebAdapterCall { webc_error, .. } => match webc_error {
+			webc::Error::ResponseFailedStatus { status, headers, .. } => {
+				Some(RetryCause::Status(*status, Some(headers.as_ref())))
+			}
+			webc::Error::Reqwest(err) => classify_reqwest_error(err),
+			_ => None,
+		},
+		Error::ReqwestEventSource(err) => match err.as_ref() {
+			reqwest_eventsource::Error::InvalidStatusCode(status, res) => {
+				Some(RetryCause::Status(*status, Some(res.headers())))
+			}
+			reqwest_eventsource::Error::Transport(err) => classify_reqwest_error(err),
+			_ => None,
+		},
+		_ => None,
+	}
+}
+
+/// Returns true for the provider errors which fail again whatever the status (quota, context length, ...).
+fn is_permanent(error: &Error) -> bool {
+	matches!(
+		error.kind(),
+		ErrorKind::QuotaExhausted
+			| ErrorKind::ContextLengthExceeded
+			| ErrorKind::ContentFiltered
+			| ErrorKind::InvalidAuth
+	)
+}
+
+fn classify_reqwest_error(err: &reqwest::Error) -> Option<RetryCause<'_>> {
+	if let Some(status) = err.status() {
+		return Some(RetryCause::Status(status, None));
+	}
+	if err.is_connect() || err.is_timeout() || is_connection_reset(err) {
+		return Some(RetryCause::Connection);
+	}
+	None
+}
+
+fn is_connection_reset(err: &reqwest::Error) -> bool {
+	use std::io::ErrorKind;
+
+	let mut source: Option<&(dyn std::error::Error + 'static)> = std::error::Error::source(err);
+	while let Some(err) = source {
+		if let Some(io_err) = err.downcast_ref::<std::io::Error>()
+			&& matches!(
+				io_err.kind(),
+				ErrorKind::ConnectionReset | ErrorKind::ConnectionAborted | ErrorKind::BrokenPipe
+			) {
+			return true;
+		}
+		source = err.source();
+	}
+	false
+}
+
+/// Returns the delay requested by the provider, if any.
+///
+/// Supported headers (first match wins):
+/// - `retry-after-ms` (OpenAI, Azure)
+/// - `retry-after` (seconds, or an HTTP-date, RFC 9110)
+/// - `x-ratelimit-reset-requests` / `x-ratelimit-reset-tokens` (e.g., `1s`, `6m0s`, `250ms`), the longest is used
+fn delay_from_headers(headers: &HeaderMap) -> Option<Duration> {
+	let header_str = |name: &str| headers.get(name).and_then(|v| v.to_str().ok()).map(str::trim);
+
+	if let Some(ms) = header_str("retry-after-ms").and_then(|v| v.parse::<f64>().ok())
+		&& ms >= 0.
+	{
+		return Some(Duration::from_secs_f64(ms / 1000.));
+	}
+
+	if let Some(retry_after) = header_str("retry-after") {
+		if let Ok(secs) = retry_after.parse::<f64>()
+			&& secs >= 0.
+		{
+			return Some(Duration::from_secs_f64(secs));
+		}
+		// A date in the past means no wait.
+		if let Some(date) = parse_http_date(retry_after) {
+			return Some(date.duration_since(SystemTime::now()).unwrap_or_default());
+		}
+	}
+
+	headers
+		.iter()
+		.filter(|(name, _)| name.as_str().starts_with("x-ratelimit-reset"))
+		.filter_map(|(_, value)| value.to_str().ok())
+		.filter_map(parse_reset_duration)
+		.max()
+}
+
+/// Parse reset durations such as `20ms`, `1.5s`, `6m0s`, `1h2m3s`, or plain seconds (`12`).
+pub(super) fn parse_reset_duration(value: &str) -> Option<Duration> {
+	let value = value.trim();
+	if value.is_empty() {
+		return None;
+	}
+
+	if let Ok(secs) = value.parse::<f64>() {
+		return (secs >= 0.).then(|| Duration::from_secs_f64(secs));
+	}
+
+	let mut total_secs = 0.;
+	let mut rest = value;
+	while !rest.is_empty() {
+		let num_end = rest.find(|c: char| !(c.is_ascii_digit() || c == '.'))?;
+		let num: f64 = rest[..num_end].parse().ok()?;
+		rest = &rest[num_end..];
+
+		let unit_end = rest.find(|c: char| c.is_ascii_digit()).unwrap_or(rest.len());
+		let factor = match &rest[..unit_end] {
+			"ms" => 0.001,
+			"s" => 1.,
+			"m" => 60.,
+			"h" => 3600.,
+			_ => return None,
+		};
+		rest = &rest[unit_end..];
+
+		total_secs += num * factor;
+	}
+
+	Some(Duration::from_secs_f64(total_secs))
+}
+
+/// Parse the preferred HTTP-date format (IMF-fixdate), e.g., `Sun, 06 Nov 1994 08:49:37 GMT`.
+///
+/// Note: The obsolete RFC 850 and asctime formats are not supported (the computed backoff is used).
+fn parse_http_date(value: &str) -> Option<SystemTime> {
+	const MONTHS: [&str; 12] = [
+		"Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+	];
+
+	let (_weekday, rest) = value.split_once(", ")?;
+	let mut parts = rest.split(' ');
+	let (day, month, year, time, zone) = (
+		parts.next()?,
+		parts.next()?,
+		parts.next()?,
+		parts.next()?,
+		parts.next()?,
+	);
+	if zone != "GMT" || parts.next().is_some() {
+		return None;
+	}
+
+	let day: i64 = day.parse().ok()?;
+	let month = MONTHS.iter().position(|m| *m == month)? as i64 + 1;
+	let year: i64 = year.parse().ok()?;
+	let mut time = time.split(':').map(|v| v.parse::<i64>().ok());
+	let (hour, min, sec) = (time.next()??, time.next()??, time.next()??);
+	if !(1..=31).contains(&day) || hour > 23 || min > 59 || sec > 60 {
+		return None;
+	}
+
+	let days = support::days_from_civil(year, month, day);
+	let secs = days * 86_400 + hour * 3_600 + min * 60 + sec;
+	Some(UNIX_EPOCH + Duration::from_secs(u64::try_from(secs).ok()?))
+}
+
+// endregion: --- Support
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use reqwest::header::HeaderValue;
+
+	#[test]
+	fn test_parse_reset_duration() {
+		assert_eq!(parse_reset_duration("20ms"), Some(Duration::from_millis(20)));
+		assert_eq!(parse_reset_duration("1.5s"), Some(Duration::from_millis(1500)));
+		assert_eq!(parse_reset_duration("6m0s"), Some(Duration::from_secs(360)));
+		assert_eq!(parse_reset_duration("12"), Some(Duration::from_secs(12)));
+		assert_eq!(parse_reset_duration("soon"), None);
+	}
+
+	#[test]
+	fn test_retry_after_http_date() {
+		// -- Check the date parsing
+		let date = parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT");
+		assert_eq!(date, Some(UNIX_EPOCH + Duration::from_secs(784_111_777)));
+		assert_eq!(parse_http_date("Sunday, 06-Nov-94 08:49:37 GMT"), None);
+
+		// -- Check the delay (a future date, capped as the other delays, and a past one)
+		let policy = RetryPolicy::new(3).with_jitter(false);
+		let mut headers = HeaderMap::new();
+		headers.insert("retry-after", HeaderValue::from_static("Fri, 01 Jan 2100 00:00:00 GMT"));
+		let delay = policy.retry_delay(1, &status_error(StatusCode::SERVICE_UNAVAILABLE, headers));
+		assert_eq!(delay, Some(policy.max_backoff));
+		let mut headers = HeaderMap::new();
+		headers.insert("retry-after", HeaderValue::from_static("Sun, 06 Nov 1994 08:49:37 GMT"));
+		let delay = policy.retry_delay(1, &status_error(StatusCode::TOO_MANY_REQUESTS, headers));
+		assert_eq!(delay, Some(Duration::ZERO));
+	}
+
+	#[test]
+	fn test_retry_delay_from_status() {
+		let policy = RetryPolicy::new(3).with_jitter(false);
+		let mut headers = HeaderMap::new();
+		headers.insert("x-ratelimit-reset-requests", HeaderValue::from_static("2s"));
+		headers.insert("x-ratelimit-reset-tokens", HeaderValue::from_static("250ms"));
+
+		// Provider reset header wins over the computed backoff.
+		let error_429 = status_error(StatusCode::TOO_MANY_REQUESTS, headers);
+		assert_eq!(policy.retry_delay(1, &error_429), Some(Duration::from_secs(2)));
+		// No more attempts left.
+		assert_eq!(policy.retry_delay(3, &error_429), None);
+
+		// Not retryable.
+		let error_400 = status_error(StatusCode::BAD_REQUEST, HeaderMap::new());
+		assert_eq!(policy.retry_delay(1, &error_400), None);
+
+		// Exponential backoff (500ms * 2).
+		let error_503 = status_error(StatusCode::SERVICE_UNAVAILABLE, HeaderMap::new());
+		assert_eq!(policy.retry_delay(2, &error_503), Some(Duration::from_secs(1)));
+	}
+
+	#[test]
+	fn test_retry_not_on_quota_exhausted() {
+		let policy = RetryPolicy::new(3).with_jitter(false);
+		let error = Error::WebModelCall {
+			model_iden: crate::ModelIden::new(crate::adapter::AdapterKind::OpenAI, "gpt-test"),
+			webc_error: webc::Error::ResponseFailedStatus {
+				status: StatusCode::TOO_MANY_REQUESTS,
+				body: r#"{"error": {"message": "You exceeded your current quota", "code": "insufficient_quota"}}"#
+					.to_string(),
+				headers: Box::new(HeaderMap::new()),
+			},
+		};
+
+		assert_eq!(error.kind(), ErrorKind::QuotaExhausted);
+		assert!(!policy.is_retryable(&error));
+		assert_eq!(policy.retry_delay(1, &error), None);
+	}
+
+	fn status_error(status: StatusCode, headers: HeaderMap) -> Error {
+		Error::WebModelCall {
+			model_iden: crate::ModelIden::new(crate::adapter::AdapterKind::OpenAI, "gpt-test"),
+			webc_error: webc::Error::ResponseFailedStatus {
+				status,
+				body: String::new(),
+				headers: Box::new(headers),
+			},
+		}
+	}
+}
@@ -0,0 +1,186 @@
+//! `tracing` spans of the client calls, with the OpenTelemetry GenAI semantic conventions attributes (`gen_ai.*`).
+//! See <https://opentelemetry.io/docs/specs/semconv/gen-ai/gen-ai-spans/>
+//!
+//! Each `exec_chat`, `exec_chat_stream`, and `exec_embed` call runs in a `gen_ai` span (INFO level),
+//! named `{operation} {model}` for OpenTelemetry (`otel.name`), so a `tracing-opentelemetry` layer exports it as is.
+//! The chat stream span ends with the stream, with the finish reason and usage of its end event
+//! (its `gen_ai.response.model` is the resolved model, as the stream events do not have the provider one).
+//!
+//! The prompt and completion content are only recorded (as the `gen_ai.content.prompt` and
+//! `gen_ai.content.completion` span events) when enabled with `ClientConfig::with_trace_content(true)`.
+
+use crate::adapter::AdapterKind;
+use crate::adapter::inter_stream::InterStreamEvent;
+use crate::chat::{ChatOptionsSet, ChatRequest, ChatResponse, ChatStream, Usage};
+use crate::embed::EmbedResponse;
+use crate::{Error, Result, webc};
+use futures::StreamExt;
+use tracing::field::Empty;
+use tracing::{Level, Span};
+
+// region:    --- Spans
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum GenAiOperation {
+	Chat,
+	Embeddings,
+}
+
+impl GenAiOperation {
+	fn as_str(&self) -> &'static str {
+		match self {
+			GenAiOperation::Chat => "chat",
+			GenAiOperation::Embeddings => "embeddings",
+		}
+	}
+}
+
+/// Create the span of a call for the requested model name (`adapter_kind` is `None` when it could not be inferred).
+pub(crate) fn gen_ai_span(operation: GenAiOperation, model_name: &str, adapter_kind: Option<AdapterKind>) -> Span {
+	let operation = operation.as_str();
+	tracing::info_span!(
+		"gen_ai",
+		otel.name = format!("{operation} {model_name}"),
+		otel.kind = "client",
+		otel.status_code = Empty,
+		otel.status_message = Empty,
+		gen_ai.operation.name = operation,
+		gen_ai.system = adapter_kind.map(gen_ai_system),
+		gen_ai.request.model = model_name,
+		gen_ai.request.temperature = Empty,
+		gen_ai.request.max_tokens = Empty,
+		gen_ai.request.top_p = Empty,
+		gen_ai.response.model = Empty,
+		gen_ai.response.finish_reasons = Empty,
+		gen_ai.usage.input_tokens = Empty,
+		gen_ai.usage.output_tokens = Empty,
+		error.type = Empty,
+	)
+}
+
+/// The `gen_ai.system` well-known values, otherwise the adapter kind lower case name.
+fn gen_ai_system(adapter_kind: AdapterKind) -> &'static str {
+	match adapter_kind {
+		AdapterKind::OpenAI | AdapterKind::OpenAIResp => "openai",
+		AdapterKind::Gemini => "gcp.gemini",
+		AdapterKind::Bedrock => "aws.bedrock",
+		AdapterKind::AzureOpenAI => "az.ai.openai",
+		#[cfg(feature = "vertex")]
+		AdapterKind::Vertex => "gcp.vertex_ai",
+		AdapterKind::Xai => "xai",
+		AdapterKind::DeepSeek => "deepseek",
+		other => other.as_lower_str(),
+	}
+}
+
+// endregion: --- Spans
+
+// region:    --- Recorders
+
+pub(crate) fn record_chat_request(span: &Span, chat_req: &ChatRequest, options_set: &ChatOptionsSet, content: bool) {
+	if let Some(temperature) = options_set.temperature() {
+		span.record("gen_ai.request.temperature", temperature);
+	}
+	if let Some(max_tokens) = options_set.max_tokens() {
+		span.record("gen_ai.request.max_tokens", max_tokens);
+	}
+	if let Some(top_p) = options_set.top_p() {
+		span.record("gen_ai.request.top_p", top_p);
+	}
+
+	if content {
+		let prompt = serde_json::to_string(chat_req).unwrap_or_default();
+		tracing::event!(name: "gen_ai.content.prompt", parent: span, Level::INFO, gen_ai.prompt = prompt);
+	}
+}
+
+pub(crate) fn record_chat_result(span: &Span, res: &Result<ChatResponse>, content: bool) {
+	match res {
+		Ok(chat_res) => {
+			span.record("gen_ai.response.model", &*chat_res.provider_model_iden.model_name);
+			if let Some(finish_reason) = chat_res.finish_reason.as_deref() {
+				span.record("gen_ai.response.finish_reasons", finish_reason);
+			}
+			record_usage(span, &chat_res.usage);
+			if content {
+				let completion = serde_json::to_string(&chat_res.content).unwrap_or_default();
+				tracing::event!(name: "gen_ai.content.completion", parent: span, Level::INFO, gen_ai.completion = completion);
+			}
+		}
+		Err(err) => record_error(span, err),
+	}
+}
+
+pub(crate) fn record_embed_result(span: &Span, res: &Result<EmbedResponse>) {
+	match res {
+		Ok(embed_res) => {
+			span.record("gen_ai.response.model", &*embed_res.provider_model_iden.model_name);
+			record_usage(span, &embed_res.usage);
+		}
+		Err(err) => record_error(span, err),
+	}
+}
+
+pub(crate) fn record_error(span: &Span, err: &Error) {
+	span.record("otel.status_code", "ERROR");
+	span.record("otel.status_message", err.to_string());
+	span.record("error.type", error_type(err));
+}
+
+fn record_usage(span: &Span, usage: &Usage) {
+	if let Some(prompt_tokens) = usage.prompt_tokens {
+		span.record("gen_ai.usage.input_tokens", prompt_tokens);
+	}
+	if let Some(completion_tokens) = usage.completion_tokens {
+		span.record("gen_ai.usage.output_tokens", completion_tokens);
+	}
+}
+
+/// A low cardinality error type: the HTTP status code when the provider answered, otherwise the error class.
+fn error_type(err: &Error) -> String {
+	match err {
+		Error::WebModelCall {
+			webc_error: webc::Error::ResponseFailedStatus { status, .. },
+			..
+		} => status.as_u16().to_string(),
+		Error::RequestTimeout { .. } | Error::StreamIdleTimeout { .. } => "timeout".to_string(),
+		Error::Cancelled { .. } => "cancelled".to_string(),
+		_ => "_OTHER".to_string(),
+	}
+}
+
+// endregion: --- Recorders
+
+// region:    --- Stream
+
+/// Keep the span open for the stream lifetime, and record its end (finish reason, usage, and completion) or error on it.
+///
+/// Note: The usage and completion are recorded when captured (see the `capture_...` chat options).
+pub(crate) fn trace_chat_stream(stream: ChatStream, span: Span, content: bool) -> ChatStream {
+	let inter_stream = futures::stream::unfold((stream, span), move |(stream, span)| async move {
+		let mut inter_stream = stream.into_inter_stream();
+		let event = inter_stream.next().await?;
+
+		match &event {
+			Ok(InterStreamEvent::End(end)) => {
+				if let Some(finish_reason) = end.captured_finish_reason.as_deref() {
+					span.record("gen_ai.response.finish_reasons", finish_reason);
+				}
+				if let Some(usage) = end.captured_usage.as_ref() {
+					record_usage(&span, usage);
+				}
+				if content && let Some(text) = end.captured_text_content.as_ref() {
+					tracing::event!(name: "gen_ai.content.completion", parent: &span, Level::INFO, gen_ai.completion = text.as_str());
+				}
+			}
+			Err(err) => record_error(&span, err),
+			_ => (),
+		}
+
+		Some((event, (ChatStream::new(inter_stream), span)))
+	});
+
+	ChatStream::from_inter_stream(inter_stream)
+}
+
+// endregion: --- Stream
@@ -0,0 +1,25 @@
+use crate::ModelIden;
+use serde::{Deserialize, Serialize};
+
+/// A failed attempt on a model of a fallback chain (see `ChatOptions::with_fallback_models`).
+///
+/// Reported in `ChatResponse.fallback_attempts` / `EmbedResponse.fallback_attempts`,
+/// in the order the models were tried.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FallbackAttempt {
+	/// The model that failed.
+	pub model_iden: ModelIden,
+
+	/// The error message of the failure.
+	pub error: String,
+}
+
+/// Constructors
+impl FallbackAttempt {
+	pub(crate) fn new(model_iden: ModelIden, error: &crate::Error) -> Self {
+		Self {
+			model_iden,
+			error: error.to_string(),
+		}
+	}
+}
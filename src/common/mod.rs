@@ -1,9 +1,13 @@
 // region:    --- Modules
 
+mod fallback_attempt;
 mod model_iden;
+mod model_info;
 mod model_name;
 
+pub use fallback_attempt::*;
 pub use model_iden::*;
+pub use model_info::*;
 pub use model_name::*;
 
 // endregion: --- Modules
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+/// A model served by a provider, as listed by `Client::list_models(..)`.
+///
+/// Only the `id` is always set; the other properties are set when the provider models endpoint gives them.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModelInfo {
+	/// The provider model name (e.g., `gpt-4o-mini`, `claude-sonnet-4-5`).
+	pub id: String,
+
+	/// The human readable name (e.g., Anthropic and Gemini).
+	pub display_name: Option<String>,
+
+	/// The creation (or release) date, as Unix time in seconds.
+	pub created: Option<i64>,
+
+	/// The owner organization (OpenAI compatible providers).
+	pub owned_by: Option<String>,
+
+	/// The context window size, in tokens (e.g., Groq, Together, the Gemini input token limit).
+	pub context_window: Option<u32>,
+}
+
+/// Constructors
+impl ModelInfo {
+	/// Create a model info with only the id (e.g., from the adapter static model names).
+	pub fn new(id: impl Into<String>) -> Self {
+		Self {
+			id: id.into(),
+			..Default::default()
+		}
+	}
+}
@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 
 /// Options for customizing embedding requests.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct EmbedOptions {
 	/// Custom headers to include in the request.
 	pub headers: Option<Headers>,
@@ -41,6 +42,11 @@ pub struct EmbedOptions {
 	/// Common values: "NONE", "START", "END"
 	/// Default: "END"
 	pub truncate: Option<String>,
+
+	/// Models to try, in order, when the requested model fails with a retryable error.
+	/// Not serialized, as it does not change the response (so it is not part of the `ResponseCache` key).
+	#[serde(skip_serializing)]
+	pub fallback_models: Option<Vec<String>>,
 }
 
 /// Constructors
@@ -100,6 +106,16 @@ impl EmbedOptions {
 		self.truncate = Some(truncate.into());
 		self
 	}
+
+	/// Set the ordered list of fallback models.
+	pub fn with_fallback_models<I>(mut self, models: I) -> Self
+	where
+		I: IntoIterator,
+		I::Item: Into<String>,
+	{
+		self.fallback_models = Some(models.into_iter().map(Into::into).collect());
+		self
+	}
 }
 
 /// Getters
@@ -143,6 +159,11 @@ impl EmbedOptions {
 	pub fn truncate(&self) -> Option<&str> {
 		self.truncate.as_deref()
 	}
+
+	/// Get the fallback models.
+	pub fn fallback_models(&self) -> Option<&[String]> {
+		self.fallback_models.as_deref()
+	}
 }
 
 // endregion: --- EmbedOptions
@@ -174,6 +195,16 @@ impl<'client, 'request> EmbedOptionsSet<'client, 'request> {
 		self
 	}
 
+	/// Get the raw request-level options.
+	pub(crate) fn request_options(&self) -> Option<&'request EmbedOptions> {
+		self.request_options
+	}
+
+	/// Get the raw client-level options.
+	pub(crate) fn client_options(&self) -> Option<&'client EmbedOptions> {
+		self.client_options
+	}
+
 	/// Get the effective headers (request overrides client).
 	pub fn headers(&self) -> Option<&Headers> {
 		self.request_options
@@ -231,6 +262,14 @@ impl<'client, 'request> EmbedOptionsSet<'client, 'request> {
 			.and_then(|o| o.truncate())
 			.or_else(|| self.client_options.and_then(|o| o.truncate()))
 	}
+
+	/// Get the effective fallback models (empty if none).
+	pub fn fallback_models(&self) -> &[String] {
+		self.request_options
+			.and_then(|o| o.fallback_models())
+			.or_else(|| self.client_options.and_then(|o| o.fallback_models()))
+			.unwrap_or(&[])
+	}
 }
 
 // endregion: --- EmbedOptionsSet
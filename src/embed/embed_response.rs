@@ -1,7 +1,7 @@
 //! This module contains all the types related to an Embed Response.
 
-use crate::ModelIden;
 use crate::chat::Usage;
+use crate::{FallbackAttempt, ModelIden};
 use serde::{Deserialize, Serialize};
 
 // region:    --- EmbedResponse
@@ -24,6 +24,14 @@ pub struct EmbedResponse {
 
 	/// The raw value of the response body, which can be used for provider specific features.
 	pub captured_raw_body: Option<serde_json::Value>,
+
+	/// The models that failed before `model_iden` answered (empty when no fallback was needed).
+	#[serde(default)]
+	pub fallback_attempts: Vec<FallbackAttempt>,
+
+	/// True when served from the client `ResponseCache`.
+	#[serde(default)]
+	pub cached: bool,
 }
 
 /// Constructors
@@ -41,6 +49,8 @@ impl EmbedResponse {
 			provider_model_iden,
 			usage,
 			captured_raw_body: None,
+			fallback_attempts: Vec::new(),
+			cached: false,
 		}
 	}
 
@@ -1,7 +1,11 @@
-use crate::adapter::AdapterKind;
-use crate::chat::ChatRole;
+use crate::adapter::{AdapterDispatcher, AdapterKind};
+use crate::chat::{BatchStatus, ChatRole};
 use crate::{ModelIden, resolver, webc};
 use derive_more::{Display, From};
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+use serde_json::Value;
+use std::time::Duration;
 use value_ext::JsonValueExtError;
 
 /// GenAI main Result type alias (with genai::Error)
@@ -27,6 +31,12 @@ pub enum Error {
 	#[display("Content type not supported for model '{model_iden}'.\nCause: {cause}")]
 	MessageContentTypeNotSupported { model_iden: ModelIden, cause: &'static str },
 
+	#[display("Request not supported by model '{model_iden}':\n- {}", problems.join("\n- "))]
+	RequestValidation {
+		model_iden: ModelIden,
+		problems: Vec<String>,
+	},
+
 	#[display("JSON mode requested but no instruction/prompt provided.")]
 	JsonModeWithoutInstruction,
 
@@ -98,6 +108,46 @@ pub enum Error {
 	#[display("Web stream error for model '{model_iden}'.\nCause: {cause}")]
 	WebStream { model_iden: ModelIden, cause: String },
 
+	// -- Deadline & Cancellation
+	#[display("Request for model '{model_iden}' timed out after {timeout:?}")]
+	RequestTimeout { model_iden: ModelIden, timeout: Duration },
+
+	#[display("Stream for model '{model_iden}' received no event for {timeout:?}")]
+	StreamIdleTimeout { model_iden: ModelIden, timeout: Duration },
+
+	#[display("Request for model '{model_iden}' was cancelled")]
+	Cancelled { model_iden: ModelIden },
+
+	// -- Batch
+	#[display("Batch '{batch_id}' for model '{model_iden}' has no results yet (status: {status:?})")]
+	BatchNotCompleted {
+		model_iden: ModelIden,
+		batch_id: String,
+		status: BatchStatus,
+	},
+
+	#[display("Batch request '{custom_id}' failed for model '{model_iden}'.\nCause: {cause}")]
+	BatchRequestFailed {
+		model_iden: ModelIden,
+		custom_id: String,
+		cause: String,
+	},
+
+	#[display("Invalid batch response for model '{model_iden}'.\nCause: {cause}")]
+	BatchResponse { model_iden: ModelIden, cause: String },
+
+	// -- Model Listing
+	#[display("Invalid model list response for adapter '{adapter_kind}'.\nCause: {cause}")]
+	ModelListResponse { adapter_kind: AdapterKind, cause: String },
+
+	// -- Client Config
+	#[display("Invalid client config document.\nCause: {cause}")]
+	ClientConfigFile { cause: String },
+
+	// -- Audit
+	#[display("Invalid audit redaction pattern '{pattern}'.\nCause: {cause}")]
+	AuditRedactionPattern { pattern: String, cause: String },
+
 	// -- Modules
 	#[display("Resolver error for model '{model_iden}'.\nCause: {resolver_error}")]
 	Resolver {
@@ -105,6 +155,10 @@ pub enum Error {
 		resolver_error: resolver::Error,
 	},
 
+	// -- Middleware
+	#[display("Middleware error: {_0}")]
+	Middleware(String),
+
 	// -- Adapter Support
 	#[display("Adapter '{adapter_kind}' does not support feature '{feature}'")]
 	AdapterNotSupported { adapter_kind: AdapterKind, feature: String },
@@ -129,6 +183,216 @@ pub enum Error {
 	SerdeJson(serde_json::Error),
 }
 
+// region:    --- Classification
+
+/// Classification helpers
+impl Error {
+	/// Returns the provider error (normalized kind, provider code, message, and request id)
+	/// when the provider answered with an error status or a stream error event.
+	///
+	/// The error body is parsed by the adapter of the model, on each call.
+	pub fn provider_error(&self) -> Option<ProviderError> {
+		match self {
+			Error::WebModelCall { model_iden, webc_error } => {
+				Self::failed_status_provider_error(model_iden, webc_error)
+			}
+			// An adapter level call (e.g., the model listing), so without a model name.
+			Error::WebAdapterCall {
+				adapter_kind,
+				webc_error,
+			} => Self::failed_status_provider_error(&ModelIden::new(*adapter_kind, ""), webc_error),
+			Error::ChatResponse { model_iden, body } => {
+				Some(AdapterDispatcher::to_provider_error(model_iden, None, None, body))
+			}
+			// The stream response body is not read, so only the status (and headers) are known.
+			Error::ReqwestEventSource(err) => match err.as_ref() {
+				reqwest_eventsource::Error::InvalidStatusCode(status, res) => {
+					Some(ProviderError::new(Some(*status), Some(res.headers())))
+				}
+				_ => None,
+			},
+			_ => None,
+		}
+	}
+
+	/// The provider error of a failed status (the body is parsed by the adapter of the model).
+	fn failed_status_provider_error(model_iden: &ModelIden, webc_error: &webc::Error) -> Option<ProviderError> {
+		let webc::Error::ResponseFailedStatus { status, body, headers } = webc_error else {
+			return None;
+		};
+		// A non JSON body (e.g., from a proxy) is taken as the message.
+		let body = match serde_json::from_str(body) {
+			Ok(body) => body,
+			Err(_) if body.trim().is_empty() => Value::Null,
+			Err(_) => Value::String(body.to_string()),
+		};
+		Some(AdapterDispatcher::to_provider_error(
+			model_iden,
+			Some(*status),
+			Some(headers),
+			&body,
+		))
+	}
+
+	/// Returns the normalized kind of this error.
+	pub fn kind(&self) -> ErrorKind {
+		if let Some(provider_error) = self.provider_error() {
+			return provider_error.kind;
+		}
+
+		match self {
+			Error::RequestTimeout { .. } | Error::StreamIdleTimeout { .. } => ErrorKind::Timeout,
+			Error::Cancelled { .. } => ErrorKind::Cancelled,
+			Error::RequiresApiKey { .. } | Error::NoAuthResolver { .. } | Error::NoAuthData { .. } => {
+				ErrorKind::InvalidAuth
+			}
+			Error::WebModelCall {
+				webc_error: webc::Error::Reqwest(err),
+				..
+			}
+			| Error::WebAdapterCall {
+				webc_error: webc::Error::Reqwest(err),
+				..
+			} => reqwest_error_kind(err),
+			Error::ReqwestEventSource(err) => match err.as_ref() {
+				reqwest_eventsource::Error::Transport(err) => reqwest_error_kind(err),
+				_ => ErrorKind::Other,
+			},
+			Error::WebStream { .. } => ErrorKind::Network,
+			Error::RequestValidation { .. } => ErrorKind::BadRequest,
+			_ => ErrorKind::Other,
+		}
+	}
+
+	/// Returns true if the same request may succeed later
+	/// (rate limited, overloaded, server, timeout, or network error).
+	///
+	/// Note: Quota, context length, content filter, auth, and bad request errors are not retryable,
+	///       even when the provider answered with a usually retryable status (e.g., a `429` for an exhausted quota).
+	pub fn is_retryable(&self) -> bool {
+		self.kind().is_retryable()
+	}
+}
+
+fn reqwest_error_kind(err: &reqwest::Error) -> ErrorKind {
+	match err.status() {
+		Some(status) => ErrorKind::from_status(status),
+		None if err.is_timeout() => ErrorKind::Timeout,
+		None if err.is_connect() || err.is_request() || err.is_body() => ErrorKind::Network,
+		None => ErrorKind::Other,
+	}
+}
+
+// endregion: --- Classification
+
+// region:    --- ErrorKind
+
+/// Normalized classification of an error, across providers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display)]
+pub enum ErrorKind {
+	/// Too many requests or tokens for the current rate limit window.
+	RateLimited,
+	/// The account quota or credit is exhausted (billing).
+	QuotaExhausted,
+	/// The prompt (or prompt and max tokens) does not fit the model context window.
+	ContextLengthExceeded,
+	/// The prompt or completion was blocked by the provider content filter or safety settings.
+	ContentFiltered,
+	/// Missing, invalid, or unauthorized API key.
+	InvalidAuth,
+	/// Unknown model, or not accessible with this API key.
+	ModelNotFound,
+	/// The provider is temporarily overloaded or unavailable.
+	Overloaded,
+	/// The provider rejected the request (invalid parameter, unsupported content, ...).
+	BadRequest,
+	/// The provider failed to process the request (5xx).
+	ServerError,
+	/// The call or the stream timed out.
+	Timeout,
+	/// The connection failed or dropped.
+	Network,
+	/// The call was cancelled.
+	Cancelled,
+	/// Any other error.
+	Other,
+}
+
+impl ErrorKind {
+	/// Returns true for the transient kinds (rate limited, overloaded, server, timeout, network).
+	pub fn is_retryable(&self) -> bool {
+		matches!(
+			self,
+			ErrorKind::RateLimited
+				| ErrorKind::Overloaded
+				| ErrorKind::ServerError
+				| ErrorKind::Timeout
+				| ErrorKind::Network
+		)
+	}
+
+	/// The kind of a provider error status, when nothing more specific is known.
+	pub fn from_status(status: StatusCode) -> Self {
+		match status.as_u16() {
+			401 | 403 => ErrorKind::InvalidAuth,
+			404 => ErrorKind::ModelNotFound,
+			408 => ErrorKind::Timeout,
+			413 => ErrorKind::ContextLengthExceeded,
+			429 => ErrorKind::RateLimited,
+			503 | 529 => ErrorKind::Overloaded,
+			500..=599 => ErrorKind::ServerError,
+			400..=499 => ErrorKind::BadRequest,
+			_ => ErrorKind::Other,
+		}
+	}
+}
+
+// endregion: --- ErrorKind
+
+// region:    --- ProviderError
+
+/// An error returned by a provider, parsed from its error body by the adapter.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProviderError {
+	/// The normalized classification.
+	pub kind: ErrorKind,
+
+	/// The HTTP status (`None` for a stream error event).
+	pub status: Option<StatusCode>,
+
+	/// The provider error code or type (e.g., `insufficient_quota`, `overloaded_error`, `RESOURCE_EXHAUSTED`).
+	pub code: Option<String>,
+
+	/// The provider error message.
+	pub message: Option<String>,
+
+	/// The provider request id (from the body or the response headers), to quote to the provider support.
+	pub request_id: Option<String>,
+}
+
+impl ProviderError {
+	/// A provider error known only by its status (and response headers), to be completed by the adapter.
+	pub(crate) fn new(status: Option<StatusCode>, headers: Option<&HeaderMap>) -> Self {
+		ProviderError {
+			kind: status.map(ErrorKind::from_status).unwrap_or(ErrorKind::Other),
+			status,
+			code: None,
+			message: None,
+			request_id: headers.and_then(request_id_from_headers),
+		}
+	}
+}
+
+/// The request id headers of the providers (`x-request-id` for OpenAI compatible APIs, `request-id` for Anthropic).
+fn request_id_from_headers(headers: &HeaderMap) -> Option<String> {
+	["x-request-id", "request-id"]
+		.into_iter()
+		.find_map(|name| headers.get(name)?.to_str().ok())
+		.map(String::from)
+}
+
+// endregion: --- ProviderError
+
 // region:    --- Error Boilerplate
 
 // The Display trait is now derived via derive_more::Display
@@ -15,7 +15,7 @@ pub enum AuthData {
 
 	/// The key names/values when a credential has multiple pieces of credential information.
 	/// This will be adapter-specific.
-	/// NOTE: Not used yet.
+	/// Also the named keys of an `ApiKeyPool` (see `ApiKeyPool::with_keys`).
 	MultiKeys(HashMap<String, String>),
 }
 
@@ -4,7 +4,7 @@
 //! - Contains a fixed auth value,
 //! - Contains an `AuthResolverFn` trait object or closure that will be called to return the `AuthData`.
 //!
-//! Note: `AuthData` is typically a single value but can be multiple for future adapters (e.g., AWS Bedrock).
+//! Note: `AuthData` is typically a single value but can be multiple (e.g., the AWS credentials and region of the Bedrock adapter).
 
 use crate::ModelIden;
 use crate::resolver::{AuthData, Result};
@@ -0,0 +1,282 @@
+use crate::webc::{Error, Result, WebStreamRequest};
+use bytes::Bytes;
+use futures::stream::BoxStream;
+use futures::{Stream, StreamExt};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// AwsEventStream decodes the AWS binary event stream (`application/vnd.amazon.eventstream`),
+/// as sent by the Bedrock `ConverseStream` API.
+/// - Each message is `total length (u32) | headers length (u32) | prelude CRC32 | headers | payload | message CRC32`.
+/// - Only the string headers are kept (e.g., `:message-type`, `:event-type`, `:exception-type`).
+/// - A failed status is returned as a `ResponseFailedStatus` error with the response body (not framed).
+pub struct AwsEventStream {
+	inner: BoxStream<'static, Result<AwsEventMessage>>,
+}
+
+impl AwsEventStream {
+	pub fn new(stream_request: WebStreamRequest) -> Self {
+		let inner = futures::stream::unfold(StreamState::Request(stream_request), next_message);
+		Self { inner: inner.boxed() }
+	}
+}
+
+impl Stream for AwsEventStream {
+	type Item = Result<AwsEventMessage>;
+
+	fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		self.inner.as_mut().poll_next(cx)
+	}
+}
+
+// region:    --- AwsEventMessage
+
+#[derive(Debug, Clone)]
+pub struct AwsEventMessage {
+	headers: Vec<(String, String)>,
+	payload: Bytes,
+}
+
+impl AwsEventMessage {
+	pub fn header(&self, name: &str) -> Option<&str> {
+		self.headers.iter().find(|(n, _)| n == name).map(|(_, v)| v.as_str())
+	}
+
+	/// `event` or `exception` (or `error`)
+	pub fn message_type(&self) -> Option<&str> {
+		self.header(":message-type")
+	}
+
+	pub fn event_type(&self) -> Option<&str> {
+		self.header(":event-type")
+	}
+
+	pub fn exception_type(&self) -> Option<&str> {
+		self.header(":exception-type")
+	}
+
+	pub fn payload(&self) -> &[u8] {
+		&self.payload
+	}
+}
+
+// endregion: --- AwsEventMessage
+
+// region:    --- Stream State
+
+enum StreamState {
+	Request(WebStreamRequest),
+	Streaming {
+		bytes_stream: BoxStream<'static, reqwest::Result<Bytes>>,
+		buffer: Vec<u8>,
+	},
+	Done,
+}
+
+async fn next_message(mut state: StreamState) -> Option<(Result<AwsEventMessage>, StreamState)> {
+	loop {
+		match state {
+			StreamState::Request(stream_request) => {
+				let res = match stream_request.send().await {
+					Ok(res) => res,
+					Err(err) => return Some((Err(err.into()), StreamState::Done)),
+				};
+				let status = res.status();
+				if !status.is_success() {
+					let headers = Box::new(res.headers().clone());
+					let body = res.text().await.unwrap_or_default();
+					let err = Error::ResponseFailedStatus { status, body, headers };
+					return Some((Err(err), StreamState::Done));
+				}
+				state = StreamState::Streaming {
+					bytes_stream: res.bytes_stream().boxed(),
+					buffer: Vec::new(),
+				};
+			}
+			StreamState::Streaming {
+				mut bytes_stream,
+				mut buffer,
+			} => {
+				match decode_message(&mut buffer) {
+					Ok(Some(message)) => return Some((Ok(message), StreamState::Streaming { bytes_stream, buffer })),
+					Ok(None) => (),
+					Err(err) => return Some((Err(err), StreamState::Done)),
+				}
+				match bytes_stream.next().await {
+					Some(Ok(bytes)) => {
+						buffer.extend_from_slice(&bytes);
+						state = StreamState::Streaming { bytes_stream, buffer };
+					}
+					Some(Err(err)) => return Some((Err(err.into()), StreamState::Done)),
+					None if buffer.is_empty() => return None,
+					None => {
+						let err = Error::AwsEventStream {
+							cause: format!("stream ended within a message ({} bytes left)", buffer.len()),
+						};
+						return Some((Err(err), StreamState::Done));
+					}
+				}
+			}
+			StreamState::Done => return None,
+		}
+	}
+}
+
+// endregion: --- Stream State
+
+// region:    --- Decoding
+
+const PRELUDE_LEN: usize = 12;
+const MESSAGE_CRC_LEN: usize = 4;
+
+/// Takes the first complete message of the buffer, if any.
+fn decode_message(buffer: &mut Vec<u8>) -> Result<Option<AwsEventMessage>> {
+	if buffer.len() < PRELUDE_LEN {
+		return Ok(None);
+	}
+
+	let total_len = read_u32(&buffer[0..4]) as usize;
+	let headers_len = read_u32(&buffer[4..8]) as usize;
+	if crc32(&buffer[0..8]) != read_u32(&buffer[8..12]) {
+		return Err(frame_error("prelude CRC mismatch"));
+	}
+	if total_len < PRELUDE_LEN + headers_len + MESSAGE_CRC_LEN {
+		return Err(frame_error("invalid message length"));
+	}
+	if buffer.len() < total_len {
+		return Ok(None);
+	}
+
+	let message: Vec<u8> = buffer.drain(..total_len).collect();
+	let crc_start = total_len - MESSAGE_CRC_LEN;
+	if crc32(&message[..crc_start]) != read_u32(&message[crc_start..]) {
+		return Err(frame_error("message CRC mismatch"));
+	}
+
+	let headers = decode_headers(&message[PRELUDE_LEN..PRELUDE_LEN + headers_len])?;
+	let payload = Bytes::copy_from_slice(&message[PRELUDE_LEN + headers_len..crc_start]);
+
+	Ok(Some(AwsEventMessage { headers, payload }))
+}
+
+/// Header: `name length (u8) | name | value type (u8) | value`
+fn decode_headers(mut bytes: &[u8]) -> Result<Vec<(String, String)>> {
+	let mut headers = Vec::new();
+	while !bytes.is_empty() {
+		let name_len = bytes[0] as usize;
+		let name = take(&mut bytes, 1 + name_len)?;
+		let name = String::from_utf8_lossy(&name[1..]).to_string();
+		let value_type = take(&mut bytes, 1)?[0];
+		let value_len = match value_type {
+			0 | 1 => 0, // bool true / false
+			2 => 1,     // byte
+			3 => 2,     // short
+			4 => 4,     // int
+			5 | 8 => 8, // long, timestamp
+			6 | 7 => {
+				// bytes, string (u16 length)
+				let len = take(&mut bytes, 2)?;
+				u16::from_be_bytes([len[0], len[1]]) as usize
+			}
+			9 => 16, // uuid
+			other => return Err(frame_error(&format!("unknown header value type {other}"))),
+		};
+		let value = take(&mut bytes, value_len)?;
+		if value_type == 7 {
+			headers.push((name, String::from_utf8_lossy(value).to_string()));
+		}
+	}
+	Ok(headers)
+}
+
+fn take<'a>(bytes: &mut &'a [u8], len: usize) -> Result<&'a [u8]> {
+	if bytes.len() < len {
+		return Err(frame_error("truncated headers"));
+	}
+	let (head, rest) = bytes.split_at(len);
+	*bytes = rest;
+	Ok(head)
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+	u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+fn frame_error(cause: &str) -> Error {
+	Error::AwsEventStream {
+		cause: cause.to_string(),
+	}
+}
+
+/// CRC32 (IEEE), as used by the event stream prelude and message checksums.
+fn crc32(bytes: &[u8]) -> u32 {
+	let mut crc = !0u32;
+	for &byte in bytes {
+		crc ^= byte as u32;
+		for _ in 0..8 {
+			let mask = (crc & 1).wrapping_neg();
+			crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+		}
+	}
+	!crc
+}
+
+// endregion: --- Decoding
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+
+	fn encode_message(headers: &[(&str, &str)], payload: &[u8]) -> Vec<u8> {
+		let mut header_bytes = Vec::new();
+		for (name, value) in headers {
+			header_bytes.push(name.len() as u8);
+			header_bytes.extend_from_slice(name.as_bytes());
+			header_bytes.push(7);
+			header_bytes.extend_from_slice(&(value.len() as u16).to_be_bytes());
+			header_bytes.extend_from_slice(value.as_bytes());
+		}
+		let total_len = PRELUDE_LEN + header_bytes.len() + payload.len() + MESSAGE_CRC_LEN;
+		let mut message = Vec::new();
+		message.extend_from_slice(&(total_len as u32).to_be_bytes());
+		message.extend_from_slice(&(header_bytes.len() as u32).to_be_bytes());
+		message.extend_from_slice(&crc32(&message).to_be_bytes());
+		message.extend_from_slice(&header_bytes);
+		message.extend_from_slice(payload);
+		message.extend_from_slice(&crc32(&message).to_be_bytes());
+		message
+	}
+
+	#[test]
+	fn test_aws_event_stream_decode_message() -> Result<()> {
+		// -- Setup & Fixtures
+		let headers = [(":message-type", "event"), (":event-type", "contentBlockDelta")];
+		let payload = br#"{"contentBlockIndex":0,"delta":{"text":"Hello"}}"#;
+		let message = encode_message(&headers, payload);
+		let mut buffer = message[..20].to_vec();
+
+		// -- Exec & Check
+		assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+		assert!(decode_message(&mut buffer)?.is_none(), "partial message");
+		buffer.extend_from_slice(&message[20..]);
+		buffer.extend_from_slice(&message);
+		let first = decode_message(&mut buffer)?.ok_or("should have a message")?;
+		assert_eq!(first.message_type(), Some("event"));
+		assert_eq!(first.event_type(), Some("contentBlockDelta"));
+		assert_eq!(first.payload(), payload);
+		assert!(decode_message(&mut buffer)?.is_some());
+		assert!(buffer.is_empty());
+
+		let mut corrupted = message.clone();
+		corrupted[PRELUDE_LEN + 2] ^= 0xFF;
+		assert!(decode_message(&mut corrupted).is_err());
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,542 @@
+//! Cassettes record the web calls of the `WebClient` to a JSON file and replay them, so that provider tests
+//! can run without network access or API keys.
+//!
+//! - Set with `ClientBuilder::with_cassette(..)`.
+//! - `CassetteMode::Record` calls the provider and writes each request/response pair to the cassette file.
+//!   Streaming bodies (SSE and `WebStream`) are recorded chunk by chunk.
+//! - `CassetteMode::Replay` never calls the provider. Requests are matched on method, URL, and payload
+//!   (compared as JSON values, so key order and formatting do not matter), in recording order.
+//! - Secrets (e.g., `Authorization`, `x-api-key`, `x-amz-security-token` headers, `key` URL query parameter) are redacted when recording.
+
+use crate::Headers;
+use crate::webc::{Error, Result, WebResponse, new_reqwest_response};
+use bytes::Bytes;
+use futures::Stream;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Method, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll};
+
+const REDACTED: &str = "[REDACTED]";
+
+/// Request/response header names whose values are never written to a cassette.
+const SECRET_HEADERS: &[&str] = &[
+	"authorization",
+	"proxy-authorization",
+	"x-api-key",
+	"x-goog-api-key",
+	"api-key",
+	// AWS SigV4 temporary credentials (e.g., Bedrock)
+	"x-amz-security-token",
+	"cookie",
+	"set-cookie",
+];
+
+/// URL query parameters whose values are never written to a cassette (e.g., Gemini `?key=`).
+const SECRET_QUERY_PARAMS: &[&str] = &["key", "api-key", "api_key"];
+
+// region:    --- Cassette
+
+/// Whether a `Cassette` records or replays the web calls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+	/// Call the provider and write the interactions to the cassette file (overwriting it).
+	Record,
+	/// Serve the interactions from the cassette file, without calling the provider.
+	Replay,
+}
+
+/// A file of recorded web interactions (see module documentation).
+///
+/// Cloning a `Cassette` shares the same interactions.
+#[derive(Debug, Clone)]
+pub struct Cassette {
+	path: PathBuf,
+	mode: CassetteMode,
+	state: Arc<Mutex<CassetteState>>,
+}
+
+#[derive(Debug, Default)]
+struct CassetteState {
+	/// `None` until loaded from the file (replay mode).
+	interactions: Option<Vec<Interaction>>,
+	/// Replay only, the interactions already served.
+	used: Vec<bool>,
+}
+
+/// Constructors
+impl Cassette {
+	/// Create a cassette with the given mode.
+	pub fn new(path: impl Into<PathBuf>, mode: CassetteMode) -> Self {
+		let interactions = match mode {
+			CassetteMode::Record => Some(Vec::new()),
+			// Loaded on first use, so the errors are reported by the calls.
+			CassetteMode::Replay => None,
+		};
+		Self {
+			path: path.into(),
+			mode,
+			state: Arc::new(Mutex::new(CassetteState {
+				interactions,
+				used: Vec::new(),
+			})),
+		}
+	}
+
+	/// Create a cassette recording to `path`.
+	pub fn record(path: impl Into<PathBuf>) -> Self {
+		Self::new(path, CassetteMode::Record)
+	}
+
+	/// Create a cassette replaying from `path`.
+	pub fn replay(path: impl Into<PathBuf>) -> Self {
+		Self::new(path, CassetteMode::Replay)
+	}
+}
+
+/// Getters
+impl Cassette {
+	pub fn path(&self) -> &Path {
+		&self.path
+	}
+
+	pub fn mode(&self) -> CassetteMode {
+		self.mode
+	}
+}
+
+/// Crate internals
+impl Cassette {
+	/// Returns the recorded response of the first not yet served interaction matching the request
+	/// (or of the last matching one if all were served).
+	pub(crate) fn replay_response(&self, request: &RecordedRequest) -> Result<RecordedResponse> {
+		let mut state = self.lock_state();
+
+		if state.interactions.is_none() {
+			let content = std::fs::read_to_string(&self.path).map_err(|err| self.cassette_error(err))?;
+			let file: CassetteFile = serde_json::from_str(&content).map_err(|err| self.cassette_error(err))?;
+			state.used = vec![false; file.interactions.len()];
+			state.interactions = Some(file.interactions);
+		}
+
+		let CassetteState { interactions, used } = &mut *state;
+		let interactions = interactions.as_deref().unwrap_or_default();
+		let matching: Vec<usize> = interactions
+			.iter()
+			.enumerate()
+			.filter(|(_, interaction)| interaction.request.matches(request))
+			.map(|(idx, _)| idx)
+			.collect();
+
+		let idx = matching
+			.iter()
+			.find(|idx| !used[**idx])
+			.or(matching.last())
+			.copied()
+			.ok_or_else(|| Error::CassetteNoMatch {
+				method: request.method.clone(),
+				url: request.url.clone(),
+			})?;
+		used[idx] = true;
+
+		tracing::debug!(method = %request.method, url = %request.url, "replayed from cassette");
+		Ok(interactions[idx].response.clone())
+	}
+
+	/// Appends the interaction and writes the cassette file.
+	pub(crate) fn push_interaction(&self, request: RecordedRequest, response: RecordedResponse) -> Result<()> {
+		let mut state = self.lock_state();
+		let interactions = state.interactions.get_or_insert_with(Vec::new);
+		interactions.push(Interaction { request, response });
+
+		let file = CassetteFile {
+			interactions: interactions.clone(),
+		};
+		let content = serde_json::to_string_pretty(&file).map_err(|err| self.cassette_error(err))?;
+		if let Some(dir) = self.path.parent()
+			&& !dir.as_os_str().is_empty()
+		{
+			std::fs::create_dir_all(dir).map_err(|err| self.cassette_error(err))?;
+		}
+		std::fs::write(&self.path, content).map_err(|err| self.cassette_error(err))?;
+
+		Ok(())
+	}
+
+	/// Returns a response whose body is recorded, chunk by chunk, as it is read.
+	/// The interaction is written when the body ends (or is dropped).
+	pub(crate) fn record_stream_response(&self, request: RecordedRequest, res: reqwest::Response) -> reqwest::Response {
+		let status = res.status();
+		let headers = res.headers().clone();
+		let body = RecordingBody {
+			inner: Box::pin(res.bytes_stream()),
+			recorder: Some(StreamRecorder {
+				cassette: self.clone(),
+				request,
+				status,
+				headers: headers.clone(),
+				chunks: Vec::new(),
+				pending: Vec::new(),
+			}),
+		};
+		new_reqwest_response(status, headers, reqwest::Body::wrap_stream(body))
+	}
+
+	fn lock_state(&self) -> MutexGuard<'_, CassetteState> {
+		self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+	}
+
+	fn cassette_error(&self, cause: impl std::fmt::Display) -> Error {
+		Error::Cassette {
+			path: self.path.to_string_lossy().to_string(),
+			cause: cause.to_string(),
+		}
+	}
+}
+
+// endregion: --- Cassette
+
+// region:    --- Recorded Types
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CassetteFile {
+	interactions: Vec<Interaction>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+	request: RecordedRequest,
+	response: RecordedResponse,
+}
+
+/// A request as written in a cassette (secrets redacted).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct RecordedRequest {
+	method: String,
+	url: String,
+	headers: BTreeMap<String, String>,
+	#[serde(default)]
+	payload: Value,
+}
+
+impl RecordedRequest {
+	pub(crate) fn new<'a>(
+		method: &Method,
+		url: &str,
+		headers: impl IntoIterator<Item = (&'a String, &'a String)>,
+		payload: Option<&Value>,
+	) -> Self {
+		let headers = headers
+			.into_iter()
+			.map(|(name, value)| (name.to_lowercase(), value.clone()))
+			.collect();
+		Self {
+			method: method.to_string(),
+			url: redact_url(url),
+			headers: redact_headers(headers),
+			payload: payload.cloned().unwrap_or_default(),
+		}
+	}
+
+	pub(crate) fn from_headers(method: &Method, url: &str, headers: &Headers, payload: &Value) -> Self {
+		Self::new(method, url, headers.iter(), Some(payload))
+	}
+
+	fn matches(&self, other: &RecordedRequest) -> bool {
+		self.method == other.method && self.url == other.url && self.payload == other.payload
+	}
+}
+
+/// A response as written in a cassette (secrets redacted).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct RecordedResponse {
+	status: u16,
+	headers: BTreeMap<String, String>,
+	body: RecordedBody,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum RecordedBody {
+	/// A JSON body (successful non-streaming response).
+	Json(Value),
+	/// A text body (failed or non-JSON response).
+	Text(String),
+	/// A streaming body, as the chunks were received.
+	Chunks(Vec<String>),
+}
+
+impl RecordedResponse {
+	/// Read the full (non-streaming) response.
+	pub(crate) async fn from_reqwest_response(res: reqwest::Response) -> Result<Self> {
+		let status = res.status();
+		let headers = header_map_to_btree(res.headers());
+
+		let is_json = headers
+			.get("content-type")
+			.is_some_and(|content_type| content_type.starts_with("application/json"));
+		let body = if status.is_success() && is_json {
+			RecordedBody::Json(res.json::<Value>().await?)
+		} else {
+			RecordedBody::Text(res.text().await?)
+		};
+
+		Ok(Self {
+			status: status.as_u16(),
+			headers: redact_headers(headers),
+			body,
+		})
+	}
+
+	/// Same result as `WebResponse::from_reqwest_response` for the original response.
+	pub(crate) fn into_web_response(self) -> Result<WebResponse> {
+		let status = self.status_code();
+		let headers = btree_to_header_map(&self.headers);
+
+		match self.body {
+			_ if !status.is_success() => Err(Error::ResponseFailedStatus {
+				status,
+				body: self.body.into_text(),
+				headers: Box::new(headers),
+			}),
+			RecordedBody::Json(body) => Ok(WebResponse { status, body, headers }),
+			RecordedBody::Text(_) | RecordedBody::Chunks(_) => Err(Error::ResponseFailedNotJson {
+				content_type: self.headers.get("content-type").cloned().unwrap_or_default(),
+			}),
+		}
+	}
+
+	/// Same result as `WebClient::do_get_text` for the original response.
+	pub(crate) fn into_text(self) -> Result<String> {
+		let status = self.status_code();
+		if !status.is_success() {
+			return Err(Error::ResponseFailedStatus {
+				status,
+				body: self.body.into_text(),
+				headers: Box::new(btree_to_header_map(&self.headers)),
+			});
+		}
+		Ok(self.body.into_text())
+	}
+
+	/// Build a `reqwest::Response` streaming the recorded chunks.
+	pub(crate) fn into_reqwest_response(self) -> reqwest::Response {
+		let status = self.status_code();
+		let headers = btree_to_header_map(&self.headers);
+		let chunks = match self.body {
+			RecordedBody::Chunks(chunks) => chunks,
+			body => vec![body.into_text()],
+		};
+		let chunks = chunks.into_iter().map(Ok::<_, std::io::Error>);
+		new_reqwest_response(
+			status,
+			headers,
+			reqwest::Body::wrap_stream(futures::stream::iter(chunks)),
+		)
+	}
+
+	fn status_code(&self) -> StatusCode {
+		StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+	}
+}
+
+impl RecordedBody {
+	fn into_text(self) -> String {
+		match self {
+			RecordedBody::Json(value) => value.to_string(),
+			RecordedBody::Text(text) => text,
+			RecordedBody::Chunks(chunks) => chunks.concat(),
+		}
+	}
+}
+
+// endregion: --- Recorded Types
+
+// region:    --- Stream Recording
+
+/// Byte stream recording the chunks of a streaming response.
+struct RecordingBody {
+	inner: Pin<Box<dyn Stream<Item = reqwest::Result<Bytes>> + Send>>,
+	recorder: Option<StreamRecorder>,
+}
+
+struct StreamRecorder {
+	cassette: Cassette,
+	request: RecordedRequest,
+	status: StatusCode,
+	headers: HeaderMap,
+	chunks: Vec<String>,
+	/// Trailing bytes of an incomplete UTF-8 character, kept for the next chunk.
+	pending: Vec<u8>,
+}
+
+impl StreamRecorder {
+	fn push(&mut self, bytes: &[u8]) {
+		self.pending.extend_from_slice(bytes);
+		let valid_len = match std::str::from_utf8(&self.pending) {
+			Ok(_) => self.pending.len(),
+			// Incomplete character at the end, keep it for the next chunk.
+			Err(err) if err.error_len().is_none() => err.valid_up_to(),
+			// Invalid UTF-8, recorded lossy.
+			Err(_) => self.pending.len(),
+		};
+		let rest = self.pending.split_off(valid_len);
+		let chunk = String::from_utf8_lossy(&self.pending).to_string();
+		self.pending = rest;
+		if !chunk.is_empty() {
+			self.chunks.push(chunk);
+		}
+	}
+
+	fn commit(mut self) {
+		if !self.pending.is_empty() {
+			self.chunks.push(String::from_utf8_lossy(&self.pending).to_string());
+		}
+		let response = RecordedResponse {
+			status: self.status.as_u16(),
+			headers: redact_headers(header_map_to_btree(&self.headers)),
+			body: RecordedBody::Chunks(self.chunks),
+		};
+		if let Err(err) = self.cassette.push_interaction(self.request, response) {
+			tracing::warn!(%err, "failed to record stream interaction");
+		}
+	}
+}
+
+impl Stream for RecordingBody {
+	type Item = reqwest::Result<Bytes>;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		let this = self.get_mut();
+		let res = this.inner.as_mut().poll_next(cx);
+		match &res {
+			Poll::Ready(Some(Ok(bytes))) => {
+				if let Some(recorder) = this.recorder.as_mut() {
+					recorder.push(bytes);
+				}
+			}
+			Poll::Ready(Some(Err(_))) | Poll::Ready(None) => {
+				if let Some(recorder) = this.recorder.take() {
+					recorder.commit();
+				}
+			}
+			Poll::Pending => (),
+		}
+		res
+	}
+}
+
+impl Drop for RecordingBody {
+	fn drop(&mut self) {
+		// Body not fully read, record what was received.
+		if let Some(recorder) = self.recorder.take() {
+			recorder.commit();
+		}
+	}
+}
+
+// endregion: --- Stream Recording
+
+// region:    --- Support
+
+fn header_map_to_btree(headers: &HeaderMap) -> BTreeMap<String, String> {
+	headers
+		.iter()
+		.filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+		.collect()
+}
+
+fn btree_to_header_map(headers: &BTreeMap<String, String>) -> HeaderMap {
+	headers
+		.iter()
+		.filter_map(|(name, value)| {
+			let name = HeaderName::from_bytes(name.as_bytes()).ok()?;
+			let value = HeaderValue::from_str(value).ok()?;
+			Some((name, value))
+		})
+		.collect()
+}
+
+fn redact_headers(mut headers: BTreeMap<String, String>) -> BTreeMap<String, String> {
+	for (name, value) in headers.iter_mut() {
+		if SECRET_HEADERS.contains(&name.as_str()) {
+			*value = REDACTED.to_string();
+		}
+	}
+	headers
+}
+
+fn redact_url(url: &str) -> String {
+	let Some((base, query)) = url.split_once('?') else {
+		return url.to_string();
+	};
+	let query = query
+		.split('&')
+		.map(|param| match param.split_once('=') {
+			Some((name, _)) if SECRET_QUERY_PARAMS.contains(&name) => format!("{name}={REDACTED}"),
+			_ => param.to_string(),
+		})
+		.collect::<Vec<_>>()
+		.join("&");
+	format!("{base}?{query}")
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_cassette_redact_and_match() {
+		let headers = Headers::from([("Authorization", "Bearer sk-secret"), ("content-type", "application/json")]);
+		let url = "https://generativelanguage.googleapis.com/v1beta/models/gemini:embedContent?key=secret&alt=sse";
+		let recorded = RecordedRequest::from_headers(
+			&Method::POST,
+			url,
+			&headers,
+			&serde_json::json!({"model": "m", "input": ["a", "b"]}),
+		);
+
+		assert_eq!(
+			recorded.headers.get("authorization").map(String::as_str),
+			Some(REDACTED)
+		);
+		assert_eq!(
+			recorded.url,
+			"https://generativelanguage.googleapis.com/v1beta/models/gemini:embedContent?key=[REDACTED]&alt=sse"
+		);
+
+		// Payload matching ignores the key order.
+		let payload = serde_json::from_str(r#"{"input": ["a", "b"], "model": "m"}"#).unwrap_or_default();
+		let other = RecordedRequest::from_headers(&Method::POST, url, &Headers::default(), &payload);
+		assert!(recorded.matches(&other));
+	}
+
+	#[test]
+	fn test_cassette_stream_recorder_utf8() {
+		let mut recorder = StreamRecorder {
+			cassette: Cassette::replay("unused.json"),
+			request: RecordedRequest::new(&Method::GET, "http://localhost", [], None),
+			status: StatusCode::OK,
+			headers: HeaderMap::new(),
+			chunks: Vec::new(),
+			pending: Vec::new(),
+		};
+		let bytes = "data: héllo\n\n".as_bytes();
+		// Split inside the two bytes `é`.
+		recorder.push(&bytes[..8]);
+		recorder.push(&bytes[8..]);
+
+		assert_eq!(recorder.chunks, vec!["data: h".to_string(), "éllo\n\n".to_string()]);
+	}
+}
+
+// endregion: --- Tests
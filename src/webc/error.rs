@@ -18,6 +18,21 @@ pub enum Error {
 		headers: Box<HeaderMap>,
 	},
 
+	#[display("AWS event stream error: {cause}")]
+	AwsEventStream { cause: String },
+
+	// -- Cassette
+	#[display("Cassette '{path}' error: {cause}")]
+	Cassette { path: String, cause: String },
+
+	#[display("No cassette interaction matches the request {method} {url}")]
+	CassetteNoMatch { method: String, url: String },
+
+	// -- Mock
+	#[cfg(feature = "mock")]
+	#[display("No mock set for the mock adapter URL '{url}' (see ClientBuilder::with_mock)")]
+	MockNotSet { url: String },
+
 	// -- Utils
 	#[display("JSON value extension error: {_0}")]
 	#[from]
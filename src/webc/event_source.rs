@@ -0,0 +1,97 @@
+use crate::webc::WebStreamRequest;
+use eventsource_stream::Eventsource;
+use futures::stream::{self, BoxStream};
+use futures::{Stream, StreamExt};
+use reqwest::header::{CONTENT_TYPE, HeaderValue};
+use reqwest::{Response, StatusCode};
+use reqwest_eventsource::{CannotCloneRequestError, Error, Event};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// The `text/event-stream` source used by the SSE streamers (OpenAI, Anthropic, ...).
+/// - Without a cassette, it is the `reqwest_eventsource::EventSource`.
+/// - With a cassette (or for the mock adapter, or a request with an `on_response`), the events are parsed from the recorded (or replayed) body,
+///   with the same `Event` and `Error` types, so the streamers do not have to know about it.
+pub struct EventSource {
+	inner: EventSourceInner,
+}
+
+enum EventSourceInner {
+	Live(Box<reqwest_eventsource::EventSource>),
+	Parsed(BoxStream<'static, Result<Event, Error>>),
+}
+
+impl EventSource {
+	pub fn new(stream_request: WebStreamRequest) -> Result<Self, CannotCloneRequestError> {
+		let inner = match stream_request.into_reqwest_builder() {
+			Ok(reqwest_builder) => {
+				EventSourceInner::Live(Box::new(reqwest_eventsource::EventSource::new(reqwest_builder)?))
+			}
+			Err(stream_request) => EventSourceInner::Parsed(parsed_events(stream_request)),
+		};
+		Ok(Self { inner })
+	}
+}
+
+impl Stream for EventSource {
+	type Item = Result<Event, Error>;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		match &mut self.get_mut().inner {
+			EventSourceInner::Live(event_source) => Pin::new(event_source.as_mut()).poll_next(cx),
+			EventSourceInner::Parsed(events) => events.as_mut().poll_next(cx),
+		}
+	}
+}
+
+// region:    --- Support
+
+/// Same sequence as the `reqwest_eventsource::EventSource` for a single connection:
+/// `Open`, the messages, then the `StreamEnded` error.
+// The error type is imposed by `reqwest_eventsource`.
+#[allow(clippy::result_large_err)]
+fn parsed_events(stream_request: WebStreamRequest) -> BoxStream<'static, Result<Event, Error>> {
+	let response = async move {
+		let response = stream_request.send().await.map_err(Error::Transport)?;
+		check_response(response)
+	};
+
+	stream::once(response)
+		.flat_map(|response| match response {
+			Ok(response) => {
+				let messages = response
+					.bytes_stream()
+					.eventsource()
+					.map(|event| event.map(Event::Message).map_err(Error::from));
+				stream::once(async { Ok(Event::Open) })
+					.chain(messages)
+					.chain(stream::once(async { Err(Error::StreamEnded) }))
+					.boxed()
+			}
+			Err(err) => stream::once(async { Err(err) }).boxed(),
+		})
+		.boxed()
+}
+
+/// Same checks as `reqwest_eventsource` (200 status and `text/event-stream` content type).
+#[allow(clippy::result_large_err)]
+fn check_response(response: Response) -> Result<Response, Error> {
+	if response.status() != StatusCode::OK {
+		return Err(Error::InvalidStatusCode(response.status(), response));
+	}
+
+	let content_type = response.headers().get(CONTENT_TYPE).cloned();
+	match content_type {
+		Some(content_type)
+			if content_type
+				.to_str()
+				.is_ok_and(|value| value.trim_start().starts_with("text/event-stream")) =>
+		{
+			Ok(response)
+		}
+		Some(content_type) => Err(Error::InvalidContentType(content_type, response)),
+		None => Err(Error::InvalidContentType(HeaderValue::from_static(""), response)),
+	}
+}
+
+// endregion: --- Support
@@ -1,17 +1,25 @@
-//! The `GenAI` web client construct that uses reqwest. Only `webc::Error` is exposed as the public interface.
+//! The `GenAI` web client construct that uses reqwest. Only `webc::Error`, `webc::WebResponse`, and `webc::Cassette` are exposed as the public interface.
 
 // region:    --- Modules
 
+mod aws_event_stream;
+mod cassette;
 mod error;
+mod event_source;
 mod web_client;
 // For when not using `text/event-stream`
 mod web_stream;
 
+pub(crate) use aws_event_stream::*;
+pub(crate) use cassette::{RecordedRequest, RecordedResponse};
 pub(crate) use error::Result;
+pub(crate) use event_source::*;
 pub(crate) use web_client::*;
 pub(crate) use web_stream::*;
 
 // Only public for external use
+pub use cassette::{Cassette, CassetteMode};
 pub use error::Error;
+pub use web_client::WebResponse;
 
 // endregion: --- Modules
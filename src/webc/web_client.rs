@@ -1,5 +1,7 @@
 use crate::Headers;
-use crate::webc::{Error, Result};
+#[cfg(feature = "mock")]
+use crate::adapter::Mock;
+use crate::webc::{Cassette, CassetteMode, Error, RecordedRequest, RecordedResponse, Result};
 use reqwest::header::HeaderMap;
 use reqwest::{Method, RequestBuilder, StatusCode};
 use serde_json::Value;
@@ -8,6 +10,9 @@ use serde_json::Value;
 #[derive(Debug)]
 pub struct WebClient {
 	reqwest_client: reqwest::Client,
+	cassette: Option<Cassette>,
+	#[cfg(feature = "mock")]
+	mock: Option<Mock>,
 }
 
 // Implements Default
@@ -15,6 +20,9 @@ impl Default for WebClient {
 	fn default() -> Self {
 		WebClient {
 			reqwest_client: reqwest::Client::new(),
+			cassette: None,
+			#[cfg(feature = "mock")]
+			mock: None,
 		}
 	}
 }
@@ -23,7 +31,25 @@ impl Default for WebClient {
 
 impl WebClient {
 	pub fn from_reqwest_client(reqwest_client: reqwest::Client) -> Self {
-		WebClient { reqwest_client }
+		WebClient {
+			reqwest_client,
+			cassette: None,
+			#[cfg(feature = "mock")]
+			mock: None,
+		}
+	}
+
+	/// Record or replay the web calls with the given cassette.
+	pub fn with_cassette(mut self, cassette: Cassette) -> Self {
+		self.cassette = Some(cassette);
+		self
+	}
+
+	/// Serve the `mock://` URLs (of the mock adapter) with the given script.
+	#[cfg(feature = "mock")]
+	pub fn with_mock(mut self, mock: Mock) -> Self {
+		self.mock = Some(mock);
+		self
 	}
 }
 
@@ -38,6 +64,12 @@ impl WebClient {
 		for (k, v) in headers.iter() {
 			reqwest_builder = reqwest_builder.header(k, v);
 		}
+
+		if let Some(cassette) = &self.cassette {
+			let request = RecordedRequest::new(&Method::GET, url, headers.iter().map(|(k, v)| (k, v)), None);
+			return exec_with_cassette(cassette, request, reqwest_builder).await;
+		}
+
 		let reqwest_res = reqwest_builder.send().await?;
 
 		let response = WebResponse::from_reqwest_response(reqwest_res).await?;
@@ -46,6 +78,17 @@ impl WebClient {
 	}
 
 	pub async fn do_post(&self, url: &str, headers: &Headers, content: Value) -> Result<WebResponse> {
+		#[cfg(feature = "mock")]
+		if url.starts_with(MOCK_URL_SCHEME) {
+			return self.get_mock(url)?.do_post(&content).await;
+		}
+
+		if let Some(cassette) = &self.cassette {
+			let request = RecordedRequest::from_headers(&Method::POST, url, headers, &content);
+			let reqwest_builder = self.new_req_builder(url, headers, content)?;
+			return exec_with_cassette(cassette, request, reqwest_builder).await;
+		}
+
 		let reqwest_builder = self.new_req_builder(url, headers, content)?;
 
 		let reqwest_res = reqwest_builder.send().await?;
@@ -55,6 +98,73 @@ impl WebClient {
 		Ok(response)
 	}
 
+	/// GET a text body (e.g., a JSONL file), whatever its content type.
+	pub async fn do_get_text(&self, url: &str, headers: &Headers) -> Result<String> {
+		let mut reqwest_builder = self.reqwest_client.request(Method::GET, url);
+		for (k, v) in headers.iter() {
+			reqwest_builder = reqwest_builder.header(k, v);
+		}
+
+		if let Some(cassette) = &self.cassette {
+			let request = RecordedRequest::from_headers(&Method::GET, url, headers, &Value::Null);
+			return record_or_replay(cassette, request, reqwest_builder).await?.into_text();
+		}
+
+		let res = reqwest_builder.send().await?;
+		let status = res.status();
+		if !status.is_success() {
+			let headers = res.headers().clone();
+			return Err(Error::ResponseFailedStatus {
+				status,
+				body: res.text().await?,
+				headers: Box::new(headers),
+			});
+		}
+
+		Ok(res.text().await?)
+	}
+
+	/// POST a `multipart/form-data` body with text fields and one file (e.g., a batch input file upload).
+	pub async fn do_post_file(
+		&self,
+		url: &str,
+		headers: &Headers,
+		fields: &[(&str, &str)],
+		file_name: &str,
+		file_content: String,
+	) -> Result<WebResponse> {
+		// Fixed boundary, so the body (and a cassette interaction) is the same for the same file.
+		const BOUNDARY: &str = "----genai-form-boundary-7d4a1f";
+
+		let mut body = String::new();
+		for (name, value) in fields {
+			body.push_str(&format!(
+				"--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"{name}\"\r\n\r\n{value}\r\n"
+			));
+		}
+		body.push_str(&format!(
+			"--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"{file_name}\"\r\n\
+			Content-Type: application/octet-stream\r\n\r\n{file_content}\r\n--{BOUNDARY}--\r\n"
+		));
+
+		let mut reqwest_builder = self.reqwest_client.request(Method::POST, url);
+		for (k, v) in headers.iter() {
+			reqwest_builder = reqwest_builder.header(k, v);
+		}
+		reqwest_builder = reqwest_builder
+			.header("content-type", format!("multipart/form-data; boundary={BOUNDARY}"))
+			.body(body.clone());
+
+		if let Some(cassette) = &self.cassette {
+			let request = RecordedRequest::from_headers(&Method::POST, url, headers, &Value::String(body));
+			return exec_with_cassette(cassette, request, reqwest_builder).await;
+		}
+
+		let reqwest_res = reqwest_builder.send().await?;
+
+		WebResponse::from_reqwest_response(reqwest_res).await
+	}
+
 	pub fn new_req_builder(&self, url: &str, headers: &Headers, content: Value) -> Result<RequestBuilder> {
 		let method = Method::POST;
 
@@ -66,9 +176,170 @@ impl WebClient {
 
 		Ok(reqwest_builder)
 	}
+
+	/// Build the request of a streaming call (sent by the adapter stream when first polled).
+	pub fn new_stream_request(&self, url: &str, headers: &Headers, content: Value) -> Result<WebStreamRequest> {
+		#[cfg(feature = "mock")]
+		if url.starts_with(MOCK_URL_SCHEME) {
+			let mock = self.get_mock(url)?.clone();
+			return Ok(WebStreamRequest::new(WebStreamRequestInner::Mock {
+				mock,
+				payload: content,
+			}));
+		}
+
+		let Some(cassette) = &self.cassette else {
+			let reqwest_builder = self.new_req_builder(url, headers, content)?;
+			return Ok(WebStreamRequest::new(WebStreamRequestInner::Live(reqwest_builder)));
+		};
+
+		let request = RecordedRequest::from_headers(&Method::POST, url, headers, &content);
+		let inner = match cassette.mode() {
+			CassetteMode::Record => WebStreamRequestInner::Record {
+				reqwest_builder: self.new_req_builder(url, headers, content)?,
+				cassette: cassette.clone(),
+				request,
+			},
+			// Matched now, so a missing interaction fails the call rather than the stream.
+			CassetteMode::Replay => WebStreamRequestInner::Replay(cassette.replay_response(&request)?),
+		};
+
+		Ok(WebStreamRequest::new(inner))
+	}
+
+	#[cfg(feature = "mock")]
+	fn get_mock(&self, url: &str) -> Result<&Mock> {
+		self.mock.as_ref().ok_or_else(|| Error::MockNotSet { url: url.to_string() })
+	}
+}
+
+#[cfg(feature = "mock")]
+const MOCK_URL_SCHEME: &str = "mock://";
+
+async fn exec_with_cassette(
+	cassette: &Cassette,
+	request: RecordedRequest,
+	reqwest_builder: RequestBuilder,
+) -> Result<WebResponse> {
+	// Same path for record and replay, so both return the same result.
+	record_or_replay(cassette, request, reqwest_builder).await?.into_web_response()
+}
+
+async fn record_or_replay(
+	cassette: &Cassette,
+	request: RecordedRequest,
+	reqwest_builder: RequestBuilder,
+) -> Result<RecordedResponse> {
+	match cassette.mode() {
+		CassetteMode::Replay => cassette.replay_response(&request),
+		CassetteMode::Record => {
+			let reqwest_res = reqwest_builder.send().await?;
+			let recorded_res = RecordedResponse::from_reqwest_response(reqwest_res).await?;
+			cassette.push_interaction(request, recorded_res.clone())?;
+			Ok(recorded_res)
+		}
+	}
 }
+
 // endregion: --- Web Method Implementation
 
+// region:    --- WebStreamRequest
+
+/// The request of a streaming call.
+/// With a cassette, its response body is recorded chunk by chunk, or replayed.
+/// For the mock adapter, it is served by the `Mock` script.
+#[derive(Debug)]
+pub struct WebStreamRequest {
+	// Boxed, as the `RequestBuilder` is large.
+	inner: Box<WebStreamRequestInner>,
+	on_response: Option<ResponseHook>,
+}
+
+/// Called with the response headers once the stream response is received (e.g., for the `RateLimiter`).
+struct ResponseHook(Box<dyn FnOnce(&HeaderMap) + Send + Sync>);
+
+impl std::fmt::Debug for ResponseHook {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str("ResponseHook")
+	}
+}
+
+#[derive(Debug)]
+enum WebStreamRequestInner {
+	Live(RequestBuilder),
+	Record {
+		reqwest_builder: RequestBuilder,
+		cassette: Cassette,
+		request: RecordedRequest,
+	},
+	Replay(RecordedResponse),
+	#[cfg(feature = "mock")]
+	Mock {
+		mock: Mock,
+		payload: Value,
+	},
+}
+
+impl WebStreamRequest {
+	fn new(inner: WebStreamRequestInner) -> Self {
+		Self {
+			inner: Box::new(inner),
+			on_response: None,
+		}
+	}
+
+	/// Call `on_response` with the headers of the response (failed status included), once received.
+	pub(crate) fn with_on_response(mut self, on_response: impl FnOnce(&HeaderMap) + Send + Sync + 'static) -> Self {
+		self.on_response = Some(ResponseHook(Box::new(on_response)));
+		self
+	}
+
+	/// Returns the `RequestBuilder` if there is no cassette (or mock) nor `on_response`, or the request back otherwise.
+	///
+	/// Note: The `reqwest_eventsource::EventSource` does not expose the response headers,
+	///       so a request with an `on_response` is sent with `send`.
+	pub(crate) fn into_reqwest_builder(self) -> core::result::Result<RequestBuilder, Self> {
+		match (*self.inner, self.on_response) {
+			(WebStreamRequestInner::Live(reqwest_builder), None) => Ok(reqwest_builder),
+			(inner, on_response) => Err(Self {
+				inner: Box::new(inner),
+				on_response,
+			}),
+		}
+	}
+
+	/// Send the request (or replay its response).
+	pub(crate) async fn send(self) -> reqwest::Result<reqwest::Response> {
+		let response = self.inner.send().await?;
+		if let Some(ResponseHook(on_response)) = self.on_response {
+			on_response(response.headers());
+		}
+		Ok(response)
+	}
+}
+
+/// Private Support
+impl WebStreamRequestInner {
+	async fn send(self) -> reqwest::Result<reqwest::Response> {
+		match self {
+			Self::Live(reqwest_builder) => reqwest_builder.send().await,
+			Self::Record {
+				reqwest_builder,
+				cassette,
+				request,
+			} => {
+				let reqwest_res = reqwest_builder.send().await?;
+				Ok(cassette.record_stream_response(request, reqwest_res))
+			}
+			Self::Replay(recorded_res) => Ok(recorded_res.into_reqwest_response()),
+			#[cfg(feature = "mock")]
+			Self::Mock { mock, payload } => Ok(mock.stream_response(&payload)),
+		}
+	}
+}
+
+// endregion: --- WebStreamRequest
+
 // region:    --- WebResponse
 
 // NOTE: This is not a non-streaming web response (assumed to be JSON for this library).
@@ -79,9 +350,20 @@ pub struct WebResponse {
 	#[allow(unused)]
 	pub status: StatusCode,
 	pub body: Value,
+	/// The response headers (e.g., for the `x-ratelimit-*` information).
+	pub headers: HeaderMap,
 }
 
 impl WebResponse {
+	/// Create a successful (200) JSON response, typically to short-circuit a call from a `Middleware`.
+	pub fn from_body(body: Value) -> Self {
+		WebResponse {
+			status: StatusCode::OK,
+			body,
+			headers: HeaderMap::new(),
+		}
+	}
+
 	/// Note 1: For now, assume only a JSON response.
 	/// Note 2: Currently, the WebResponse holds a Value (parsed from the entire body), and then the caller
 	///         can cherry-pick/deserialize further. In the future, we might consider returning `body: String`
@@ -113,8 +395,20 @@ impl WebResponse {
 			});
 		};
 
-		Ok(WebResponse { status, body })
+		Ok(WebResponse {
+			status,
+			body,
+			headers: header_map,
+		})
 	}
 }
 
+/// Build a `reqwest::Response` from parts (for the responses not coming from the network, e.g., cassette replay).
+pub(crate) fn new_reqwest_response(status: StatusCode, headers: HeaderMap, body: reqwest::Body) -> reqwest::Response {
+	let mut res = http::Response::new(body);
+	*res.status_mut() = status;
+	*res.headers_mut() = headers;
+	reqwest::Response::from(res)
+}
+
 // endregion: --- WebResponse
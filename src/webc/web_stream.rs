@@ -1,7 +1,8 @@
+use crate::webc::WebStreamRequest;
 use bytes::Bytes;
 use futures::stream::TryStreamExt;
 use futures::{Future, Stream};
-use reqwest::{RequestBuilder, Response};
+use reqwest::Response;
 use std::collections::VecDeque;
 use std::error::Error;
 use std::pin::Pin;
@@ -16,7 +17,7 @@ use std::task::{Context, Poll};
 #[allow(clippy::type_complexity)]
 pub struct WebStream {
 	stream_mode: StreamMode,
-	reqwest_builder: Option<RequestBuilder>,
+	stream_request: Option<WebStreamRequest>,
 	response_future: Option<Pin<Box<dyn Future<Output = Result<Response, Box<dyn Error>>> + Send>>>,
 	bytes_stream: Option<Pin<Box<dyn Stream<Item = Result<Bytes, Box<dyn Error>>> + Send>>>,
 	// If a poll was a partial message, then we keep the previous part
@@ -33,10 +34,10 @@ pub enum StreamMode {
 }
 
 impl WebStream {
-	pub fn new_with_delimiter(reqwest_builder: RequestBuilder, message_delimiter: &'static str) -> Self {
+	pub fn new_with_delimiter(stream_request: WebStreamRequest, message_delimiter: &'static str) -> Self {
 		Self {
 			stream_mode: StreamMode::Delimiter(message_delimiter),
-			reqwest_builder: Some(reqwest_builder),
+			stream_request: Some(stream_request),
 			response_future: None,
 			bytes_stream: None,
 			partial_message: None,
@@ -44,10 +45,10 @@ impl WebStream {
 		}
 	}
 
-	pub fn new_with_pretty_json_array(reqwest_builder: RequestBuilder) -> Self {
+	pub fn new_with_pretty_json_array(stream_request: WebStreamRequest) -> Self {
 		Self {
 			stream_mode: StreamMode::PrettyJsonArray,
-			reqwest_builder: Some(reqwest_builder),
+			stream_request: Some(stream_request),
 			response_future: None,
 			bytes_stream: None,
 			partial_message: None,
@@ -144,8 +145,8 @@ impl Stream for WebStream {
 				}
 			}
 
-			if let Some(reqwest_builder) = this.reqwest_builder.take() {
-				let fut = async move { reqwest_builder.send().await.map_err(|e| Box::new(e) as Box<dyn Error>) };
+			if let Some(stream_request) = this.stream_request.take() {
+				let fut = async move { stream_request.send().await.map_err(|e| Box::new(e) as Box<dyn Error>) };
 				this.response_future = Some(Box::pin(fut));
 				continue;
 			}
@@ -92,6 +92,7 @@ pub async fn extract_stream_end(mut chat_stream: ChatStream) -> TestResult<Strea
 			ChatStreamEvent::Chunk(s_chunk) => content.push(s_chunk.content),
 			ChatStreamEvent::ReasoningChunk(s_chunk) => reasoning_content.push(s_chunk.content),
 			ChatStreamEvent::ToolCallChunk(_) => (), // ignore tool call chunks for now
+			ChatStreamEvent::Resumed(_) => (),       // the next chunks continue the content
 			ChatStreamEvent::End(s_end) => {
 				stream_end = Some(s_end);
 				break;
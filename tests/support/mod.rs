@@ -9,11 +9,13 @@ mod asserts;
 mod data;
 mod helpers;
 mod seeders;
+mod stub_server;
 mod test_error;
 
 pub use asserts::*;
 pub use helpers::*;
 pub use seeders::*;
+pub use stub_server::*;
 pub use test_error::*;
 
 pub mod common_tests;
@@ -0,0 +1,178 @@
+//! A minimal local HTTP/1.1 stub server for the adapter tests (no network, no provider account).
+//!
+//! The stub answers canned responses in order (one per connection) and records the requests,
+//! so the URLs, headers, and bodies sent by the adapters can be checked.
+
+use crate::support::TestResult;
+use genai::resolver::{AuthData, Endpoint, ServiceTargetResolver};
+use genai::{Client, ClientBuilder, ServiceTarget};
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+// region:    --- StubResponse
+
+pub struct StubResponse {
+	pub status: u16,
+	pub headers: Vec<(String, String)>,
+	pub body: Vec<u8>,
+}
+
+impl StubResponse {
+	pub fn new(status: u16, content_type: &str, body: impl Into<Vec<u8>>) -> Self {
+		Self {
+			status,
+			headers: vec![("content-type".to_string(), content_type.to_string())],
+			body: body.into(),
+		}
+	}
+
+	pub fn json(status: u16, body: impl Into<Vec<u8>>) -> Self {
+		Self::new(status, "application/json", body)
+	}
+
+	pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+		self.headers.push((name.into(), value.into()));
+		self
+	}
+}
+
+// endregion: --- StubResponse
+
+// region:    --- StubRequest
+
+#[derive(Debug, Clone)]
+pub struct StubRequest {
+	pub path: String,
+	pub headers: Vec<(String, String)>,
+	pub body: Vec<u8>,
+}
+
+impl StubRequest {
+	pub fn header(&self, name: &str) -> Option<&str> {
+		self.headers
+			.iter()
+			.find(|(n, _)| n.eq_ignore_ascii_case(name))
+			.map(|(_, v)| v.as_str())
+	}
+}
+
+// endregion: --- StubRequest
+
+// region:    --- Stub
+
+/// A minimal HTTP/1.1 server answering the given responses in order (one per connection).
+pub struct Stub {
+	port: u16,
+	requests: Arc<Mutex<Vec<StubRequest>>>,
+}
+
+impl Stub {
+	pub async fn start(responses: Vec<StubResponse>) -> TestResult<Self> {
+		let listener = TcpListener::bind("127.0.0.1:0").await?;
+		let port = listener.local_addr()?.port();
+		let requests: Arc<Mutex<Vec<StubRequest>>> = Default::default();
+
+		let recorded = requests.clone();
+		tokio::spawn(async move {
+			for response in responses {
+				let Ok((mut socket, _)) = listener.accept().await else {
+					return;
+				};
+				let Some(request) = read_request(&mut socket).await else {
+					return;
+				};
+				recorded.lock().unwrap().push(request);
+
+				let mut head = format!(
+					"HTTP/1.1 {} Stub\r\ncontent-length: {}\r\nconnection: close\r\n",
+					response.status,
+					response.body.len()
+				);
+				for (name, value) in &response.headers {
+					head.push_str(&format!("{name}: {value}\r\n"));
+				}
+				head.push_str("\r\n");
+				let _ = socket.write_all(head.as_bytes()).await;
+				let _ = socket.write_all(&response.body).await;
+				let _ = socket.shutdown().await;
+			}
+		});
+
+		Ok(Self { port, requests })
+	}
+
+	/// e.g., `http://127.0.0.1:1234/`
+	pub fn base_url(&self) -> String {
+		format!("http://127.0.0.1:{}/", self.port)
+	}
+
+	/// A client resolving all the models to the stub, with the `endpoint_path` (appended to the `base_url`) and `auth`.
+	pub fn client(&self, endpoint_path: &str, auth: AuthData) -> Client {
+		self.client_builder(endpoint_path, auth).build()
+	}
+
+	/// The builder of `Stub::client`, to add the middlewares, policies, etc.
+	pub fn client_builder(&self, endpoint_path: &str, auth: AuthData) -> ClientBuilder {
+		let endpoint = format!("{}{endpoint_path}", self.base_url());
+		let target_resolver = ServiceTargetResolver::from_resolver_fn(
+			move |service_target: ServiceTarget| -> Result<ServiceTarget, genai::resolver::Error> {
+				Ok(ServiceTarget {
+					endpoint: Endpoint::from_owned(endpoint.clone()),
+					auth: auth.clone(),
+					model: service_target.model,
+				})
+			},
+		);
+		Client::builder().with_service_target_resolver(target_resolver)
+	}
+
+	pub fn requests(&self) -> Vec<StubRequest> {
+		self.requests.lock().unwrap().clone()
+	}
+
+	pub fn last_request(&self) -> Option<StubRequest> {
+		self.requests.lock().unwrap().last().cloned()
+	}
+}
+
+async fn read_request(socket: &mut tokio::net::TcpStream) -> Option<StubRequest> {
+	let mut buffer = Vec::new();
+	let mut chunk = [0u8; 4096];
+	let head_end = loop {
+		let n = socket.read(&mut chunk).await.ok()?;
+		if n == 0 {
+			return None;
+		}
+		buffer.extend_from_slice(&chunk[..n]);
+		if let Some(pos) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+			break pos + 4;
+		}
+	};
+
+	let head = String::from_utf8_lossy(&buffer[..head_end]).to_string();
+	let mut lines = head.lines();
+	let path = lines.next()?.split_whitespace().nth(1)?.to_string();
+	let headers: Vec<(String, String)> = lines
+		.filter_map(|line| line.split_once(':'))
+		.map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+		.collect();
+	let content_length = headers
+		.iter()
+		.find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+		.and_then(|(_, value)| value.parse::<usize>().ok())
+		.unwrap_or(0);
+
+	let mut body = buffer[head_end..].to_vec();
+	while body.len() < content_length {
+		let n = socket.read(&mut chunk).await.ok()?;
+		if n == 0 {
+			break;
+		}
+		body.extend_from_slice(&chunk[..n]);
+	}
+
+	Some(StubRequest { path, headers, body })
+}
+
+// endregion: --- Stub
@@ -0,0 +1,106 @@
+//! Tests for the `ApiKeyPool` against a local stub endpoint (no network, no API key).
+//!
+//! The stub records the `authorization` header of each request, so the key taken per request can be checked.
+
+mod support;
+
+use crate::support::{Stub, StubResponse, TestResult};
+use genai::adapter::AdapterKind;
+use genai::chat::{ChatMessage, ChatRequest};
+use genai::resolver::{AuthData, Endpoint, ServiceTargetResolver};
+use genai::{ApiKeyPool, Client, RetryPolicy, ServiceTarget};
+use serde_json::json;
+use std::time::Duration;
+
+const MODEL: &str = "gpt-4o-mini";
+
+#[tokio::test]
+async fn test_api_key_pool_stub_retry_takes_next_key_ok() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let chat_body = json!({
+		"id": "chatcmpl-1",
+		"object": "chat.completion",
+		"model": MODEL,
+		"choices": [{"index": 0, "finish_reason": "stop", "message": {"role": "assistant", "content": "Blue."}}],
+		"usage": {"prompt_tokens": 8, "completion_tokens": 2, "total_tokens": 10}
+	});
+	let stub = Stub::start(vec![
+		StubResponse::json(429, r#"{"error":{"message":"Rate limit reached"}}"#),
+		StubResponse::json(200, chat_body.to_string()),
+	])
+	.await?;
+	let pool = ApiKeyPool::new()
+		.with_keys(AdapterKind::OpenAI, AuthData::from_single("sk-1"))
+		.with_keys(AdapterKind::OpenAI, AuthData::from_single("sk-2"));
+	let retry_policy = RetryPolicy::new(2)
+		.with_initial_backoff(Duration::from_millis(1))
+		.with_jitter(false);
+	let client = stub_client(&stub, pool.clone()).with_retry_policy(retry_policy).build();
+
+	// -- Exec
+	let chat_req = ChatRequest::new(vec![ChatMessage::user("Why is the sky blue?")]);
+	let res = client.exec_chat(MODEL, chat_req, None).await?;
+
+	// -- Check (the retry takes the next key, and the rate limited one is cooling down)
+	assert_eq!(res.first_text(), Some("Blue."));
+	let auth_headers: Vec<_> = stub
+		.requests()
+		.iter()
+		.map(|request| request.header("authorization").map(String::from))
+		.collect();
+	assert_eq!(
+		auth_headers,
+		[Some("Bearer sk-1".to_string()), Some("Bearer sk-2".to_string())]
+	);
+	let usage = pool.usage(AdapterKind::OpenAI);
+	assert_eq!((usage[0].requests, usage[0].rate_limited), (1, 1));
+	assert_eq!((usage[1].requests, usage[1].rate_limited), (1, 0));
+	assert!(usage[0].cooldown_remaining.is_some());
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_api_key_pool_stub_preview_and_list_models_do_not_take_ok() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let models_body = json!({"object": "list", "data": [
+		{"id": MODEL, "object": "model", "created": 1715367049, "owned_by": "system"}
+	]});
+	let stub = Stub::start(vec![StubResponse::json(200, models_body.to_string())]).await?;
+	let pool = ApiKeyPool::new()
+		.with_keys(AdapterKind::OpenAI, AuthData::from_single("sk-1"))
+		.with_keys(AdapterKind::OpenAI, AuthData::from_single("sk-2"));
+	let client = stub_client(&stub, pool.clone()).build();
+
+	// -- Exec
+	let chat_req = ChatRequest::new(vec![ChatMessage::user("Why is the sky blue?")]);
+	client.preview_chat_request(MODEL, &chat_req, None).await?;
+	client.list_models(AdapterKind::OpenAI).await?;
+
+	// -- Check (the listing uses the first key, and no request is counted)
+	let request = stub.last_request().ok_or("Should have a request")?;
+	assert_eq!(request.header("authorization"), Some("Bearer sk-1"));
+	assert!(pool.usage(AdapterKind::OpenAI).iter().all(|usage| usage.requests == 0));
+
+	Ok(())
+}
+
+// region:    --- Support
+
+/// A client builder with the pool, sending all the requests to the stub (the auth is left to the pool).
+fn stub_client(stub: &Stub, pool: ApiKeyPool) -> genai::ClientBuilder {
+	let endpoint = format!("{}v1/", stub.base_url());
+	let target_resolver = ServiceTargetResolver::from_resolver_fn(
+		move |service_target: ServiceTarget| -> Result<ServiceTarget, genai::resolver::Error> {
+			Ok(ServiceTarget {
+				endpoint: Endpoint::from_owned(endpoint.clone()),
+				..service_target
+			})
+		},
+	);
+	Client::builder()
+		.with_api_key_pool(pool)
+		.with_service_target_resolver(target_resolver)
+}
+
+// endregion: --- Support
@@ -0,0 +1,131 @@
+//! Tests for the Azure OpenAI adapter against a local stub endpoint (no network, no Azure resource).
+//!
+//! The stub serves canned Chat Completions responses (JSON, or the SSE stream) with the Azure content filter results,
+//! and records the requests, so the deployment URL and the `api-key` header can be checked.
+
+mod support;
+
+use crate::support::{Stub, StubResponse, TestResult, extract_stream_end};
+use genai::Client;
+use genai::adapter::AdapterKind;
+use genai::chat::{ChatMessage, ChatOptions, ChatRequest};
+use genai::resolver::AuthData;
+use serde_json::{Value, json};
+
+const MODEL: &str = "azure::my-gpt-4o";
+
+#[tokio::test]
+async fn test_azure_openai_stub_chat_ok() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let chat_body = json!({
+		"id": "chatcmpl-1",
+		"object": "chat.completion",
+		"model": "gpt-4o-2024-11-20",
+		"prompt_filter_results": [{
+			"prompt_index": 0,
+			"content_filter_results": {
+				"hate": {"filtered": false, "severity": "safe"},
+				"jailbreak": {"filtered": false, "detected": false}
+			}
+		}],
+		"choices": [{
+			"index": 0,
+			"finish_reason": "stop",
+			"message": {"role": "assistant", "content": "Rayleigh scattering."},
+			"content_filter_results": {
+				"hate": {"filtered": false, "severity": "safe"},
+				"violence": {"filtered": false, "severity": "low"}
+			}
+		}],
+		"usage": {"prompt_tokens": 12, "completion_tokens": 4, "total_tokens": 16}
+	});
+	let stub = Stub::start(vec![StubResponse::json(200, chat_body.to_string())]).await?;
+	let client = stub_client(&stub);
+
+	// -- Exec
+	let chat_req = ChatRequest::new(vec![ChatMessage::user("Why is the sky blue?")]);
+	let res = client.exec_chat(MODEL, chat_req, None).await?;
+
+	// -- Check
+	assert_eq!(res.model_iden.adapter_kind, AdapterKind::AzureOpenAI);
+	assert_eq!(res.first_text(), Some("Rayleigh scattering."));
+	assert_eq!(res.usage.total_tokens, Some(16));
+	let results = res.content_filter_results.ok_or("Should have content filter results")?;
+	assert_eq!(results.prompt.len(), 2);
+	assert_eq!(results.completion.len(), 2);
+	assert!(!results.is_filtered());
+
+	let request = stub.last_request().ok_or("Should have a request")?;
+	assert_eq!(
+		request.path,
+		"/openai/deployments/my-gpt-4o/chat/completions?api-version=2024-10-21"
+	);
+	assert_eq!(request.header("api-key"), Some("azure-stub-key"));
+	assert!(request.header("authorization").is_none());
+	let body: Value = serde_json::from_slice(&request.body).map_err(|err| err.to_string())?;
+	assert_eq!(body["messages"][0]["content"], "Why is the sky blue?");
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_azure_openai_stub_chat_stream_ok() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let events = [
+		// The prompt filter results come first, without choices nor usage
+		json!({"choices": [], "prompt_filter_results": [{
+			"prompt_index": 0,
+			"content_filter_results": {"hate": {"filtered": false, "severity": "safe"}}
+		}]}),
+		json!({"choices": [{"index": 0, "delta": {"role": "assistant", "content": "Rayleigh "}}]}),
+		json!({"choices": [{"index": 0, "delta": {"content": "scattering."}, "content_filter_results": {
+			"violence": {"filtered": false, "severity": "safe"}
+		}}]}),
+		json!({"choices": [{"index": 0, "delta": {}, "finish_reason": "content_filter", "content_filter_results": {
+			"violence": {"filtered": true, "severity": "medium"}
+		}}]}),
+		json!({"choices": [], "usage": {"prompt_tokens": 10, "completion_tokens": 4, "total_tokens": 14}}),
+	];
+	let mut body: String = events.iter().map(|event| format!("data: {event}\n\n")).collect();
+	body.push_str("data: [DONE]\n\n");
+	let stub = Stub::start(vec![StubResponse::new(200, "text/event-stream", body)]).await?;
+	let client = stub_client(&stub);
+	let options = ChatOptions::default().with_capture_usage(true).with_capture_content(true);
+
+	// -- Exec
+	let chat_req = ChatRequest::new(vec![ChatMessage::user("Why is the sky blue?")]);
+	let stream_res = client.exec_chat_stream(MODEL, chat_req, Some(&options)).await?;
+	let extract = extract_stream_end(stream_res.stream).await?;
+
+	// -- Check
+	assert_eq!(extract.content.as_deref(), Some("Rayleigh scattering."));
+	assert_eq!(
+		extract.stream_end.captured_finish_reason.as_deref(),
+		Some("content_filter")
+	);
+	let usage = extract.stream_end.captured_usage.ok_or("Should have usage")?;
+	assert_eq!(usage.prompt_tokens, Some(10));
+	assert_eq!(usage.total_tokens, Some(14));
+	let results = extract
+		.stream_end
+		.captured_content_filter_results
+		.ok_or("Should have content filter results")?;
+	assert_eq!(results.prompt.len(), 1);
+	assert_eq!(results.completion.len(), 1);
+	assert_eq!(results.completion[0].severity.as_deref(), Some("medium"));
+	assert_eq!(results.filtered_categories(), vec!["violence"]);
+
+	Ok(())
+}
+
+// region:    --- Support
+
+/// A client resolving all the models to the stub resource, with an API key.
+fn stub_client(stub: &Stub) -> Client {
+	stub.client(
+		"openai/?api-version=2024-10-21",
+		AuthData::from_single("azure-stub-key"),
+	)
+}
+
+// endregion: --- Support
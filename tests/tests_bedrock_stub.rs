@@ -0,0 +1,351 @@
+//! Tests for the Bedrock adapter against a local stub endpoint (no network, no AWS account).
+//!
+//! The stub serves canned Converse responses (JSON, or the binary event stream for ConverseStream)
+//! and records the requests, so the SigV4 headers and Converse bodies can be checked.
+
+mod support;
+
+use crate::support::{Stub, StubRequest, StubResponse, TestResult, extract_stream_end};
+use genai::adapter::{AdapterKind, WebRequestData};
+use genai::chat::{ChatMessage, ChatOptions, ChatRequest, Tool};
+use genai::resolver::AuthData;
+use genai::webc::{Cassette, WebResponse};
+use genai::{Client, ClientBuilder, ErrorKind, Middleware, MiddlewareContext, RetryPolicy};
+use ring::{digest, hmac};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::time::Duration;
+
+const MODEL: &str = "bedrock::amazon.nova-lite-v1:0";
+
+#[tokio::test]
+async fn test_bedrock_stub_chat_ok() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let converse_body = json!({
+		"output": {"message": {"role": "assistant", "content": [
+			{"text": "Let me check."},
+			{"toolUse": {"toolUseId": "tooluse_1", "name": "get_weather", "input": {"city": "Paris"}}}
+		]}},
+		"stopReason": "tool_use",
+		"usage": {"inputTokens": 20, "outputTokens": 8, "totalTokens": 28}
+	});
+	let stub = Stub::start(vec![StubResponse::json(200, converse_body.to_string())]).await?;
+	let client = stub_client(&stub);
+	let chat_req = ChatRequest::new(vec![ChatMessage::user("What is the weather in Paris?")])
+		.with_system("Be concise")
+		.with_tools([Tool::new("get_weather").with_schema(json!({
+			"type": "object",
+			"properties": {"city": {"type": "string"}},
+			"required": ["city"]
+		}))]);
+	let options = ChatOptions::default().with_max_tokens(256).with_top_p(0.9);
+
+	// -- Exec
+	let res = client.exec_chat(MODEL, chat_req, Some(&options)).await?;
+
+	// -- Check
+	assert_eq!(res.model_iden.adapter_kind, AdapterKind::Bedrock);
+	assert_eq!(res.first_text(), Some("Let me check."));
+	let tool_calls = res.tool_calls();
+	assert_eq!(tool_calls.len(), 1);
+	assert_eq!(tool_calls[0].fn_arguments, json!({"city": "Paris"}));
+	assert_eq!(res.finish_reason.as_deref(), Some("tool_use"));
+	assert_eq!(res.usage.total_tokens, Some(28));
+
+	let request = stub.last_request().ok_or("Should have a request")?;
+	assert_eq!(request.path, "/model/amazon.nova-lite-v1%3A0/converse");
+	let authorization = request.header("authorization").ok_or("Should have authorization")?;
+	assert!(authorization.starts_with("AWS4-HMAC-SHA256 Credential=AKIDSTUB/"));
+	assert!(authorization.contains("/us-west-2/bedrock/aws4_request"));
+	assert!(request.header("x-amz-date").is_some());
+	let body: Value = serde_json::from_slice(&request.body).map_err(|err| err.to_string())?;
+	assert_eq!(body["system"][0]["text"], "Be concise");
+	assert_eq!(body["inferenceConfig"]["maxTokens"], 256);
+	assert_eq!(body["toolConfig"]["tools"][0]["toolSpec"]["name"], "get_weather");
+	assert_eq!(
+		body["messages"][0]["content"][0]["text"],
+		"What is the weather in Paris?"
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_bedrock_stub_chat_stream_ok() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let events = [
+		("messageStart", json!({"role": "assistant"})),
+		(
+			"contentBlockDelta",
+			json!({"contentBlockIndex": 0, "delta": {"text": "Rayleigh "}}),
+		),
+		(
+			"contentBlockDelta",
+			json!({"contentBlockIndex": 0, "delta": {"text": "scattering."}}),
+		),
+		("contentBlockStop", json!({"contentBlockIndex": 0})),
+		("messageStop", json!({"stopReason": "end_turn"})),
+		(
+			"metadata",
+			json!({"usage": {"inputTokens": 10, "outputTokens": 4, "totalTokens": 14}, "metrics": {"latencyMs": 120}}),
+		),
+	];
+	let body: Vec<u8> = events
+		.iter()
+		.flat_map(|(event_type, payload)| encode_event_message(event_type, payload))
+		.collect();
+	let stub = Stub::start(vec![event_stream_response(body)]).await?;
+	let client = stub_client(&stub);
+	let options = ChatOptions::default().with_capture_usage(true).with_capture_content(true);
+
+	// -- Exec
+	let chat_req = ChatRequest::new(vec![ChatMessage::user("Why is the sky blue?")]);
+	let stream_res = client.exec_chat_stream(MODEL, chat_req, Some(&options)).await?;
+	let extract = extract_stream_end(stream_res.stream).await?;
+
+	// -- Check
+	assert_eq!(extract.content.as_deref(), Some("Rayleigh scattering."));
+	assert_eq!(extract.stream_end.captured_first_text(), Some("Rayleigh scattering."));
+	assert_eq!(extract.stream_end.captured_finish_reason.as_deref(), Some("end_turn"));
+	let usage = extract.stream_end.captured_usage.ok_or("Should have usage")?;
+	assert_eq!(usage.prompt_tokens, Some(10));
+	assert_eq!(usage.total_tokens, Some(14));
+	let request = stub.last_request().ok_or("Should have a request")?;
+	assert_eq!(request.path, "/model/amazon.nova-lite-v1%3A0/converse-stream");
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_bedrock_stub_chat_throttling_err() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let response = StubResponse::json(429, r#"{"message":"Too many requests, please wait."}"#)
+		.with_header("x-amzn-ErrorType", "ThrottlingException:http://internal.amazon.com/");
+	let stub = Stub::start(vec![response]).await?;
+	let client = stub_client(&stub);
+
+	// -- Exec
+	let chat_req = ChatRequest::new(vec![ChatMessage::user("Hello")]);
+	let Err(err) = client.exec_chat(MODEL, chat_req, None).await else {
+		return Err("Should have failed".into());
+	};
+
+	// -- Check
+	let provider_error = err.provider_error().ok_or("Should have a provider error")?;
+	assert_eq!(provider_error.kind, ErrorKind::RateLimited);
+	assert_eq!(provider_error.code.as_deref(), Some("ThrottlingException"));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_bedrock_stub_chat_middleware_retry_signed_ok() -> TestResult<()> {
+	// -- Setup & Fixtures
+	struct SetTemperature;
+	impl Middleware for SetTemperature {
+		fn on_request(
+			&self,
+			_ctx: &MiddlewareContext,
+			request: &mut WebRequestData,
+		) -> genai::Result<Option<WebResponse>> {
+			request.payload["inferenceConfig"] = json!({"temperature": 0.1});
+			Ok(None)
+		}
+	}
+	let converse_body = json!({
+		"output": {"message": {"role": "assistant", "content": [{"text": "Rayleigh scattering."}]}},
+		"stopReason": "end_turn",
+		"usage": {"inputTokens": 10, "outputTokens": 4, "totalTokens": 14}
+	});
+	let stub = Stub::start(vec![
+		StubResponse::json(503, r#"{"message":"Service unavailable"}"#),
+		StubResponse::json(200, converse_body.to_string()),
+	])
+	.await?;
+	let retry_policy = RetryPolicy::new(2)
+		.with_initial_backoff(Duration::from_millis(1))
+		.with_jitter(false);
+	let client = stub_client_builder(&stub)
+		.with_middleware(SetTemperature)
+		.with_retry_policy(retry_policy)
+		.build();
+
+	// -- Exec
+	let chat_req = ChatRequest::new(vec![ChatMessage::user("Why is the sky blue?")]);
+	let res = client.exec_chat(MODEL, chat_req, None).await?;
+
+	// -- Check (each request is signed after the middleware change, the retry included)
+	assert_eq!(res.first_text(), Some("Rayleigh scattering."));
+	let requests = stub.requests();
+	assert_eq!(requests.len(), 2);
+	for request in requests {
+		let body: Value = serde_json::from_slice(&request.body).map_err(|err| err.to_string())?;
+		assert_eq!(body["inferenceConfig"]["temperature"], 0.1);
+		let authorization = request.header("authorization").ok_or("Should have authorization")?;
+		let signature = authorization.rsplit("Signature=").next().ok_or("Should have a signature")?;
+		assert_eq!(signature, expected_signature(&request)?);
+	}
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_bedrock_stub_cassette_redacts_session_token_ok() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let path = std::env::temp_dir().join(format!("genai-bedrock-cassette-{}.json", std::process::id()));
+	let _ = std::fs::remove_file(&path);
+	let converse_body = json!({
+		"output": {"message": {"role": "assistant", "content": [{"text": "Blue."}]}},
+		"stopReason": "end_turn",
+		"usage": {"inputTokens": 5, "outputTokens": 1, "totalTokens": 6}
+	});
+	let stub = Stub::start(vec![StubResponse::json(200, converse_body.to_string())]).await?;
+	let mut keys = stub_keys();
+	keys.insert("AWS_SESSION_TOKEN".to_string(), "stub-session-token".to_string());
+	let client = stub
+		.client_builder("", AuthData::from_multi(keys))
+		.with_cassette(Cassette::record(&path))
+		.build();
+
+	// -- Exec
+	let chat_req = ChatRequest::new(vec![ChatMessage::user("Why is the sky blue?")]);
+	client.exec_chat(MODEL, chat_req, None).await?;
+
+	// -- Check (sent signed, but recorded redacted)
+	let request = stub.last_request().ok_or("Should have a request")?;
+	assert_eq!(request.header("x-amz-security-token"), Some("stub-session-token"));
+	let content = std::fs::read_to_string(&path)?;
+	let _ = std::fs::remove_file(&path);
+	let cassette: Value = serde_json::from_str(&content).map_err(|err| err.to_string())?;
+	let headers = &cassette["interactions"][0]["request"]["headers"];
+	assert_eq!(headers["x-amz-security-token"], "[REDACTED]");
+	assert_eq!(headers["authorization"], "[REDACTED]");
+	assert!(!content.contains("stub-session-token"));
+
+	Ok(())
+}
+
+// region:    --- Support
+
+const SECRET_ACCESS_KEY: &str = "stub-secret";
+const REGION: &str = "us-west-2";
+
+/// A client resolving all the models to the Bedrock adapter on the stub, with static AWS credentials.
+fn stub_client(stub: &Stub) -> Client {
+	stub_client_builder(stub).build()
+}
+
+fn stub_client_builder(stub: &Stub) -> ClientBuilder {
+	stub.client_builder("", AuthData::from_multi(stub_keys()))
+}
+
+fn stub_keys() -> HashMap<String, String> {
+	HashMap::from([
+		("AWS_ACCESS_KEY_ID".to_string(), "AKIDSTUB".to_string()),
+		("AWS_SECRET_ACCESS_KEY".to_string(), SECRET_ACCESS_KEY.to_string()),
+		("AWS_REGION".to_string(), REGION.to_string()),
+	])
+}
+
+fn event_stream_response(body: Vec<u8>) -> StubResponse {
+	StubResponse::new(200, "application/vnd.amazon.eventstream", body)
+}
+
+// endregion: --- Support
+
+// region:    --- SigV4 Check
+
+/// Recomputes the SigV4 signature of a received request (POST, no query), from its path, signed headers, and body.
+fn expected_signature(request: &StubRequest) -> TestResult<String> {
+	let header = |name: &str| request.header(name).ok_or(format!("Should have the '{name}' header"));
+	let authorization = header("authorization")?;
+	let signed_headers = authorization
+		.split("SignedHeaders=")
+		.nth(1)
+		.and_then(|rest| rest.split(',').next())
+		.ok_or("Should have the signed headers")?;
+	let amz_date = header("x-amz-date")?;
+	let date = &amz_date[..8];
+	let scope = format!("{date}/{REGION}/bedrock/aws4_request");
+
+	// Each path segment is encoded again (e.g., `%3A` is `%253A`)
+	let canonical_uri = request.path.replace('%', "%25");
+	let mut canonical_headers = String::new();
+	for name in signed_headers.split(';') {
+		canonical_headers.push_str(&format!("{name}:{}\n", header(name)?));
+	}
+	let canonical_request = format!(
+		"POST\n{canonical_uri}\n\n{canonical_headers}\n{signed_headers}\n{}",
+		hex_sha256(&request.body)
+	);
+	let string_to_sign = format!(
+		"AWS4-HMAC-SHA256\n{amz_date}\n{scope}\n{}",
+		hex_sha256(canonical_request.as_bytes())
+	);
+
+	let signing_key = [date, REGION, "bedrock", "aws4_request"]
+		.iter()
+		.fold(format!("AWS4{SECRET_ACCESS_KEY}").into_bytes(), |key, data| {
+			hmac_sha256(&key, data.as_bytes())
+		});
+	Ok(hex(&hmac_sha256(&signing_key, string_to_sign.as_bytes())))
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+	let key = hmac::Key::new(hmac::HMAC_SHA256, key);
+	hmac::sign(&key, data).as_ref().to_vec()
+}
+
+fn hex_sha256(data: &[u8]) -> String {
+	hex(digest::digest(&digest::SHA256, data).as_ref())
+}
+
+fn hex(bytes: &[u8]) -> String {
+	bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+// endregion: --- SigV4 Check
+
+// region:    --- Event Stream Encoding
+
+/// An event message of the AWS binary event stream, with the `:message-type`, `:event-type`, and `:content-type` headers.
+fn encode_event_message(event_type: &str, payload: &Value) -> Vec<u8> {
+	let headers = [
+		(":message-type", "event"),
+		(":event-type", event_type),
+		(":content-type", "application/json"),
+	];
+	let mut header_bytes = Vec::new();
+	for (name, value) in headers {
+		header_bytes.push(name.len() as u8);
+		header_bytes.extend_from_slice(name.as_bytes());
+		header_bytes.push(7); // string
+		header_bytes.extend_from_slice(&(value.len() as u16).to_be_bytes());
+		header_bytes.extend_from_slice(value.as_bytes());
+	}
+	let payload = payload.to_string().into_bytes();
+
+	let total_len = 12 + header_bytes.len() + payload.len() + 4;
+	let mut message = Vec::new();
+	message.extend_from_slice(&(total_len as u32).to_be_bytes());
+	message.extend_from_slice(&(header_bytes.len() as u32).to_be_bytes());
+	message.extend_from_slice(&crc32(&message).to_be_bytes());
+	message.extend_from_slice(&header_bytes);
+	message.extend_from_slice(&payload);
+	message.extend_from_slice(&crc32(&message).to_be_bytes());
+	message
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+	let mut crc = !0u32;
+	for &byte in bytes {
+		crc ^= byte as u32;
+		for _ in 0..8 {
+			let mask = (crc & 1).wrapping_neg();
+			crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+		}
+	}
+	!crc
+}
+
+// endregion: --- Event Stream Encoding
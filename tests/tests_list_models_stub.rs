@@ -0,0 +1,100 @@
+//! Tests for `Client::list_models` against a local stub endpoint (no network, no API key).
+//!
+//! For `list_models`, the static model lists are the fallback only when the provider has no models endpoint,
+//! and for `all_model_names`, when the listing fails for any reason.
+
+mod support;
+
+use crate::support::{Stub, StubResponse, TestResult};
+use genai::ErrorKind;
+use genai::adapter::AdapterKind;
+use genai::resolver::AuthData;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_list_models_stub_ok() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let body = json!({"object": "list", "data": [
+		{"id": "gpt-4o", "object": "model", "created": 1715367049, "owned_by": "system"}
+	]});
+	let stub = Stub::start(vec![StubResponse::json(200, body.to_string())]).await?;
+	let client = stub.client("v1/", AuthData::from_single("stub-key"));
+
+	// -- Exec
+	let models = client.list_models(AdapterKind::OpenAI).await?;
+
+	// -- Check
+	assert_eq!(models.len(), 1);
+	assert_eq!(models[0].id, "gpt-4o");
+	let request = stub.last_request().ok_or("Should have a request")?;
+	assert_eq!(request.path, "/v1/models");
+	assert_eq!(request.header("authorization"), Some("Bearer stub-key"));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_list_models_stub_not_found_fallback_ok() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let stub = Stub::start(vec![StubResponse::json(404, r#"{"error":"not found"}"#)]).await?;
+	let client = stub.client("v1/", AuthData::from_single("stub-key"));
+
+	// -- Exec
+	let models = client.list_models(AdapterKind::OpenAI).await?;
+
+	// -- Check
+	assert!(!models.is_empty(), "Should have the static model list");
+	assert!(models.iter().any(|model| model.id == "gpt-5"));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_list_models_stub_auth_err() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let body = json!({"error": {"message": "Incorrect API key provided", "type": "invalid_request_error", "code": "invalid_api_key"}});
+	let stub = Stub::start(vec![StubResponse::json(401, body.to_string())]).await?;
+	let client = stub.client("v1/", AuthData::from_single("bad-key"));
+
+	// -- Exec
+	let Err(err) = client.list_models(AdapterKind::OpenAI).await else {
+		return Err("Should have failed (no static list fallback)".into());
+	};
+
+	// -- Check
+	assert_eq!(err.kind(), ErrorKind::InvalidAuth);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_list_models_stub_all_model_names_auth_err_fallback_ok() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let body = json!({"error": {"message": "Incorrect API key provided", "type": "invalid_request_error", "code": "invalid_api_key"}});
+	let stub = Stub::start(vec![StubResponse::json(401, body.to_string())]).await?;
+	let client = stub.client("v1/", AuthData::from_single("bad-key"));
+
+	// -- Exec
+	let names = client.all_model_names(AdapterKind::OpenAI).await?;
+
+	// -- Check (the names still have the static list)
+	assert!(names.iter().any(|name| name == "gpt-5"));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_list_models_stub_not_supported_fallback_ok() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let stub = Stub::start(Vec::new()).await?;
+	let client = stub.client("", AuthData::from_single("stub-key"));
+
+	// -- Exec
+	let models = client.list_models(AdapterKind::Bedrock).await?;
+
+	// -- Check
+	assert!(!models.is_empty(), "Should have the static model list");
+	assert!(stub.last_request().is_none());
+
+	Ok(())
+}
@@ -0,0 +1,594 @@
+//! Tests for the scripted mock adapter (no network, no API key).
+//!
+//! These tests require the `mock` feature to be enabled.
+
+#![cfg(feature = "mock")]
+
+mod support;
+
+use crate::support::{TestResult, extract_stream_end, seed_chat_req_simple};
+use futures::StreamExt;
+use genai::adapter::AdapterKind;
+use genai::adapter::{Mock, MockResponse};
+use genai::chat::{ChatOptions, ChatRequest, ChatRole, ChatStreamEvent, ToolCall, Usage};
+use genai::{CancellationToken, ChatJob, Client, Error, ErrorKind, ExecManyOptions, ModelIden, ResumePolicy};
+use serde_json::json;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+const MODEL: &str = "mock::test-model";
+
+#[tokio::test]
+async fn test_mock_chat_simple_ok() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let usage = Usage {
+		prompt_tokens: Some(12),
+		completion_tokens: Some(3),
+		total_tokens: Some(15),
+		..Default::default()
+	};
+	let mock = Mock::new().with_response(MockResponse::text("Rayleigh scattering.").with_usage(usage));
+	let client = Client::builder().with_mock(mock.clone()).build();
+	let options = ChatOptions::default().with_temperature(0.2);
+
+	// -- Exec
+	let res = client.exec_chat(MODEL, seed_chat_req_simple(), Some(&options)).await?;
+
+	// -- Check
+	assert_eq!(res.model_iden.adapter_kind, AdapterKind::Mock);
+	assert_eq!(res.first_text(), Some("Rayleigh scattering."));
+	assert_eq!(res.usage.total_tokens, Some(15));
+	let request = mock.last_request().ok_or("Should have a request")?;
+	assert_eq!(request.model_name, "test-model");
+	assert_eq!(request.chat_request.messages.len(), 2);
+	assert_eq!(request.chat_options.temperature, Some(0.2));
+	assert!(!request.stream);
+	assert_eq!(mock.remaining_responses(), 0);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_chat_stream_ok() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let tool_call = ToolCall {
+		call_id: "call_1".to_string(),
+		fn_name: "get_weather".to_string(),
+		fn_arguments: json!({"city": "Paris"}),
+		thought_signature: None,
+	};
+	let mut response = MockResponse::chunks(["Let me ", "check."])
+		.with_reasoning_content("Needs the weather tool.")
+		.with_chunk_delay(Duration::from_millis(5));
+	response.content.push(tool_call);
+	let mock = Mock::new().with_response(response);
+	let client = Client::builder().with_mock(mock.clone()).build();
+	let options = ChatOptions::default().with_capture_content(true).with_capture_tool_calls(true);
+
+	// -- Exec
+	let stream_res = client.exec_chat_stream(MODEL, seed_chat_req_simple(), Some(&options)).await?;
+	let extract = extract_stream_end(stream_res.stream).await?;
+
+	// -- Check
+	assert_eq!(extract.content.as_deref(), Some("Let me check."));
+	assert_eq!(extract.reasoning_content.as_deref(), Some("Needs the weather tool."));
+	assert_eq!(extract.stream_end.captured_first_text(), Some("Let me check."));
+	let tool_calls = extract.stream_end.captured_tool_calls().ok_or("Should have tool calls")?;
+	assert_eq!(tool_calls[0].fn_name, "get_weather");
+	assert!(mock.last_request().ok_or("Should have a request")?.stream);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_error_ok() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let mock = Mock::new()
+		.with_response(MockResponse::error(500, "mock server error"))
+		.with_response(MockResponse::text("ok").with_stream_error_after(1));
+	let client = Client::builder().with_mock(mock.clone()).build();
+
+	// -- Exec & Check
+	let res = client.exec_chat(MODEL, seed_chat_req_simple(), None).await;
+	assert!(res.is_err(), "Should fail with the scripted error");
+
+	let stream_res = client.exec_chat_stream(MODEL, seed_chat_req_simple(), None).await?;
+	assert!(
+		extract_stream_end(stream_res.stream).await.is_err(),
+		"Stream should fail"
+	);
+
+	// -- Check the exhausted script
+	let res = client.exec_chat(MODEL, seed_chat_req_simple(), None).await;
+	assert!(res.is_err(), "Exhausted script should fail");
+	assert_eq!(mock.requests().len(), 3);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_error_kind_ok() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let body = json!({"error": {"message": "Rate limit reached for gpt-4o", "code": "rate_limit_exceeded"}});
+	let mock =
+		Mock::new().with_response(MockResponse::error(429, body.to_string()).with_header("x-request-id", "req_42"));
+	let client = Client::builder().with_mock(mock).build();
+
+	// -- Exec
+	let err = client
+		.exec_chat(MODEL, seed_chat_req_simple(), None)
+		.await
+		.err()
+		.ok_or("Should fail with the scripted error")?;
+
+	// -- Check
+	assert_eq!(err.kind(), ErrorKind::RateLimited);
+	assert!(err.is_retryable());
+	let provider_error = err.provider_error().ok_or("Should have a provider error")?;
+	assert_eq!(provider_error.code.as_deref(), Some("rate_limit_exceeded"));
+	assert_eq!(provider_error.message.as_deref(), Some("Rate limit reached for gpt-4o"));
+	assert_eq!(provider_error.request_id.as_deref(), Some("req_42"));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_timeout_and_cancel_ok() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let mock = Mock::new()
+		.with_response(MockResponse::text("late").with_delay(Duration::from_secs(5)))
+		.with_response(MockResponse::chunks(["a", "b"]).with_chunk_delay(Duration::from_secs(5)))
+		.with_response(MockResponse::text("late").with_delay(Duration::from_secs(5)));
+	let client = Client::builder().with_mock(mock).build();
+
+	// -- Exec & Check: deadline
+	let options = ChatOptions::default().with_timeout(Duration::from_millis(20));
+	let res = client.exec_chat(MODEL, seed_chat_req_simple(), Some(&options)).await;
+	assert!(
+		matches!(res, Err(Error::RequestTimeout { .. })),
+		"Should time out: {res:?}"
+	);
+
+	// -- Exec & Check: stream idle timeout (the stream starts, then the first chunk is late)
+	let options = ChatOptions::default().with_stream_idle_timeout(Duration::from_millis(20));
+	let mut stream = client
+		.exec_chat_stream(MODEL, seed_chat_req_simple(), Some(&options))
+		.await?
+		.stream;
+	assert!(matches!(stream.next().await, Some(Ok(_))), "Should start");
+	let event = stream.next().await;
+	assert!(
+		matches!(event, Some(Err(Error::StreamIdleTimeout { .. }))),
+		"Should be idle: {event:?}"
+	);
+	assert!(stream.next().await.is_none(), "Should end after the error");
+
+	// -- Exec & Check: cancellation
+	let cancellation_token = CancellationToken::new();
+	let options = ChatOptions::default().with_cancellation_token(cancellation_token.clone());
+	tokio::spawn(async move {
+		tokio::time::sleep(Duration::from_millis(20)).await;
+		cancellation_token.cancel();
+	});
+	let res = client.exec_chat(MODEL, seed_chat_req_simple(), Some(&options)).await;
+	assert!(
+		matches!(res, Err(Error::Cancelled { .. })),
+		"Should be cancelled: {res:?}"
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_stream_resume_ok() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let mock = Mock::new()
+		.with_response(MockResponse::chunks(["The sky ", "is ", "green"]).with_stream_error_after(2))
+		.with_response(MockResponse::chunks([" blue."]));
+	let client = Client::builder().with_mock(mock.clone()).build();
+	let options = ChatOptions::default()
+		.with_capture_content(true)
+		.with_resume_policy(ResumePolicy::new(1));
+
+	// -- Exec
+	let mut stream = client
+		.exec_chat_stream(MODEL, seed_chat_req_simple(), Some(&options))
+		.await?
+		.stream;
+	let mut texts = Vec::new();
+	let mut resumes = Vec::new();
+	let mut stream_end = None;
+	while let Some(event) = stream.next().await {
+		match event? {
+			ChatStreamEvent::Chunk(chunk) => texts.push(chunk.content),
+			ChatStreamEvent::Resumed(resumed) => resumes.push(resumed.attempt),
+			ChatStreamEvent::End(end) => stream_end = Some(end),
+			_ => (),
+		}
+	}
+
+	// -- Check
+	assert_eq!(texts.join(""), "The sky is blue.");
+	assert_eq!(resumes, vec![1]);
+	let stream_end = stream_end.ok_or("Should have a StreamEnd event")?;
+	assert_eq!(stream_end.captured_first_text(), Some("The sky is blue."));
+	// The resume request ends with the prefill (trailing whitespace trimmed).
+	let request = mock.last_request().ok_or("Should have a request")?;
+	let prefill = request.chat_request.messages.last().ok_or("Should have messages")?;
+	assert!(matches!(prefill.role, ChatRole::Assistant));
+	assert_eq!(prefill.content.first_text(), Some("The sky is"));
+	assert_eq!(mock.requests().len(), 2);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_stream_retry_middleware_audit_ok() -> TestResult<()> {
+	use genai::adapter::WebRequestData;
+	use genai::webc::WebResponse;
+	use genai::{AuditRecord, AuditSink, Middleware, MiddlewareContext, RetryPolicy};
+	use std::sync::Mutex;
+
+	// -- Setup & Fixtures
+	#[derive(Default)]
+	struct CountRequests(Arc<AtomicUsize>);
+	impl Middleware for CountRequests {
+		fn on_request(
+			&self,
+			_ctx: &MiddlewareContext,
+			_request: &mut WebRequestData,
+		) -> genai::Result<Option<WebResponse>> {
+			self.0.fetch_add(1, Ordering::SeqCst);
+			Ok(None)
+		}
+	}
+	#[derive(Default)]
+	struct Records(Arc<Mutex<Vec<AuditRecord>>>);
+	impl AuditSink for Records {
+		fn record(&self, record: &AuditRecord) {
+			self.0.lock().unwrap().push(record.clone());
+		}
+	}
+	let on_requests = Arc::new(AtomicUsize::new(0));
+	let records: Arc<Mutex<Vec<AuditRecord>>> = Default::default();
+	let mock = Mock::new()
+		.with_response(MockResponse::error(503, "mock overloaded"))
+		.with_response(MockResponse::chunks(["Blue", " sky."]));
+	let client = Client::builder()
+		.with_mock(mock.clone())
+		.with_middleware(CountRequests(on_requests.clone()))
+		.with_audit_sink(Records(records.clone()))
+		.build();
+	let retry_policy = RetryPolicy::new(2)
+		.with_initial_backoff(Duration::from_millis(1))
+		.with_jitter(false);
+	let options = ChatOptions::default().with_retry_policy(retry_policy);
+
+	// -- Exec
+	let stream_res = client.exec_chat_stream(MODEL, seed_chat_req_simple(), Some(&options)).await?;
+	let extract = extract_stream_end(stream_res.stream).await?;
+
+	// -- Check (the retry goes through the middlewares and the audit sink as the first request)
+	assert_eq!(extract.content.as_deref(), Some("Blue sky."));
+	assert_eq!(mock.requests().len(), 2);
+	assert_eq!(on_requests.load(Ordering::SeqCst), 2);
+	let records = records.lock().unwrap().clone();
+	assert_eq!(records.len(), 2);
+	assert!(records[0].error.is_some());
+	assert!(records[1].error.is_none());
+	assert!(records[1].timings.is_some());
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_exec_chat_many_ok() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let mock = Mock::new()
+		.with_response(MockResponse::text("one"))
+		.with_response(MockResponse::error(400, "bad request"))
+		.with_response(MockResponse::text("three"))
+		.with_default_response(MockResponse::text("default").with_delay(Duration::from_millis(5)));
+	let client = Client::builder().with_mock(mock.clone()).build();
+	let progress_count = Arc::new(AtomicUsize::new(0));
+	let jobs = ["first", "second", "third"].map(|prompt| (MODEL, ChatRequest::from_user(prompt)));
+
+	// -- Exec & Check: in order (one job at a time, so the scripted responses follow the jobs)
+	let options = ExecManyOptions::default().with_concurrency(1).with_on_progress({
+		let progress_count = progress_count.clone();
+		move |progress| {
+			progress_count.fetch_add(1, Ordering::SeqCst);
+			assert_eq!(progress.total, 3);
+		}
+	});
+	let results = client.exec_chat_many(jobs, Some(&options)).await;
+	assert_eq!(results.len(), 3);
+	assert_eq!(results[0].as_ref().ok().and_then(|res| res.first_text()), Some("one"));
+	assert!(results[1].is_err(), "Second job should fail without aborting the batch");
+	assert_eq!(results[2].as_ref().ok().and_then(|res| res.first_text()), Some("three"));
+	assert_eq!(progress_count.load(Ordering::SeqCst), 3);
+
+	// -- Exec & Check: as a stream
+	let options = ExecManyOptions::default().with_concurrency(4);
+	let jobs = (0..10).map(|i| ChatJob::new(MODEL, ChatRequest::from_user(format!("prompt {i}"))));
+	let mut indexes: Vec<usize> = client
+		.exec_chat_many_stream(jobs, Some(&options))
+		.map(|(index, res)| res.map(|_| index))
+		.collect::<Vec<_>>()
+		.await
+		.into_iter()
+		.collect::<Result<_, _>>()?;
+	indexes.sort();
+	assert_eq!(indexes, (0..10).collect::<Vec<_>>());
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_tracing_span_ok() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let (buffer, _guard) = capture_spans();
+	let usage = Usage {
+		prompt_tokens: Some(12),
+		completion_tokens: Some(3),
+		..Default::default()
+	};
+	let mock = Mock::new().with_response(MockResponse::text("Blue.").with_usage(usage));
+	let client = Client::builder().with_mock(mock).with_trace_content(true).build();
+	let options = ChatOptions::default().with_temperature(0.2).with_max_tokens(64);
+
+	// -- Exec
+	client.exec_chat(MODEL, seed_chat_req_simple(), Some(&options)).await?;
+
+	// -- Check
+	let output = buffer.output();
+	for attribute in [
+		"gen_ai.operation.name=\"chat\"",
+		"gen_ai.request.model=\"mock::test-model\"",
+		"gen_ai.request.temperature=0.2",
+		"gen_ai.request.max_tokens=64",
+		"gen_ai.usage.input_tokens=12",
+		"gen_ai.usage.output_tokens=3",
+		"gen_ai.completion=",
+	] {
+		assert!(output.contains(attribute), "Should contain '{attribute}' in:\n{output}");
+	}
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_tracing_span_alias_ok() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let (buffer, _guard) = capture_spans();
+	let mock = Mock::new().with_response(MockResponse::text("Blue."));
+	// "fast" alone would be an Ollama model (see `AdapterKind::from_model`)
+	let client = Client::builder()
+		.with_mock(mock)
+		.with_model_mapper_fn(|model_iden: ModelIden| -> Result<ModelIden, genai::resolver::Error> {
+			match &*model_iden.model_name {
+				"fast" => Ok(ModelIden::new(AdapterKind::Mock, MODEL)),
+				_ => Ok(model_iden),
+			}
+		})
+		.build();
+
+	// -- Exec
+	client.exec_chat("fast", seed_chat_req_simple(), None).await?;
+
+	// -- Check
+	let output = buffer.output();
+	for attribute in ["gen_ai.system=\"mock\"", "gen_ai.request.model=\"fast\""] {
+		assert!(output.contains(attribute), "Should contain '{attribute}' in:\n{output}");
+	}
+	assert!(
+		!output.contains("gen_ai.system=\"ollama\""),
+		"Should not be ollama in:\n{output}"
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_fallback_alias_options_ok() -> TestResult<()> {
+	use genai::ClientConfig;
+
+	// -- Setup & Fixtures
+	let config = ClientConfig::from_toml(
+		r#"
+		[aliases]
+		fast = { model = "mock::fast-model", chat_options = { temperature = 0.1, max_tokens = 100 } }
+		smart = { model = "mock::smart-model", chat_options = { temperature = 0.9 } }
+		"#,
+	)?;
+	let mock = Mock::new()
+		.with_response(MockResponse::error(503, "mock overloaded"))
+		.with_response(MockResponse::text("Blue."));
+	let client = Client::builder().with_config(config).with_mock(mock.clone()).build();
+	let options = ChatOptions::default().with_fallback_models(["smart"]);
+
+	// -- Exec
+	let res = client.exec_chat("fast", seed_chat_req_simple(), Some(&options)).await?;
+
+	// -- Check (each model is sent with its own alias options)
+	assert_eq!(res.fallback_attempts.len(), 1);
+	let requests = mock.requests();
+	assert_eq!(requests.len(), 2);
+	assert_eq!(requests[0].model_name, "fast-model");
+	assert_eq!(requests[0].chat_options.temperature, Some(0.1));
+	assert_eq!(requests[0].chat_options.max_tokens, Some(100));
+	assert_eq!(requests[1].model_name, "smart-model");
+	assert_eq!(requests[1].chat_options.temperature, Some(0.9));
+	assert_eq!(requests[1].chat_options.max_tokens, None);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_fallback_response_cache_ok() -> TestResult<()> {
+	use genai::InMemoryResponseCache;
+
+	// -- Setup & Fixtures
+	let mock = Mock::new()
+		.with_response(MockResponse::error(503, "mock overloaded"))
+		.with_response(MockResponse::text("Blue."))
+		.with_response(MockResponse::text("Still blue."));
+	let client = Client::builder()
+		.with_mock(mock.clone())
+		.with_response_cache(InMemoryResponseCache::new(10))
+		.build();
+	let options = ChatOptions::default().with_fallback_models(["mock::smart-model"]);
+
+	// -- Exec
+	let fallback_res = client
+		.exec_chat("mock::fast-model", seed_chat_req_simple(), Some(&options))
+		.await?;
+	let primary_res = client
+		.exec_chat("mock::fast-model", seed_chat_req_simple(), Some(&options))
+		.await?;
+	let smart_res = client.exec_chat("mock::smart-model", seed_chat_req_simple(), None).await?;
+
+	// -- Check (the fallback response is cached under the model that answered, not the requested one)
+	assert_eq!(fallback_res.fallback_attempts.len(), 1);
+	assert_eq!(
+		(primary_res.first_text(), primary_res.cached),
+		(Some("Still blue."), false)
+	);
+	assert_eq!((smart_res.first_text(), smart_res.cached), (Some("Blue."), true));
+	assert!(smart_res.fallback_attempts.is_empty());
+	assert_eq!(mock.requests().len(), 3);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_metrics_hook_ok() -> TestResult<()> {
+	use genai::{ChatMetrics, MetricsHook};
+	use std::sync::Mutex;
+
+	// -- Setup & Fixtures
+	#[derive(Clone, Default)]
+	struct Collector(Arc<Mutex<Vec<ChatMetrics>>>);
+	impl MetricsHook for Collector {
+		fn on_chat_metrics(&self, metrics: &ChatMetrics) {
+			self.0.lock().unwrap().push(metrics.clone());
+		}
+	}
+	let usage = Usage {
+		completion_tokens: Some(4),
+		..Default::default()
+	};
+	let mock = Mock::new()
+		.with_response(MockResponse::text("Blue.").with_usage(usage.clone()))
+		.with_response(
+			MockResponse::chunks(["Blue", " sky."])
+				.with_reasoning_content("Rayleigh.")
+				.with_usage(usage)
+				.with_chunk_delay(Duration::from_millis(10)),
+		);
+	let collector = Collector::default();
+	let client = Client::builder().with_mock(mock).with_metrics_hook(collector.clone()).build();
+	let options = ChatOptions::default().with_capture_usage(true);
+
+	// -- Exec
+	let chat_res = client.exec_chat(MODEL, seed_chat_req_simple(), None).await?;
+	let stream_res = client.exec_chat_stream(MODEL, seed_chat_req_simple(), Some(&options)).await?;
+	let extract = extract_stream_end(stream_res.stream).await?;
+
+	// -- Check
+	let timings = chat_res.timings.ok_or("Should have timings")?;
+	assert!(timings.time_to_first_token.is_none());
+	assert!(timings.output_tokens_per_sec.is_some());
+	let timings = extract.stream_end.timings.ok_or("Should have stream timings")?;
+	let first_token = timings.time_to_first_token.ok_or("Should have a time to first token")?;
+	let first_reasoning_token = timings
+		.time_to_first_reasoning_token
+		.ok_or("Should have a time to first reasoning token")?;
+	assert!(first_reasoning_token <= first_token && first_token <= timings.total);
+	assert!(timings.output_tokens_per_sec.is_some());
+	let metrics = collector.0.lock().unwrap().clone();
+	assert_eq!(metrics.len(), 2);
+	assert!(!metrics[0].stream && metrics[1].stream);
+	assert_eq!(metrics[1].timings, timings);
+	assert_eq!(metrics[1].usage.as_ref().and_then(|u| u.completion_tokens), Some(4));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_audit_sink_ok() -> TestResult<()> {
+	use genai::{AuditRedaction, JsonlAuditSink};
+
+	// -- Setup & Fixtures
+	let path = std::env::temp_dir().join(format!("genai-audit-{}.jsonl", std::process::id()));
+	let _ = std::fs::remove_file(&path);
+	let mock = Mock::new()
+		.with_response(MockResponse::text("Blue."))
+		.with_response(MockResponse::chunks(["Blue", " sky."]));
+	let sink = JsonlAuditSink::new(&path).with_redaction(AuditRedaction::default().with_secret("Why"));
+	let client = Client::builder().with_mock(mock).with_audit_sink(sink).build();
+
+	// -- Exec
+	client.exec_chat(MODEL, seed_chat_req_simple(), None).await?;
+	let stream_res = client.exec_chat_stream(MODEL, seed_chat_req_simple(), None).await?;
+	extract_stream_end(stream_res.stream).await?;
+
+	// -- Check
+	let content = std::fs::read_to_string(&path)?;
+	let _ = std::fs::remove_file(&path);
+	let records = content
+		.lines()
+		.map(|line| serde_json::from_str::<serde_json::Value>(line).map_err(|err| err.to_string()))
+		.collect::<Result<Vec<_>, _>>()?;
+	assert_eq!(records.len(), 2);
+	assert_eq!(records[0]["request_kind"], "Chat");
+	assert_eq!(records[1]["request_kind"], "ChatStream");
+	assert_eq!(records[1]["response"]["content"], "Blue sky.");
+	assert!(records[1]["timings"].is_object());
+	let request = records[0]["request"].to_string();
+	assert!(request.contains("[REDACTED] is the sky blue?") && !request.contains("Why"));
+
+	Ok(())
+}
+
+// region:    --- Support
+
+/// The fmt output of the closed spans, captured by `capture_spans`.
+#[derive(Clone, Default)]
+struct SpanBuffer(Arc<std::sync::Mutex<Vec<u8>>>);
+
+impl SpanBuffer {
+	fn output(&self) -> String {
+		String::from_utf8_lossy(&self.0.lock().unwrap()).to_string()
+	}
+}
+
+impl std::io::Write for SpanBuffer {
+	fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+		self.0.lock().unwrap().extend_from_slice(buf);
+		Ok(buf.len())
+	}
+	fn flush(&mut self) -> std::io::Result<()> {
+		Ok(())
+	}
+}
+
+/// Sets a fmt subscriber for the current thread (until the guard is dropped), writing the closed spans to the buffer.
+fn capture_spans() -> (SpanBuffer, tracing::subscriber::DefaultGuard) {
+	use tracing_subscriber::fmt::format::FmtSpan;
+
+	let buffer = SpanBuffer::default();
+	let subscriber = tracing_subscriber::fmt()
+		.with_writer({
+			let buffer = buffer.clone();
+			move || buffer.clone()
+		})
+		.with_span_events(FmtSpan::CLOSE)
+		.with_ansi(false)
+		.finish();
+	let guard = tracing::subscriber::set_default(subscriber);
+	(buffer, guard)
+}
+
+// endregion: --- Support
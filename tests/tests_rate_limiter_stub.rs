@@ -0,0 +1,61 @@
+//! Tests for the adaptive `RateLimiter` against a local stub endpoint (no network, no API key).
+//!
+//! The stub responses carry the `x-ratelimit-*` headers, so the limiter wait can be checked from the call times.
+
+mod support;
+
+use crate::support::{Stub, StubResponse, TestResult, extract_stream_end};
+use genai::RateLimiter;
+use genai::chat::{ChatMessage, ChatOptions, ChatRequest};
+use genai::resolver::AuthData;
+use serde_json::json;
+use std::time::{Duration, Instant};
+
+const MODEL: &str = "gpt-4o-mini";
+
+#[tokio::test]
+async fn test_rate_limiter_stub_adapt_from_stream_ok() -> TestResult<()> {
+	// -- Setup & Fixtures
+	let events = [
+		json!({"choices": [{"index": 0, "delta": {"role": "assistant", "content": "Rayleigh "}}]}),
+		json!({"choices": [{"index": 0, "delta": {"content": "scattering."}}]}),
+		json!({"choices": [{"index": 0, "delta": {}, "finish_reason": "stop"}]}),
+	];
+	let mut stream_body: String = events.iter().map(|event| format!("data: {event}\n\n")).collect();
+	stream_body.push_str("data: [DONE]\n\n");
+	let chat_body = json!({
+		"id": "chatcmpl-1",
+		"object": "chat.completion",
+		"model": MODEL,
+		"choices": [{"index": 0, "finish_reason": "stop", "message": {"role": "assistant", "content": "Blue."}}],
+		"usage": {"prompt_tokens": 8, "completion_tokens": 2, "total_tokens": 10}
+	});
+	let stub = Stub::start(vec![
+		// No request left, until the reset.
+		StubResponse::new(200, "text/event-stream", stream_body)
+			.with_header("x-ratelimit-limit-requests", "100")
+			.with_header("x-ratelimit-remaining-requests", "0")
+			.with_header("x-ratelimit-reset-requests", "500ms"),
+		StubResponse::json(200, chat_body.to_string()),
+	])
+	.await?;
+	let client = stub
+		.client_builder("v1/", AuthData::from_single("sk-stub"))
+		.with_rate_limiter(RateLimiter::new().with_adaptive(true))
+		.build();
+	let options = ChatOptions::default().with_capture_content(true);
+	let chat_req = ChatRequest::new(vec![ChatMessage::user("Why is the sky blue?")]);
+
+	// -- Exec
+	let stream_res = client.exec_chat_stream(MODEL, chat_req.clone(), Some(&options)).await?;
+	let extract = extract_stream_end(stream_res.stream).await?;
+	let start = Instant::now();
+	let res = client.exec_chat(MODEL, chat_req, None).await?;
+
+	// -- Check (the next call waits for the reset of the stream response headers)
+	assert_eq!(extract.content.as_deref(), Some("Rayleigh scattering."));
+	assert_eq!(res.first_text(), Some("Blue."));
+	assert!(start.elapsed() >= Duration::from_millis(400));
+
+	Ok(())
+}